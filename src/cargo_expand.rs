@@ -24,21 +24,23 @@ pub(crate) fn is_cargo_expand_available() -> bool {
         .unwrap_or(false)
 }
 
+/// Fail when `use_cargo_expand` is on but the subcommand is missing:
+/// silently falling back would drop every macro-generated type and command.
+fn require_cargo_expand(available: bool) -> Result<()> {
+    if !available {
+        anyhow::bail!(
+            "use_cargo_expand is enabled but cargo-expand is not installed. \
+            Install it with `cargo install cargo-expand`, or set use_cargo_expand = false."
+        );
+    }
+    Ok(())
+}
+
 /// Run cargo expand on a crate and return the expanded code
 pub(crate) fn run_cargo_expand(manifest_path: &Path) -> Result<ExpandResult> {
     let mut warnings = Vec::new();
 
-    // Check if cargo-expand is available
-    if !is_cargo_expand_available() {
-        warnings.push(
-            "Warning: cargo-expand is not installed. Install it with: cargo install cargo-expand"
-                .to_string(),
-        );
-        return Ok(ExpandResult {
-            code: String::new(),
-            warnings,
-        });
-    }
+    require_cargo_expand(is_cargo_expand_available())?;
 
     warnings.push(format!(
         "Warning: Using cargo expand on {}. This may take a while...",
@@ -145,4 +147,11 @@ mod tests {
         // This test just checks that the function doesn't panic
         let _available = is_cargo_expand_available();
     }
+
+    #[test]
+    fn test_missing_cargo_expand_is_an_error() {
+        assert!(require_cargo_expand(true).is_ok());
+        let err = require_cargo_expand(false).unwrap_err().to_string();
+        assert!(err.contains("cargo install cargo-expand"));
+    }
 }
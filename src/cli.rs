@@ -1,6 +1,8 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
+use crate::diagnostics::AnnotationFormat;
+
 /// CLI tool to generate TypeScript bindings from Tauri commands
 #[derive(Parser, Debug)]
 #[command(name = "tauri-ts-generator")]
@@ -21,6 +23,58 @@ pub enum Commands {
         /// Enable verbose output
         #[arg(short, long, default_value = "false")]
         verbose: bool,
+
+        /// Only write the types file; leave the commands file untouched
+        #[arg(long, conflicts_with = "commands_only")]
+        types_only: bool,
+
+        /// Only write the commands file; leave the types file untouched
+        #[arg(long)]
+        commands_only: bool,
+
+        /// Print the generated files to stdout, each after a
+        /// `// ==> path <==` line, instead of writing them
+        #[arg(long, conflicts_with_all = ["watch", "since", "explain"])]
+        stdout: bool,
+
+        /// Keep running and regenerate whenever a `.rs` file changes
+        #[arg(long, conflicts_with = "explain")]
+        watch: bool,
+
+        /// Only rewrite the output files that `.rs` files changed since
+        /// this git ref can affect
+        #[arg(long, value_name = "REF", conflicts_with_all = ["watch", "explain"])]
+        since: Option<String>,
+
+        /// Fail when a command uses a type no scanned file defines, instead
+        /// of typing it as `unknown`
+        #[arg(long)]
+        strict: bool,
+
+        /// Explain how the named type is resolved instead of writing files
+        #[arg(long, value_name = "TYPE")]
+        explain: Option<String>,
+
+        /// Write warnings and errors as annotations for a CI system
+        /// (`github`: workflow commands shown inline on pull requests)
+        #[arg(long, value_enum, value_name = "FORMAT", default_value = "plain")]
+        annotations: AnnotationFormat,
+    },
+
+    /// Fail if the generated files on disk are out of date, without writing
+    Check {
+        /// Path to the configuration file
+        #[arg(short, long, default_value = "tauri-codegen.toml")]
+        config: PathBuf,
+
+        /// Enable verbose output
+        #[arg(short, long, default_value = "false")]
+        verbose: bool,
+
+        /// Write warnings and errors as annotations for a CI system
+        /// (`github`: workflow commands shown inline on pull requests)
+        #[arg(long, value_enum, value_name = "FORMAT", default_value = "plain")]
+        annotations: AnnotationFormat,
     },
 
     /// Initialize a new configuration file
@@ -1,7 +1,9 @@
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Main configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +13,8 @@ pub struct Config {
     pub output: OutputConfig,
     #[serde(default)]
     pub naming: NamingConfig,
+    #[serde(default)]
+    pub generation: GenerationConfig,
 }
 
 /// Input configuration - where to find Rust source files
@@ -22,12 +26,39 @@ pub struct InputConfig {
     /// Directories or files to exclude from scanning
     #[serde(default)]
     pub exclude: Vec<String>,
+    /// Glob patterns (relative to source_dir) restricting which files are
+    /// scanned; empty means every file
+    #[serde(default)]
+    pub include: Vec<String>,
     /// Use cargo expand to handle macro-generated types (slower but more complete)
     #[serde(default)]
     pub use_cargo_expand: bool,
     /// Cargo manifest path for cargo expand (defaults to Cargo.toml in source_dir parent)
     #[serde(default)]
     pub cargo_manifest: Option<PathBuf>,
+    /// Also generate bindings for commands under `#[cfg(test)]`
+    #[serde(default)]
+    pub include_test_commands: bool,
+    /// Extra attribute paths that mark a command, e.g. `tauri_plugin::command`
+    #[serde(default)]
+    pub command_attributes: Vec<String>,
+    /// Descend into symlinked directories while scanning
+    #[serde(default)]
+    pub follow_symlinks: bool,
+    /// Also scan the `src` of every `path = "..."` dependency in the
+    /// crate's `Cargo.toml`, so commands can use types from sibling
+    /// workspace crates
+    #[serde(default)]
+    pub path_dependencies: bool,
+    /// Crates (or module paths, `aws_sdk_s3::types`) whose types are
+    /// knowingly not scanned: they are typed as `unknown` without being
+    /// reported as unresolved
+    #[serde(default)]
+    pub external_crate_prefixes: Vec<String>,
+    /// Cargo features taken as enabled when reading
+    /// `#[cfg_attr(feature = "...", serde(...))]` on types and fields
+    #[serde(default)]
+    pub active_features: Vec<String>,
 }
 
 /// Output configuration - where to write generated TypeScript files
@@ -38,6 +69,204 @@ pub struct OutputConfig {
     pub types_file: PathBuf,
     /// Path for generated TypeScript commands file
     pub commands_file: PathBuf,
+    /// Write types and commands into this one file instead; `types_file`
+    /// and `commands_file` are then not written
+    #[serde(default)]
+    pub single_file: Option<PathBuf>,
+    /// Language of the generated bindings
+    #[serde(default)]
+    pub target: OutputTarget,
+    /// Also write a `CommandMap` declaration of every command's args and
+    /// result here (e.g. `commands.d.ts`), for a hand-written invoke
+    /// wrapper. TypeScript only
+    #[serde(default)]
+    pub command_map_file: Option<PathBuf>,
+    /// Also write Zod schemas mirroring the types here (e.g.
+    /// `schemas.ts`), for validating command results at runtime. The
+    /// command functions validate their arguments against them.
+    /// TypeScript only
+    #[serde(default)]
+    pub zod_file: Option<PathBuf>,
+    /// Also write a typed `listen` wrapper for every `#[tauri_event]`
+    /// payload type here (e.g. `events.ts`). TypeScript only
+    #[serde(default)]
+    pub events_file: Option<PathBuf>,
+    /// Declare every type inside `export namespace <name> { ... }` and
+    /// refer to them as `<name>.User`, so they can't clash with frontend
+    /// names. TypeScript only
+    #[serde(default)]
+    pub types_namespace: Option<String>,
+    /// Notice written as `//` comments at the top of every generated file
+    /// instead of the default "auto-generated" one. `{version}` is
+    /// replaced with the generator's version
+    #[serde(default)]
+    pub header: Option<String>,
+    /// Add a `// Generated at <UTC time>` line under the notice. Leave
+    /// off when running `check`, as every run would differ
+    #[serde(default)]
+    pub header_timestamp: bool,
+    /// Indentation and quoting of the generated code
+    #[serde(default)]
+    pub style: StyleConfig,
+}
+
+/// Language the bindings are generated in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputTarget {
+    /// Interfaces and `invoke` wrappers
+    #[default]
+    TypeScript,
+    /// `@Serializable` data classes and a `Commands` interface, for
+    /// Kotlin code talking to the same commands (experimental)
+    Kotlin,
+}
+
+impl OutputConfig {
+    /// The files a run writes: `single_file` alone when set, otherwise
+    /// `types_file` and `commands_file`; plus `command_map_file`,
+    /// `zod_file` and `events_file`.
+    pub fn files(&self) -> Vec<&Path> {
+        let mut files = match &self.single_file {
+            Some(file) => vec![file.as_path()],
+            None => vec![self.types_file.as_path(), self.commands_file.as_path()],
+        };
+        files.extend(self.command_map_file.as_deref());
+        files.extend(self.zod_file.as_deref());
+        files.extend(self.events_file.as_deref());
+        files
+    }
+
+    /// The file the type declarations end up in
+    pub fn types_module(&self) -> &Path {
+        self.single_file.as_deref().unwrap_or(&self.types_file)
+    }
+
+    /// The comment lines `header` and `header_timestamp` ask for, or
+    /// `None` to keep the default notice.
+    pub fn banner(&self, now: SystemTime) -> Option<String> {
+        if self.header.is_none() && !self.header_timestamp {
+            return None;
+        }
+        let mut banner = String::new();
+        match &self.header {
+            Some(header) => {
+                let header = header.replace("{version}", env!("CARGO_PKG_VERSION"));
+                for line in header.trim_end().lines() {
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        banner.push_str("//\n");
+                    } else {
+                        banner.push_str(&format!("// {}\n", line));
+                    }
+                }
+            }
+            None => banner.push_str(DEFAULT_BANNER),
+        }
+        if self.header_timestamp {
+            banner.push_str(&format!("// Generated at {}\n", utc_timestamp(now)));
+        }
+        Some(banner)
+    }
+}
+
+/// Notice opening generated files when `[output] header` isn't set
+pub const DEFAULT_BANNER: &str =
+    "// This file was auto-generated by tauri-ts-generator\n// Do not edit this file manually\n";
+
+/// `now` as an RFC 3339 UTC time to the second, e.g. `2024-03-01T09:30:00Z`.
+fn utc_timestamp(now: SystemTime) -> String {
+    let secs = now
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let (days, time) = (secs / 86_400, secs % 86_400);
+    // Civil date from days since 1970-01-01 (Howard Hinnant's algorithm)
+    let z = days as i64 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3_600,
+        time % 3_600 / 60,
+        time % 60
+    )
+}
+
+/// `[output.style]` - formatting of the generated TypeScript
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct StyleConfig {
+    /// One level of indentation: `"  "`, `"\t"` or a number of spaces
+    #[serde(default)]
+    pub indent: Indent,
+    /// Quote character for string literals and import paths
+    #[serde(default)]
+    pub quote: QuoteStyle,
+}
+
+/// One level of indentation. Written in TOML as a whitespace string or a
+/// space count (`indent = 4`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "IndentSetting", into = "String")]
+pub struct Indent(String);
+
+impl Indent {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Default for Indent {
+    fn default() -> Self {
+        Indent("  ".to_string())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IndentSetting {
+    Spaces(usize),
+    Literal(String),
+}
+
+impl TryFrom<IndentSetting> for Indent {
+    type Error = String;
+
+    fn try_from(setting: IndentSetting) -> Result<Self, Self::Error> {
+        match setting {
+            IndentSetting::Spaces(n) => Ok(Indent(" ".repeat(n))),
+            IndentSetting::Literal(s) if s.chars().all(|c| c == ' ' || c == '\t') => Ok(Indent(s)),
+            IndentSetting::Literal(s) => Err(format!(
+                "indent must be spaces, tabs or a space count, got {:?}",
+                s
+            )),
+        }
+    }
+}
+
+impl From<Indent> for String {
+    fn from(indent: Indent) -> Self {
+        indent.0
+    }
+}
+
+/// Quote character used in the generated code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteStyle {
+    Single,
+    #[default]
+    Double,
 }
 
 /// Naming configuration - prefixes and suffixes for generated code
@@ -56,6 +285,243 @@ pub struct NamingConfig {
     /// Suffix for TypeScript function names
     #[serde(default)]
     pub function_suffix: String,
+    /// Explicit TypeScript names for Rust types (`User = "AppUser"`),
+    /// used as is instead of adding the type prefix and suffix
+    #[serde(default)]
+    pub type_overrides: HashMap<String, String>,
+}
+
+/// Generation configuration - switches that change the shape of the emitted TypeScript
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GenerationConfig {
+    /// Emit every numeric primitive as its own alias (`type I32 = number`)
+    /// and reference the alias instead of a bare `number`
+    #[serde(default)]
+    pub numeric_branding: bool,
+    /// TypeScript type of `Decimal` / `BigDecimal`
+    #[serde(default)]
+    pub decimal_type: DecimalType,
+    /// Emit an `isEnumVariant` type guard per variant of internally or
+    /// adjacently tagged enums
+    #[serde(default)]
+    pub type_guards: bool,
+    /// Quote property keys that are TypeScript reserved words
+    /// (`"delete": string`). Function and parameter names that collide
+    /// with a reserved word are always suffixed with `_` instead
+    #[serde(default)]
+    pub quote_reserved_keys: bool,
+    /// Export the error types of `Result<T, E>` commands into the types
+    /// file so `@throws {E}` names a real type
+    #[serde(default)]
+    pub export_error_types: bool,
+    /// Make commands resolve to `Ok<T> | Err<E>` through a generated
+    /// `invokeResult` helper instead of rejecting
+    #[serde(default)]
+    pub result_wrapper: bool,
+    /// Import types into the commands file with `import type { ... }`.
+    /// Turn off for setups that want a plain `import { ... }`
+    #[serde(default = "default_true")]
+    pub use_type_imports: bool,
+    /// Warn when a command function is named after a browser global
+    /// (`fetch`, `alert`, `eval`, ...) it would shadow
+    #[serde(default = "default_true")]
+    pub warn_shadowed_globals: bool,
+    /// Lines prepended verbatim to both generated files, e.g.
+    /// `/* eslint-disable */` or `// @ts-nocheck`
+    #[serde(default)]
+    pub header_pragmas: Vec<String>,
+    /// How a command returning `Option<T>` models `None`. Only the
+    /// top-level return (or the `Ok` of a `Result`) is affected; nested
+    /// `Option`s stay `T | null`
+    #[serde(default)]
+    pub option_return_style: OptionReturnStyle,
+    /// Type of a command returning `Vec<(String, T)>` (or the `Ok` of a
+    /// `Result`): the pairs as sent, or a `Record` built from them
+    #[serde(default)]
+    pub entries_return_style: EntriesReturnStyle,
+    /// Type maps keyed by an enum as `Partial<Record<K, V>>`, since the map
+    /// need not hold every variant
+    #[serde(default)]
+    pub partial_enum_records: bool,
+    /// How to type a map whose key serializes as neither a string nor a
+    /// number (a struct, a tuple, a data-carrying enum)
+    #[serde(default)]
+    pub map_key_fallback: MapKeyFallback,
+    /// Substitute `type Foo = Bar` aliases at every use site instead of
+    /// emitting `export type Foo = Bar`
+    #[serde(default)]
+    pub inline_type_aliases: bool,
+    /// Prefix every interface member with `readonly` and render arrays in
+    /// member types as `readonly T[]`
+    #[serde(default)]
+    pub readonly_fields: bool,
+    /// `readonly_fields`, and also render maps as `Readonly<Record<K, V>>`
+    /// and tuples as `readonly [A, B]`, in member types as well as in
+    /// aliases, newtypes and enum variant payloads
+    #[serde(default)]
+    pub deep_readonly: bool,
+    /// Copy `///` doc comments on commands, structs, fields and enums into
+    /// JSDoc blocks
+    #[serde(default)]
+    pub doc_comments: bool,
+    /// Emit an enum with a single data-carrying variant as that variant's
+    /// payload instead of a one-member union
+    #[serde(default)]
+    pub collapse_single_variant_enums: bool,
+    /// Also export one object per source module bundling its commands,
+    /// e.g. `export const user = { getUser, listUsers }`
+    #[serde(default)]
+    pub group_commands_by_module: bool,
+    /// Prefix every command function with its module path below the crate
+    /// root (`user::get_by_id` → `userGetById`). Invoke targets keep the
+    /// command name
+    #[serde(default)]
+    pub prefix_with_module: bool,
+    /// How enums whose variants are all units are declared
+    #[serde(default)]
+    pub unit_enum_style: UnitEnumStyle,
+    /// How optional members (`#[ts(optional)]`, `skip_serializing_if`, ...)
+    /// are typed
+    #[serde(default)]
+    pub optional_fields: OptionalFieldStyle,
+    /// How `#[serde(flatten)]` fields are declared
+    #[serde(default)]
+    pub flatten_style: FlattenStyle,
+}
+
+/// Type of an optional interface member, which matters under TypeScript's
+/// `exactOptionalPropertyTypes`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionalFieldStyle {
+    /// `field?: T`: the key may be missing, but is never `undefined`
+    #[default]
+    Exact,
+    /// `field?: T | undefined`, for code that assigns `undefined` to the
+    /// member explicitly while the flag is on
+    Undefined,
+}
+
+/// Declaration of a struct with `#[serde(flatten)]` fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlattenStyle {
+    /// `export type User = { id: number } & Audit & { [key: string]: string }`
+    #[default]
+    Intersection,
+    /// `export interface User { id: number; createdBy: string; [key: string]: ... }`:
+    /// the members of flattened structs are copied in, flattened maps
+    /// become an index signature
+    Inline,
+    /// `export type User = { id: number; [key: string]: ... } & Audit`:
+    /// flattened maps become an index signature, flattened structs stay
+    /// intersected
+    IndexSignature,
+}
+
+/// TypeScript declaration for an enum whose variants are all units
+/// (serialized as strings, or as numbers through `serde_repr`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UnitEnumStyle {
+    /// `export type Level = "Low" | "High"` (or `1 | 10` for `serde_repr`)
+    #[default]
+    Union,
+    /// `export enum Level { Low = "Low", High = "High" }` (or `Low = 1`)
+    Enum,
+}
+
+/// TypeScript type of `rust_decimal::Decimal` and `bigdecimal::BigDecimal`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DecimalType {
+    /// `string`, which keeps the full precision (serde's default format)
+    #[default]
+    String,
+    /// `number`, for decimals serialized as floats (`serde-float`,
+    /// `serde-with-float`, ...)
+    Number,
+}
+
+/// TypeScript spelling of `None` for an `Option<T>` command return
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OptionReturnStyle {
+    /// `Promise<T | null>`, exactly what Tauri sends over the wire
+    #[default]
+    Null,
+    /// `Promise<T | undefined>`; the generated function maps `null` to
+    /// `undefined` before returning
+    Undefined,
+}
+
+/// TypeScript type of a `Vec<(String, T)>` command return
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntriesReturnStyle {
+    /// `Promise<[string, T][]>`, exactly what Tauri sends over the wire
+    #[default]
+    Entries,
+    /// `Promise<Record<string, T>>`; the generated function converts the
+    /// pairs with `Object.fromEntries` before returning. Later pairs win
+    /// on duplicate keys
+    Record,
+}
+
+/// Type of a map whose key can't be a JSON object key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MapKeyFallback {
+    /// `Record<string, V>`, with a warning
+    Record,
+    /// `[K, V][]`, the pairs a `serde_with` `Vec<(_, _)>` adapter (or
+    /// any custom serializer writing the map as a sequence) sends. A
+    /// `bool` or unresolved key, which may be a string on the wire,
+    /// still gets `Record<string, V>` with a warning.
+    #[default]
+    Entries,
+}
+
+impl GenerationConfig {
+    /// Whether interface members are prefixed with `readonly`
+    pub fn readonly_members(&self) -> bool {
+        self.readonly_fields || self.deep_readonly
+    }
+}
+
+impl Default for GenerationConfig {
+    fn default() -> Self {
+        Self {
+            numeric_branding: false,
+            decimal_type: DecimalType::String,
+            type_guards: false,
+            quote_reserved_keys: false,
+            export_error_types: false,
+            result_wrapper: false,
+            use_type_imports: true,
+            warn_shadowed_globals: true,
+            header_pragmas: Vec::new(),
+            option_return_style: OptionReturnStyle::Null,
+            entries_return_style: EntriesReturnStyle::Entries,
+            partial_enum_records: false,
+            map_key_fallback: MapKeyFallback::Entries,
+            inline_type_aliases: false,
+            readonly_fields: false,
+            deep_readonly: false,
+            doc_comments: false,
+            collapse_single_variant_enums: false,
+            group_commands_by_module: false,
+            prefix_with_module: false,
+            unit_enum_style: UnitEnumStyle::Union,
+            optional_fields: OptionalFieldStyle::Exact,
+            flatten_style: FlattenStyle::Intersection,
+        }
+    }
+}
+
+fn default_true() -> bool {
+    true
 }
 
 impl Config {
@@ -87,39 +553,64 @@ impl Config {
     /// This should be called before writing output files.
     pub fn ensure_output_directories(&self) -> Result<()> {
         // Note: create_dir_all is idempotent and handles race conditions
-        if let Some(parent) = self.output.types_file.parent() {
-            if !parent.as_os_str().is_empty() {
-                fs::create_dir_all(parent).with_context(|| {
-                    format!("Failed to create output directory: {}", parent.display())
-                })?;
-            }
-        }
-
-        if let Some(parent) = self.output.commands_file.parent() {
-            if !parent.as_os_str().is_empty() {
-                fs::create_dir_all(parent).with_context(|| {
-                    format!("Failed to create output directory: {}", parent.display())
-                })?;
+        for file in self.output.files() {
+            if let Some(parent) = file.parent() {
+                if !parent.as_os_str().is_empty() {
+                    fs::create_dir_all(parent).with_context(|| {
+                        format!("Failed to create output directory: {}", parent.display())
+                    })?;
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Output files that live inside `source_dir`.
+    ///
+    /// The scanner skips these so the generator never reads back its own
+    /// output (a `.rs` one included). Only the files themselves: a
+    /// hand-written module next to them is still scanned.
+    pub fn output_files_in_source(&self) -> Vec<PathBuf> {
+        self.output
+            .files()
+            .into_iter()
+            .filter(|file| file.starts_with(&self.input.source_dir))
+            .map(Path::to_path_buf)
+            .collect()
+    }
+
     /// Generate a default configuration
     pub fn default_config() -> Self {
         Config {
             input: InputConfig {
                 source_dir: PathBuf::from("src-tauri/src"),
                 exclude: vec!["tests".to_string(), "target".to_string()],
+                include: Vec::new(),
                 use_cargo_expand: false,
                 cargo_manifest: None,
+                include_test_commands: false,
+                command_attributes: vec![],
+                follow_symlinks: false,
+                path_dependencies: false,
+                external_crate_prefixes: vec![],
+                active_features: vec![],
             },
             output: OutputConfig {
                 types_file: PathBuf::from("src/generated/types.ts"),
                 commands_file: PathBuf::from("src/generated/commands.ts"),
+                single_file: None,
+                target: OutputTarget::TypeScript,
+                command_map_file: None,
+                zod_file: None,
+                events_file: None,
+                types_namespace: None,
+                header: None,
+                header_timestamp: false,
+                style: StyleConfig::default(),
             },
             naming: NamingConfig::default(),
+            generation: GenerationConfig::default(),
         }
     }
 
@@ -360,19 +851,37 @@ commands_file = "commands.ts"
             input: InputConfig {
                 source_dir: PathBuf::from("src"),
                 exclude: vec!["tests".to_string()],
+                include: vec![],
                 use_cargo_expand: false,
                 cargo_manifest: None,
+                include_test_commands: false,
+                command_attributes: vec![],
+                follow_symlinks: false,
+                path_dependencies: false,
+                external_crate_prefixes: vec![],
+                active_features: vec![],
             },
             output: OutputConfig {
                 types_file: PathBuf::from("types.ts"),
                 commands_file: PathBuf::from("commands.ts"),
+                single_file: None,
+                target: OutputTarget::TypeScript,
+                command_map_file: None,
+                zod_file: None,
+                events_file: None,
+                types_namespace: None,
+                header: None,
+                header_timestamp: false,
+                style: StyleConfig::default(),
             },
             naming: NamingConfig {
                 type_prefix: "I".to_string(),
                 type_suffix: "".to_string(),
                 function_prefix: "".to_string(),
                 function_suffix: "".to_string(),
+                type_overrides: Default::default(),
             },
+            generation: GenerationConfig::default(),
         };
 
         config.save(&config_path).unwrap();
@@ -393,6 +902,94 @@ commands_file = "commands.ts"
         assert!(naming.function_suffix.is_empty());
     }
 
+    #[test]
+    fn test_load_config_with_generation() {
+        let dir = tempdir().unwrap();
+        let source_dir = dir.path().join("src");
+        fs::create_dir_all(&source_dir).unwrap();
+
+        let config_content = format!(
+            r#"
+[input]
+source_dir = '{}'
+
+[output]
+types_file = "types.ts"
+commands_file = "commands.ts"
+
+[generation]
+numeric_branding = true
+"#,
+            source_dir.display()
+        );
+
+        let config_path = dir.path().join("config.toml");
+        fs::write(&config_path, config_content).unwrap();
+
+        let config = Config::load(&config_path).unwrap();
+
+        assert!(config.generation.numeric_branding);
+        // Switches left out of the section keep their defaults.
+        assert!(config.generation.use_type_imports);
+    }
+
+    #[test]
+    fn test_style_indent_forms() {
+        let style: StyleConfig = toml::from_str("indent = 4\nquote = \"single\"").unwrap();
+        assert_eq!(style.indent.as_str(), "    ");
+        assert_eq!(style.quote, QuoteStyle::Single);
+
+        let style: StyleConfig = toml::from_str("indent = \"\\t\"").unwrap();
+        assert_eq!(style.indent.as_str(), "\t");
+        assert_eq!(style.quote, QuoteStyle::Double);
+
+        assert_eq!(StyleConfig::default().indent.as_str(), "  ");
+        assert!(toml::from_str::<StyleConfig>("indent = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_output_banner() {
+        let mut output = Config::default_config().output;
+        let now = UNIX_EPOCH + std::time::Duration::from_secs(1_709_285_400);
+        assert_eq!(output.banner(now), None);
+
+        output.header = Some("Generated by tauri-codegen {version}\n\nDo not edit".to_string());
+        assert_eq!(
+            output.banner(now).unwrap(),
+            format!(
+                "// Generated by tauri-codegen {}\n//\n// Do not edit\n",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+
+        output.header = None;
+        output.header_timestamp = true;
+        assert_eq!(
+            output.banner(now).unwrap(),
+            format!("{}// Generated at 2024-03-01T09:30:00Z\n", DEFAULT_BANNER)
+        );
+    }
+
+    #[test]
+    fn test_output_files_in_source() {
+        let mut config = Config::default_config();
+        config.input.source_dir = PathBuf::from("src-tauri/src");
+        config.output.types_file = PathBuf::from("src-tauri/src/generated/types.ts");
+        config.output.commands_file = PathBuf::from("src-tauri/src/commands.ts");
+        assert_eq!(
+            config.output_files_in_source(),
+            vec![
+                PathBuf::from("src-tauri/src/generated/types.ts"),
+                PathBuf::from("src-tauri/src/commands.ts"),
+            ]
+        );
+
+        // Outside the source tree: nothing to skip.
+        config.output.types_file = PathBuf::from("src/generated/types.ts");
+        config.output.commands_file = PathBuf::from("src/generated/commands.ts");
+        assert!(config.output_files_in_source().is_empty());
+    }
+
     #[test]
     fn test_config_with_empty_exclude() {
         let dir = tempdir().unwrap();
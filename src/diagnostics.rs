@@ -13,18 +13,44 @@
 //!    parser/generator where threading `&Diagnostics` through every
 //!    helper would double the signature noise. The pipeline installs
 //!    a thread-local at startup and helpers read it via `current()`.
-//!    Single-threaded by design — this CLI never forks work onto
-//!    rayon/tokio — so a thread-local `Cell` is both sound and cheap.
+//!    Per-file parsing runs on rayon, so the pipeline also installs the
+//!    sink on each worker before parsing a file there. A `Cell` per
+//!    thread stays sound and cheap: the sink is `Copy`.
+//!
+//! Workers finish in any order, so warnings raised while parsing in
+//! parallel would interleave differently from run to run. The pipeline
+//! wraps each file's parse in [`buffered`] and hands the captured
+//! reports to [`Diagnostics::replay`] during its sequential merge, which
+//! walks the files in scan order: the output is deterministic.
+//!
+//! Warnings and errors tied to a source file go through `warn_in` /
+//! `error_in`, so `--annotations github` can attach them to that file.
 //!
 //! Future structured output (JSON / tracing) replaces this module wholesale.
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// How warnings and errors are written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum AnnotationFormat {
+    /// `Warning: ...` / `Error: ...` on stderr
+    #[default]
+    Plain,
+    /// GitHub Actions workflow commands (`::warning file=...::...`) on
+    /// stdout, which the runner shows inline on the pull request
+    Github,
+}
 
 /// Reports progress, warnings, and verbose-only detail to the terminal.
 #[derive(Debug, Clone, Copy)]
 pub struct Diagnostics {
     verbose: bool,
+    annotations: AnnotationFormat,
+    /// Write status lines and annotations to stderr, keeping stdout for
+    /// generated output
+    status_on_stderr: bool,
 }
 
 impl Default for Diagnostics {
@@ -35,7 +61,23 @@ impl Default for Diagnostics {
 
 impl Diagnostics {
     pub fn new(verbose: bool) -> Self {
-        Self { verbose }
+        Self {
+            verbose,
+            annotations: AnnotationFormat::Plain,
+            status_on_stderr: false,
+        }
+    }
+
+    /// Write warnings and errors in `format` instead of plain text.
+    pub fn with_annotations(mut self, annotations: AnnotationFormat) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Write status lines and annotations to stderr instead of stdout.
+    pub fn with_status_on_stderr(mut self) -> Self {
+        self.status_on_stderr = true;
+        self
     }
 
     pub fn verbose(&self) -> bool {
@@ -45,17 +87,70 @@ impl Diagnostics {
     /// Status lines that users expect to see on every run
     /// ("Generated: …", "Parsed N commands", "Done!").
     pub fn info(&self, msg: impl Display) {
-        println!("{}", msg);
+        if self.status_on_stderr {
+            eprintln!("{}", msg);
+        } else {
+            println!("{}", msg);
+        }
     }
 
     /// Non-fatal warnings that should always be visible.
     pub fn warn(&self, msg: impl Display) {
-        eprintln!("Warning: {}", msg);
+        self.report(Level::Warning, None, msg);
+    }
+
+    /// A warning about `file`, at `line` when known. `msg` should still
+    /// name the file: plain output prints it as is.
+    pub fn warn_in(&self, file: &Path, line: Option<usize>, msg: impl Display) {
+        self.report(Level::Warning, Some((file, line)), msg);
     }
 
     /// Hard errors emitted before the pipeline bails; visible by default.
     pub fn error(&self, msg: impl Display) {
-        eprintln!("Error: {}", msg);
+        self.report(Level::Error, None, msg);
+    }
+
+    /// An error about `file`, at `line` when known; see [`Self::warn_in`].
+    pub fn error_in(&self, file: &Path, line: Option<usize>, msg: impl Display) {
+        self.report(Level::Error, Some((file, line)), msg);
+    }
+
+    fn report(&self, level: Level, location: Option<(&Path, Option<usize>)>, msg: impl Display) {
+        let captured = BUFFER.with(|buffer| match buffer.borrow_mut().as_mut() {
+            Some(reports) => {
+                reports.push(Report {
+                    level,
+                    file: location.map(|(file, _)| file.to_path_buf()),
+                    line: location.and_then(|(_, line)| line),
+                    message: msg.to_string(),
+                });
+                true
+            }
+            None => false,
+        });
+        if captured {
+            return;
+        }
+        match self.annotations {
+            AnnotationFormat::Plain => eprintln!("{}: {}", level.label(), msg),
+            AnnotationFormat::Github => {
+                let annotation = github_annotation(level, location, &msg.to_string());
+                if self.status_on_stderr {
+                    eprintln!("{}", annotation);
+                } else {
+                    println!("{}", annotation);
+                }
+            }
+        }
+    }
+
+    /// Write the warnings and errors captured by [`buffered`], in the
+    /// order they were raised.
+    pub fn replay(&self, reports: Vec<Report>) {
+        for report in reports {
+            let location = report.file.as_deref().map(|file| (file, report.line));
+            self.report(report.level, location, report.message);
+        }
     }
 
     /// Internal detail useful for debugging scanning/parsing.
@@ -67,12 +162,107 @@ impl Diagnostics {
     }
 }
 
+/// A warning or error captured by [`buffered`] instead of written
+#[derive(Debug, Clone)]
+pub struct Report {
+    level: Level,
+    file: Option<PathBuf>,
+    line: Option<usize>,
+    message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Warning,
+    Error,
+}
+
+impl Level {
+    fn label(self) -> &'static str {
+        match self {
+            Level::Warning => "Warning",
+            Level::Error => "Error",
+        }
+    }
+}
+
+/// `msg` as a GitHub Actions workflow command, e.g.
+/// `::warning file=src/lib.rs,line=3::Unknown type`. Leading indentation
+/// (list items under a heading) is dropped: annotations stand alone.
+fn github_annotation(level: Level, location: Option<(&Path, Option<usize>)>, msg: &str) -> String {
+    let command = match level {
+        Level::Warning => "warning",
+        Level::Error => "error",
+    };
+    let mut properties = Vec::new();
+    if let Some((file, line)) = location {
+        properties.push(format!(
+            "file={}",
+            escape_property(&file.display().to_string())
+        ));
+        if let Some(line) = line {
+            properties.push(format!("line={}", line));
+        }
+    }
+    let properties = if properties.is_empty() {
+        String::new()
+    } else {
+        format!(" {}", properties.join(","))
+    };
+    format!(
+        "::{}{}::{}",
+        command,
+        properties,
+        escape_data(msg.trim_start())
+    )
+}
+
+/// Escape a workflow command's message
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Line of the syntax error behind `err`, if it is one
+pub fn error_line(err: &anyhow::Error) -> Option<usize> {
+    err.downcast_ref::<syn::Error>()
+        .map(|e| e.span().start().line)
+        .filter(|&line| line > 0)
+}
+
 thread_local! {
     /// Ambient diagnostics sink for helpers that can't easily take a
     /// `&Diagnostics` parameter (e.g. the attribute-parsing helpers deep
     /// inside the serde walker). Defaults to a silent-default sink before
     /// `Pipeline::run` installs the real one.
-    static CURRENT: Cell<Diagnostics> = const { Cell::new(Diagnostics { verbose: false }) };
+    static CURRENT: Cell<Diagnostics> = const {
+        Cell::new(Diagnostics {
+            verbose: false,
+            annotations: AnnotationFormat::Plain,
+            status_on_stderr: false,
+        })
+    };
+}
+
+thread_local! {
+    /// Warnings and errors captured on this thread while [`buffered`] runs
+    static BUFFER: RefCell<Option<Vec<Report>>> = const { RefCell::new(None) };
+}
+
+/// Run `f`, capturing the warnings and errors it reports on this thread
+/// instead of writing them, for [`Diagnostics::replay`]. Status and
+/// `--verbose` detail are still written as they come.
+pub fn buffered<T>(f: impl FnOnce() -> T) -> (T, Vec<Report>) {
+    let outer = BUFFER.with(|buffer| buffer.replace(Some(Vec::new())));
+    let value = f();
+    let reports = BUFFER.with(|buffer| buffer.replace(outer));
+    (value, reports.unwrap_or_default())
 }
 
 /// Install the ambient sink for the duration of the current thread. Call
@@ -124,4 +314,48 @@ mod tests {
             "spawned thread must see the silent default, not inherit from parent"
         );
     }
+
+    #[test]
+    fn github_annotation_format() {
+        let file = Path::new("src/commands.rs");
+        assert_eq!(
+            github_annotation(
+                Level::Warning,
+                Some((file, Some(12))),
+                "Failed to parse commands in src/commands.rs: expected `;`"
+            ),
+            "::warning file=src/commands.rs,line=12::Failed to parse commands in src/commands.rs: expected `;`"
+        );
+        assert_eq!(
+            github_annotation(Level::Error, Some((file, None)), "  src/commands.rs: stale"),
+            "::error file=src/commands.rs::src/commands.rs: stale"
+        );
+        assert_eq!(
+            github_annotation(Level::Warning, None, "100% unknown\nsecond line"),
+            "::warning::100%25 unknown%0Asecond line"
+        );
+        assert_eq!(escape_property("C:\\a,b.rs"), "C%3A\\a%2Cb.rs");
+    }
+
+    #[test]
+    fn error_line_of_syntax_errors() {
+        let err = anyhow::Error::from(syn::parse_file("fn ok() {}\nfn broken( {}").unwrap_err());
+        assert_eq!(error_line(&err), Some(2));
+        assert_eq!(error_line(&anyhow::anyhow!("not a syntax error")), None);
+    }
+
+    #[test]
+    fn buffered_reports_are_replayed_not_written() {
+        let ((), reports) = buffered(|| {
+            warn("first");
+            current().warn_in(Path::new("src/lib.rs"), Some(3), "second");
+        });
+
+        let messages: Vec<_> = reports.iter().map(|r| r.message.as_str()).collect();
+        assert_eq!(messages, ["first", "second"]);
+        assert_eq!(reports[1].file.as_deref(), Some(Path::new("src/lib.rs")));
+        assert_eq!(reports[1].line, Some(3));
+        // Capturing ends with the closure
+        assert!(BUFFER.with(|buffer| buffer.borrow().is_none()));
+    }
 }
@@ -0,0 +1,172 @@
+//! Output backends.
+//!
+//! Scanning, parsing, resolving and collecting only produce `RustStruct`s,
+//! `RustEnum`s, aliases and `TauriCommand`s; a [`CodeGenerator`] turns
+//! those into source files. TypeScript is the default, `[output] target`
+//! picks another language.
+
+use std::path::PathBuf;
+
+use crate::config::{OutputConfig, OutputTarget};
+use crate::models::{RustEnum, RustStruct, RustTypeAlias, TauriCommand, TauriEvent};
+
+use super::commands_gen::{
+    collect_channel_type_aliases, generate_bindings_file, generate_command_map_file,
+    generate_commands_file,
+};
+use super::events_gen::generate_events_file;
+use super::kotlin::KotlinGenerator;
+use super::types_gen::{generate_numeric_aliases, generate_type_declarations, wrap_in_namespace};
+use super::zod::generate_zod_file;
+use super::GeneratorContext;
+
+/// Everything a backend renders: the exported types, the commands and the
+/// events.
+#[derive(Debug, Clone, Copy)]
+pub struct Bindings<'a> {
+    pub structs: &'a [RustStruct],
+    pub enums: &'a [RustEnum],
+    pub aliases: &'a [RustTypeAlias],
+    pub commands: &'a [TauriCommand],
+    pub events: &'a [TauriEvent],
+}
+
+/// Renders the output files of one target language.
+pub trait CodeGenerator {
+    /// Contents of `types_file`
+    fn types_file(&self, bindings: &Bindings) -> String;
+    /// Contents of `commands_file`, referring to the types in `types_file`
+    fn commands_file(&self, bindings: &Bindings) -> String;
+    /// Contents of `single_file`: the types, then the commands
+    fn single_file(&self, bindings: &Bindings) -> String;
+    /// Contents of `command_map_file`, or `None` if the language has no
+    /// such declaration
+    fn command_map_file(&self, _bindings: &Bindings) -> Option<String> {
+        None
+    }
+    /// Contents of `zod_file`, or `None` if the language has no Zod
+    /// schemas
+    fn zod_file(&self, _bindings: &Bindings) -> Option<String> {
+        None
+    }
+    /// Contents of `events_file`, or `None` if the language has no event
+    /// listeners
+    fn events_file(&self, _bindings: &Bindings) -> Option<String> {
+        None
+    }
+}
+
+/// The backend for `output.target`, rendering with `ctx` (which already
+/// has every exported type registered).
+pub fn for_target(output: &OutputConfig, ctx: GeneratorContext) -> Box<dyn CodeGenerator> {
+    match output.target {
+        OutputTarget::TypeScript => Box::new(TypeScriptGenerator {
+            ctx,
+            types_file: output.types_module().to_path_buf(),
+            commands_file: output.commands_file.clone(),
+            command_map_file: output.command_map_file.clone(),
+            zod_schemas: output.zod_file.is_some(),
+            events_file: output.events_file.clone(),
+        }),
+        OutputTarget::Kotlin => Box::new(KotlinGenerator::new(ctx)),
+    }
+}
+
+/// Interfaces and type aliases in `types_file`, `invoke` wrappers in
+/// `commands_file`.
+pub struct TypeScriptGenerator {
+    ctx: GeneratorContext,
+    /// Where the types are imported from: `types_file`, or `single_file`
+    types_file: PathBuf,
+    commands_file: PathBuf,
+    command_map_file: Option<PathBuf>,
+    zod_schemas: bool,
+    events_file: Option<PathBuf>,
+}
+
+impl TypeScriptGenerator {
+    /// Every type declaration, followed by the channel payload aliases and
+    /// (under `numeric_branding`) the numeric aliases; all inside the
+    /// `types_namespace` if there is one.
+    fn type_declarations(&self, bindings: &Bindings) -> String {
+        let ctx = &self.ctx;
+        let mut declarations =
+            generate_type_declarations(bindings.structs, bindings.enums, bindings.aliases, ctx);
+
+        let channel_aliases = collect_channel_type_aliases(bindings.commands, ctx);
+        for (alias_name, inner_ts) in &channel_aliases {
+            declarations.push_str(&format!("export type {} = {};\n", alias_name, inner_ts));
+        }
+        if !channel_aliases.is_empty() {
+            declarations.push('\n');
+        }
+
+        declarations.push_str(&generate_numeric_aliases(
+            bindings.structs,
+            bindings.enums,
+            bindings.aliases,
+            bindings.commands,
+            ctx,
+        ));
+        match ctx.types_namespace() {
+            Some(namespace) => wrap_in_namespace(&declarations, namespace, ctx),
+            None => declarations,
+        }
+    }
+}
+
+impl CodeGenerator for TypeScriptGenerator {
+    fn types_file(&self, bindings: &Bindings) -> String {
+        self.ctx.file_header() + &self.type_declarations(bindings)
+    }
+
+    fn commands_file(&self, bindings: &Bindings) -> String {
+        generate_commands_file(
+            bindings.commands,
+            &self.types_file,
+            &self.commands_file,
+            &self.ctx,
+        )
+    }
+
+    fn single_file(&self, bindings: &Bindings) -> String {
+        generate_bindings_file(
+            &self.type_declarations(bindings),
+            bindings.commands,
+            &self.types_file,
+            &self.ctx,
+        )
+    }
+
+    fn command_map_file(&self, bindings: &Bindings) -> Option<String> {
+        let map_file = self.command_map_file.as_ref()?;
+        Some(generate_command_map_file(
+            bindings.commands,
+            &self.types_file,
+            map_file,
+            &self.ctx,
+        ))
+    }
+
+    fn zod_file(&self, bindings: &Bindings) -> Option<String> {
+        self.zod_schemas.then(|| {
+            generate_zod_file(
+                bindings.structs,
+                bindings.enums,
+                bindings.aliases,
+                bindings.commands,
+                &self.ctx,
+            )
+        })
+    }
+
+    fn events_file(&self, bindings: &Bindings) -> Option<String> {
+        let events_file = self.events_file.as_ref()?;
+        Some(generate_events_file(
+            bindings.events,
+            &self.types_file,
+            events_file,
+            &self.ctx,
+        ))
+    }
+}
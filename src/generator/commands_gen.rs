@@ -1,9 +1,15 @@
+use crate::config::{EntriesReturnStyle, OptionReturnStyle};
 use crate::models::{CommandArg, RustType, TauriCommand};
-use crate::utils::to_camel_case;
-use std::collections::HashSet;
+use crate::utils::{to_camel_case, to_pascal_case};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
-use super::{type_mapper::rust_to_typescript, GeneratorContext};
+use super::{
+    jsdoc::render_jsdoc,
+    sorted_by_name, ts_identifier,
+    type_mapper::{branded_numeric_alias, rust_to_typescript},
+    GeneratorContext,
+};
 
 /// Generate TypeScript commands file content
 pub fn generate_commands_file(
@@ -12,48 +18,271 @@ pub fn generate_commands_file(
     commands_file_path: &Path,
     ctx: &GeneratorContext,
 ) -> String {
-    let mut output = String::new();
+    let import_kw = if ctx.generation().use_type_imports {
+        "import type"
+    } else {
+        "import"
+    };
+
+    let mut output = ctx.file_header();
+    output.push_str(&tauri_import(commands, ctx));
+    output.push_str(&types_import(
+        commands,
+        import_kw,
+        ctx.generation().result_wrapper,
+        types_file_path,
+        commands_file_path,
+        ctx,
+    ));
+    output.push_str(&args_schemas_import(commands, commands_file_path, ctx));
+    output.push('\n');
+    output.push_str(&generate_command_functions(commands, ctx));
+    output
+}
+
+/// `[output] command_map_file`: declarations only, for a hand-written
+/// invoke wrapper. Every command maps to its `[args, result]` pair, keyed
+/// by the name it is invoked under:
+///
+/// ```typescript
+/// export interface CommandMap {
+///   get_user: [{ id: number }, User];
+/// }
+/// ```
+///
+/// The args object uses the keys Tauri expects (camelCase unless the
+/// command says `rename_all = "snake_case"`); the result is what `invoke`
+/// resolves to.
+pub fn generate_command_map_file(
+    commands: &[TauriCommand],
+    types_file_path: &Path,
+    map_file_path: &Path,
+    ctx: &GeneratorContext,
+) -> String {
+    let mut output = ctx.file_header();
+    output.push_str(&types_import(
+        commands,
+        "import type",
+        false,
+        types_file_path,
+        map_file_path,
+        ctx,
+    ));
+    output.push('\n');
+
+    output.push_str("export interface CommandMap {\n");
+    for cmd in sorted_by_name(commands, |c| &c.name) {
+        let args = if cmd.args.is_empty() {
+            "Record<string, never>".to_string()
+        } else {
+            let members: Vec<String> = cmd
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        "{}: {}",
+                        ctx.property_key(&cmd.arg_wire_name(arg)),
+                        rust_to_typescript(&arg.ty, ctx)
+                    )
+                })
+                .collect();
+            format!("{{ {} }}", members.join("; "))
+        };
+        output.push_str(&format!(
+            "{}{}: [{}, {}];\n",
+            ctx.indent(1),
+            ctx.property_key(&cmd.name),
+            args,
+            generate_return_type(&cmd.return_type, ctx)
+        ));
+    }
+    output.push_str("}\n");
+    output
+}
+
+/// `import type { A, B } from "./types";` for the custom types `commands`
+/// use, relative from `file_path` to `types_file_path`, their error types
+/// included when `error_types`. Empty when they use none.
+fn types_import(
+    commands: &[TauriCommand],
+    import_kw: &str,
+    error_types: bool,
+    types_file_path: &Path,
+    file_path: &Path,
+    ctx: &GeneratorContext,
+) -> String {
+    // Collect all custom types used in commands
+    let used_types = collect_used_types(commands, error_types, ctx);
+    if used_types.is_empty() {
+        return String::new();
+    }
 
-    // Add header comment
-    output.push_str("// This file was auto-generated by tauri-ts-generator\n");
-    output.push_str("// Do not edit this file manually\n\n");
+    // Calculate relative import path from this file to the types file
+    let import_path = calculate_relative_import(types_file_path, file_path);
+    let types_list = ctx.type_import_names(used_types);
+    format!(
+        "{} {{ {} }} from {};\n",
+        import_kw,
+        types_list.join(", "),
+        ctx.string_literal(&import_path)
+    )
+}
+
+/// `[output] single_file`: one module holding the type declarations
+/// (`generate_type_declarations` plus any trailing aliases) followed by
+/// the command functions. The types need no import, so only the Tauri
+/// import (and the args schemas one) remains.
+pub fn generate_bindings_file(
+    type_declarations: &str,
+    commands: &[TauriCommand],
+    bindings_file_path: &Path,
+    ctx: &GeneratorContext,
+) -> String {
+    let mut output = ctx.file_header();
+    output.push_str(&tauri_import(commands, ctx));
+    output.push_str(&args_schemas_import(commands, bindings_file_path, ctx));
+    output.push('\n');
+    output.push_str(type_declarations);
+    output.push_str(&generate_command_functions(commands, ctx));
+    output
+}
 
-    // Add Tauri import — include Channel when any command uses one
+/// `import { invoke } from "@tauri-apps/api/core";`, including `Channel`
+/// when any command uses one
+fn tauri_import(commands: &[TauriCommand], ctx: &GeneratorContext) -> String {
     let mut tauri_imports = vec!["invoke"];
     if commands_use_channels(commands) {
         tauri_imports.push("Channel");
     }
-    output.push_str(&format!(
-        "import {{ {} }} from \"@tauri-apps/api/core\";\n",
-        tauri_imports.join(", ")
-    ));
-
-    // Collect all custom types used in commands
-    let used_types = collect_used_types(commands, ctx);
+    format!(
+        "import {{ {} }} from {};\n",
+        tauri_imports.join(", "),
+        ctx.string_literal("@tauri-apps/api/core")
+    )
+}
 
-    if !used_types.is_empty() {
-        // Calculate relative import path from commands file to types file
-        let import_path = calculate_relative_import(types_file_path, commands_file_path);
-        let mut types_list: Vec<_> = used_types.into_iter().collect();
-        types_list.sort();
-        output.push_str(&format!(
-            "import type {{ {} }} from \"{}\";\n",
-            types_list.join(", "),
-            import_path
-        ));
+/// `import { GetUserArgsSchema } from "./schemas";`, naming the args
+/// schema of every command taking arguments, when they are validated
+/// against `zod_file`
+fn args_schemas_import(
+    commands: &[TauriCommand],
+    file_path: &Path,
+    ctx: &GeneratorContext,
+) -> String {
+    let Some(zod_file) = ctx.args_schemas() else {
+        return String::new();
+    };
+    let names: Vec<String> = sorted_by_name(commands, |c| &c.name)
+        .into_iter()
+        .filter(|cmd| !cmd.args.is_empty())
+        .map(|cmd| args_schema_name(cmd, ctx))
+        .collect();
+    if names.is_empty() {
+        return String::new();
     }
+    format!(
+        "import {{ {} }} from {};\n",
+        names.join(", "),
+        ctx.string_literal(&calculate_relative_import(zod_file, file_path))
+    )
+}
 
-    output.push('\n');
+/// Everything after the imports: the optional result helper, one function
+/// per command and the per-module groups.
+fn generate_command_functions(commands: &[TauriCommand], ctx: &GeneratorContext) -> String {
+    let mut output = String::new();
+
+    if ctx.generation().result_wrapper {
+        output.push_str(&reindent(RESULT_WRAPPER_HELPER, ctx));
+        output.push('\n');
+    }
 
     // Generate function for each command
-    for cmd in commands {
+    for cmd in sorted_by_name(commands, |c| &c.name) {
+        warn_if_shadows_global(cmd, ctx);
         output.push_str(&generate_command_function(cmd, ctx));
         output.push('\n');
     }
 
+    if ctx.generation().group_commands_by_module {
+        output.push_str(&generate_module_groups(commands, ctx));
+    }
+
     output
 }
 
+/// `export const user = { getUser, listUsers };` per source module, named
+/// after the module path below the crate root (`api::user` → `apiUser`).
+/// Commands defined at the crate root stay ungrouped.
+fn generate_module_groups(commands: &[TauriCommand], ctx: &GeneratorContext) -> String {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for cmd in commands {
+        let segments = module_segments(cmd, ctx);
+        if segments.is_empty() {
+            continue;
+        }
+        let group = ts_identifier(&to_camel_case(&segments.join("_")));
+        groups
+            .entry(group)
+            .or_default()
+            .push(command_function_name(cmd, ctx));
+    }
+
+    let function_names: HashSet<String> = commands
+        .iter()
+        .map(|cmd| command_function_name(cmd, ctx))
+        .collect();
+
+    let mut output = String::new();
+    for (group, members) in groups {
+        if function_names.contains(&group) {
+            crate::diagnostics::warn(format!(
+                "Not grouping commands of module '{}': a command function is already named '{}'.",
+                group, group
+            ));
+            continue;
+        }
+        output.push_str(&format!("export const {} = {{\n", group));
+        for member in members {
+            output.push_str(&format!("{}{},\n", ctx.indent(1), member));
+        }
+        output.push_str("};\n\n");
+    }
+    output
+}
+
+/// Runtime glue for `[generation] result_wrapper`: commands resolve to an
+/// `Ok<T> | Err<E>` union instead of rejecting.
+const RESULT_WRAPPER_HELPER: &str = "\
+export type Ok<T> = { ok: true; value: T };
+export type Err<E> = { ok: false; error: E };
+
+async function invokeResult<T, E>(cmd: string, args?: Record<string, unknown>): Promise<Ok<T> | Err<E>> {
+  try {
+    return { ok: true, value: await invoke<T>(cmd, args) };
+  } catch (error) {
+    return { ok: false, error: error as E };
+  }
+}
+";
+
+/// Re-indent a snippet written with two-space indentation using the
+/// configured unit.
+fn reindent(snippet: &str, ctx: &GeneratorContext) -> String {
+    snippet
+        .lines()
+        .map(|line| {
+            let code = line.trim_start_matches(' ');
+            let level = (line.len() - code.len()) / 2;
+            if code.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", ctx.indent(level), code)
+            }
+        })
+        .collect()
+}
+
 /// Compute `(ts_alias_name, ts_inner_type)` pairs for all Channel args across
 /// all commands. These are written as `export type Foo = Bar;` lines in types.ts.
 ///
@@ -93,7 +322,7 @@ fn commands_use_channels(commands: &[TauriCommand]) -> bool {
 fn type_uses_channel(ty: &RustType) -> bool {
     match ty {
         RustType::Channel(_) => true,
-        RustType::Vec(inner) | RustType::Option(inner) | RustType::Result(inner) => {
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Result { ok: inner, .. } => {
             type_uses_channel(inner)
         }
         RustType::HashMap { key, value } => type_uses_channel(key) || type_uses_channel(value),
@@ -102,8 +331,14 @@ fn type_uses_channel(ty: &RustType) -> bool {
     }
 }
 
-/// Collect all custom type names used in commands
-fn collect_used_types(commands: &[TauriCommand], ctx: &GeneratorContext) -> HashSet<String> {
+/// Collect all custom type names used in commands. With `error_types`
+/// (the functions name them under `result_wrapper`), the error types the
+/// signatures reference count as well.
+fn collect_used_types(
+    commands: &[TauriCommand],
+    error_types: bool,
+    ctx: &GeneratorContext,
+) -> HashSet<String> {
     let mut types = HashSet::new();
 
     for cmd in commands {
@@ -116,6 +351,13 @@ fn collect_used_types(commands: &[TauriCommand], ctx: &GeneratorContext) -> Hash
         if let Some(ret_type) = &cmd.return_type {
             collect_types_from_rust_type(ret_type, ctx, &mut types);
         }
+
+        if let Some(err) = cmd
+            .error_type()
+            .filter(|err| error_types && is_exported(err, ctx))
+        {
+            collect_types_from_rust_type(err, ctx, &mut types);
+        }
     }
 
     types
@@ -123,7 +365,7 @@ fn collect_used_types(commands: &[TauriCommand], ctx: &GeneratorContext) -> Hash
 
 /// Collect custom type names from a RustType, filtered by the generator
 /// context (only known-registered types count) and formatted with any
-/// configured prefix/suffix.
+/// configured prefix/suffix. Branded numeric aliases count as well.
 ///
 /// A Rust field typed as `crate::types::User` surfaces here as the full
 /// path string; the context registers types by simple name only, so we
@@ -133,46 +375,220 @@ fn collect_types_from_rust_type(
     ctx: &GeneratorContext,
     types: &mut HashSet<String>,
 ) {
-    crate::models::walk_custom_type_names(ty, &mut |name| {
-        let simple = crate::utils::simple_name(name);
-        if ctx.is_custom_type(simple) {
-            types.insert(ctx.format_type_name(simple));
+    crate::models::walk_types(ty, &mut |node| match node {
+        RustType::Custom { name, .. } => {
+            let simple = crate::utils::simple_name(name);
+            if ctx.is_custom_type(simple) {
+                types.insert(ctx.format_type_name(simple));
+            }
+        }
+        // Under `numeric_branding`, `id: I32` needs `I32` imported too.
+        RustType::Primitive(name) => {
+            if let Some(alias) = branded_numeric_alias(name, ctx) {
+                types.insert(ctx.format_type_name(&alias));
+            }
         }
+        _ => {}
     });
 }
 
+/// Module path of the file a command is defined in, below the crate root
+/// (`["api", "user"]`). Empty at the crate root or when the file's module
+/// isn't registered.
+fn module_segments<'a>(cmd: &TauriCommand, ctx: &'a GeneratorContext) -> Vec<&'a str> {
+    ctx.module_of(&cmd.source_file)
+        .unwrap_or_default()
+        .iter()
+        .map(String::as_str)
+        .filter(|segment| *segment != "crate")
+        .collect()
+}
+
+/// Under `warn_shadowed_globals`, warn when the function generated for
+/// `cmd` takes the name of a browser global such as `fetch`
+fn warn_if_shadows_global(cmd: &TauriCommand, ctx: &GeneratorContext) {
+    let fn_name = command_function_name(cmd, ctx);
+    if ctx.generation().warn_shadowed_globals && crate::known_types::is_js_global(&fn_name) {
+        crate::diagnostics::current().warn_in(
+            &cmd.source_file,
+            None,
+            format!(
+                "Command '{}' in {} generates a function '{}' that shadows the JavaScript \
+                global of the same name in every module importing it. Rename the command, \
+                or set [naming] function_prefix.",
+                cmd.name,
+                cmd.source_file.display(),
+                fn_name
+            ),
+        );
+    }
+}
+
+/// Name of the TypeScript function generated for a command: camelCased,
+/// wrapped in the configured prefix/suffix and escaped if reserved. Under
+/// `prefix_with_module` the module path comes first (`user::get_by_id` →
+/// `userGetById`); the invoke target stays the command name.
+pub fn command_function_name(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    ts_identifier(&command_base_name(cmd, ctx))
+}
+
+/// [`command_function_name`] before reserved-word escaping, for backends
+/// with their own keyword list.
+pub fn command_base_name(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    let mut name = cmd.name.clone();
+    if ctx.generation().prefix_with_module {
+        let mut segments = module_segments(cmd, ctx);
+        segments.push(&cmd.name);
+        name = segments.join("_");
+    }
+    ctx.format_function_name(&to_camel_case(&name))
+}
+
+/// Name of the Zod schema of a command's arguments in `zod_file`:
+/// `get_user` → `GetUserArgsSchema`. Follows the function name, so
+/// `prefix_with_module` keeps it unique.
+pub fn args_schema_name(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    format!("{}ArgsSchema", to_pascal_case(&command_base_name(cmd, ctx)))
+}
+
+/// Whether the function for `cmd` validates its arguments against
+/// `zod_file` before invoking
+fn validates_args(cmd: &TauriCommand, ctx: &GeneratorContext) -> bool {
+    !cmd.args.is_empty() && ctx.args_schemas().is_some()
+}
+
+/// When commands are validated against `zod_file`, the statements parsing
+/// the arguments of `cmd` with its args schema before it is invoked, so
+/// a mismatch never reaches the backend. It throws, or under
+/// `result_wrapper` resolves to an `Err` holding the `Error`.
+fn args_validation(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
+    if !validates_args(cmd, ctx) {
+        return String::new();
+    }
+    let indent = ctx.indent(1);
+    let inner = ctx.indent(2);
+    let error = format!(
+        "new Error(`Invalid arguments for command {}: ${{error}}`)",
+        ctx.string_literal(&cmd.name)
+    );
+    let on_failure = if ctx.generation().result_wrapper {
+        format!("return {{ ok: false, error: {} }};", error)
+    } else {
+        format!("throw {};", error)
+    };
+    format!(
+        "{indent}try {{\n\
+         {inner}{}.parse({{ {} }});\n\
+         {indent}}} catch (error) {{\n\
+         {inner}{}\n\
+         {indent}}}\n",
+        args_schema_name(cmd, ctx),
+        generate_args_object(cmd),
+        on_failure
+    )
+}
+
 /// Generate a TypeScript function for a Tauri command
 fn generate_command_function(cmd: &TauriCommand, ctx: &GeneratorContext) -> String {
     let mut output = String::new();
 
-    // Function name in camelCase
-    let fn_name = to_camel_case(&cmd.name);
-    let fn_name = ctx.format_function_name(&fn_name);
+    let fn_name = command_function_name(cmd, ctx);
 
     // Generate parameter list
     let params = generate_params(&cmd.args, ctx);
 
-    // Generate return type
-    let return_type = generate_return_type(&cmd.return_type, ctx);
+    // Generate return type. Under `option_return_style = "undefined"`
+    // the function promises `T | undefined` but Tauri still sends `null`;
+    // under `entries_return_style = "record"` it promises a `Record` but
+    // Tauri still sends pairs. `invoke` keeps the wire type and the result
+    // is mapped.
+    let wire_type = generate_return_type(&cmd.return_type, ctx);
+    let mapping = return_mapping(&cmd.return_type, ctx);
+    let return_type = match mapping {
+        ReturnMapping::NullToUndefined(inner) => {
+            format!("{} | undefined", rust_to_typescript(inner, ctx))
+        }
+        ReturnMapping::EntriesToRecord(value) => {
+            format!("Record<string, {}>", rust_to_typescript(value, ctx))
+        }
+        ReturnMapping::Unchanged => wire_type.clone(),
+    };
+
+    let args_suffix = if cmd.args.is_empty() {
+        String::new()
+    } else {
+        format!(", {{ {} }}", generate_args_object(cmd))
+    };
+
+    if ctx.generation().result_wrapper {
+        // Rejections come back as `Err<E>`; a command without a `Result`
+        // return can still reject (e.g. unknown command), typed `unknown`.
+        let error_type = cmd
+            .error_type()
+            .map(|err| generate_throws_type(err, ctx))
+            .unwrap_or_else(|| "unknown".to_string());
+        // Invalid arguments come back as `Err<Error>` too
+        let result_error_type =
+            if validates_args(cmd, ctx) && !matches!(error_type.as_str(), "unknown" | "Error") {
+                format!("{} | Error", error_type)
+            } else {
+                error_type.clone()
+            };
+        output.push_str(&render_jsdoc(ctx.doc_comment(&cmd.docs), &[], ""));
+        output.push_str(&format!(
+            "export async function {}({}): Promise<Ok<{}> | Err<{}>> {{\n",
+            fn_name, params, return_type, result_error_type
+        ));
+        output.push_str(&args_validation(cmd, ctx));
+        let call = format!(
+            "invokeResult<{}, {}>({}{})",
+            wire_type,
+            error_type,
+            ctx.string_literal(&cmd.name),
+            args_suffix
+        );
+        let indent = ctx.indent(1);
+        if let Some(value) = mapping.apply("result.value") {
+            output.push_str(&format!("{}const result = await {};\n", indent, call));
+            output.push_str(&format!(
+                "{}return result.ok ? {{ ok: true, value: {} }} : result;\n",
+                indent, value
+            ));
+        } else {
+            output.push_str(&format!("{}return {};\n", indent, call));
+        }
+        output.push_str("}\n");
+        return output;
+    }
+
+    // Document the rejection type: Tauri rejects the invoke promise with
+    // the serialized error of a `Result<T, E>` command.
+    let throws: Vec<String> = cmd
+        .error_type()
+        .map(|err| format!("@throws {{{}}}", generate_throws_type(err, ctx)))
+        .into_iter()
+        .collect();
+    output.push_str(&render_jsdoc(ctx.doc_comment(&cmd.docs), &throws, ""));
 
     // Generate function signature
     output.push_str(&format!(
         "export async function {}({}): Promise<{}> {{\n",
         fn_name, params, return_type
     ));
+    output.push_str(&args_validation(cmd, ctx));
 
     // Generate invoke call
-    if cmd.args.is_empty() {
-        output.push_str(&format!(
-            "  return invoke<{}>(\"{}\");\n",
-            return_type, cmd.name
-        ));
+    let call = format!(
+        "invoke<{}>({}{})",
+        wire_type,
+        ctx.string_literal(&cmd.name),
+        args_suffix
+    );
+    let indent = ctx.indent(1);
+    if let Some(value) = mapping.apply(&format!("await {}", call)) {
+        output.push_str(&format!("{}return {};\n", indent, value));
     } else {
-        let args_obj = generate_args_object(&cmd.args, cmd.rename_all.as_deref());
-        output.push_str(&format!(
-            "  return invoke<{}>(\"{}\", {{ {} }});\n",
-            return_type, cmd.name, args_obj
-        ));
+        output.push_str(&format!("{}return {};\n", indent, call));
     }
 
     output.push_str("}\n");
@@ -184,7 +600,7 @@ fn generate_command_function(cmd: &TauriCommand, ctx: &GeneratorContext) -> Stri
 fn generate_params(args: &[CommandArg], ctx: &GeneratorContext) -> String {
     args.iter()
         .map(|arg| {
-            let param_name = to_camel_case(&arg.name);
+            let param_name = ts_identifier(&to_camel_case(&arg.name));
             let param_type = rust_to_typescript(&arg.ty, ctx);
             format!("{}: {}", param_name, param_type)
         })
@@ -200,30 +616,134 @@ fn generate_return_type(return_type: &Option<RustType>, ctx: &GeneratorContext)
     }
 }
 
-/// Generate the arguments object for invoke
+/// How a command function converts what `invoke` resolves to before
+/// returning it
+enum ReturnMapping<'a> {
+    Unchanged,
+    /// `null` → `undefined`, for a returned `Option` of this type
+    NullToUndefined(&'a RustType),
+    /// `[key, value][]` → `Record<string, value>`, for returned pairs with
+    /// this value type
+    EntriesToRecord(&'a RustType),
+}
+
+impl ReturnMapping<'_> {
+    /// The expression `value` converted, or `None` when returned as is
+    fn apply(&self, value: &str) -> Option<String> {
+        match self {
+            ReturnMapping::Unchanged => None,
+            ReturnMapping::NullToUndefined(_) if value.contains(' ') => {
+                Some(format!("({}) ?? undefined", value))
+            }
+            ReturnMapping::NullToUndefined(_) => Some(format!("{} ?? undefined", value)),
+            ReturnMapping::EntriesToRecord(_) => Some(format!("Object.fromEntries({})", value)),
+        }
+    }
+}
+
+fn return_mapping<'a>(
+    return_type: &'a Option<RustType>,
+    ctx: &GeneratorContext,
+) -> ReturnMapping<'a> {
+    let generation = ctx.generation();
+    if generation.option_return_style == OptionReturnStyle::Undefined {
+        if let Some(inner) = returned_option(return_type) {
+            return ReturnMapping::NullToUndefined(inner);
+        }
+    }
+    if generation.entries_return_style == EntriesReturnStyle::Record {
+        if let Some(value) = returned_entries(return_type, ctx) {
+            return ReturnMapping::EntriesToRecord(value);
+        }
+    }
+    ReturnMapping::Unchanged
+}
+
+/// `T` when a command returns `Vec<(K, T)>` (or `Result<Vec<(K, T)>, E>`)
+/// with a key that serializes as a string.
+fn returned_entries<'a>(
+    return_type: &'a Option<RustType>,
+    ctx: &GeneratorContext,
+) -> Option<&'a RustType> {
+    let returned = match return_type.as_ref()? {
+        RustType::Result { ok, .. } => ok.as_ref(),
+        ty => ty,
+    };
+    let RustType::Vec(element) = returned else {
+        return None;
+    };
+    match element.as_ref() {
+        RustType::Tuple(pair)
+            if pair.len() == 2 && rust_to_typescript(&pair[0], ctx) == "string" =>
+        {
+            Some(&pair[1])
+        }
+        _ => None,
+    }
+}
+
+/// `T` when a command returns `Option<T>` or `Result<Option<T>, E>`.
+fn returned_option(return_type: &Option<RustType>) -> Option<&RustType> {
+    match return_type.as_ref()? {
+        RustType::Option(inner) => Some(inner),
+        RustType::Result { ok, .. } => match ok.as_ref() {
+            RustType::Option(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// TypeScript type named by the `@throws` tag for a command's error type.
 ///
-/// By default, Tauri serializes command arguments to camelCase.
-/// If `rename_all = "snake_case"` is specified, arguments stay as snake_case.
-fn generate_args_object(args: &[CommandArg], rename_all: Option<&str>) -> String {
-    let use_snake_case = rename_all == Some("snake_case");
+/// An error type we export (or a primitive like `String`) is referenced
+/// directly; custom error types are only exported under
+/// `[generation] export_error_types`. In a `@throws` tag the type is
+/// deliberately not added to the `import type` list — a JSDoc-only
+/// reference would trip `noUnusedLocals`; in a `result_wrapper` signature
+/// it is. Anything else — `std::io::Error`, `anyhow::Error`, a type
+/// the collector couldn't resolve — is not in the types file, so naming
+/// it would leave a dangling reference; those fall back to `Error`.
+fn generate_throws_type(err: &RustType, ctx: &GeneratorContext) -> String {
+    if let RustType::Unknown(_) = err {
+        return "unknown".to_string();
+    }
 
-    args.iter()
+    if is_exported(err, ctx) {
+        rust_to_typescript(err, ctx)
+    } else {
+        "Error".to_string()
+    }
+}
+
+/// Whether every custom type `ty` names is declared in the types file
+fn is_exported(ty: &RustType, ctx: &GeneratorContext) -> bool {
+    let mut exported = true;
+    crate::models::walk_custom_type_names(ty, &mut |name| {
+        if !ctx.is_custom_type(crate::utils::simple_name(name)) {
+            exported = false;
+        }
+    });
+    exported
+}
+
+/// Generate the arguments object for invoke
+///
+/// Keys are the wire names from [`TauriCommand::arg_wire_name`]. A
+/// parameter renamed away from a reserved word (`delete_`) still sends
+/// the original key.
+fn generate_args_object(cmd: &TauriCommand) -> String {
+    cmd.args
+        .iter()
         .map(|arg| {
-            let param_name = to_camel_case(&arg.name);
-
-            if use_snake_case {
-                // With rename_all = "snake_case", Tauri expects snake_case keys
-                if arg.name == param_name {
-                    // Already the same (e.g., single word like "id")
-                    param_name
-                } else {
-                    // Map camelCase param to snake_case key
-                    format!("{}: {}", arg.name, param_name)
-                }
-            } else {
-                // Default: Tauri expects camelCase keys
-                // Use shorthand since param name matches key name
+            let param_name = ts_identifier(&to_camel_case(&arg.name));
+            let key = cmd.arg_wire_name(arg);
+
+            if key == param_name {
+                // Shorthand since param name matches key name
                 param_name
+            } else {
+                format!("{}: {}", key, param_name)
             }
         })
         .collect::<Vec<_>>()
@@ -231,7 +751,7 @@ fn generate_args_object(args: &[CommandArg], rename_all: Option<&str>) -> String
 }
 
 /// Calculate relative import path from commands file to types file
-fn calculate_relative_import(types_file: &Path, commands_file: &Path) -> String {
+pub(super) fn calculate_relative_import(types_file: &Path, commands_file: &Path) -> String {
     // Get the directory of the commands file
     let commands_dir = commands_file.parent().unwrap_or(Path::new("."));
     let types_dir = types_file.parent().unwrap_or(Path::new("."));
@@ -12,6 +12,13 @@ fn default_ctx() -> GeneratorContext {
     GeneratorContext::new(NamingConfig::default())
 }
 
+fn result_of(ok: RustType, err: RustType) -> RustType {
+    RustType::Result {
+        ok: Box::new(ok),
+        err: Some(Box::new(err)),
+    }
+}
+
 fn ctx_with_type(type_name: &str) -> GeneratorContext {
     let mut ctx = default_ctx();
     ctx.register_type(type_name);
@@ -29,6 +36,7 @@ fn test_generate_simple_command() {
         return_type: Some(RustType::custom("User")),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let mut ctx = default_ctx();
@@ -50,6 +58,7 @@ fn test_generate_command_no_args() {
         return_type: Some(RustType::Vec(Box::new(RustType::custom("Item")))),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = ctx_with_type("Item");
@@ -83,6 +92,7 @@ fn test_generate_command_multiple_args() {
         return_type: Some(RustType::custom("User")),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = ctx_with_type("User");
@@ -104,6 +114,7 @@ fn test_generate_void_return() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -121,6 +132,7 @@ fn test_camel_case_function_name() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -141,6 +153,7 @@ fn test_default_camel_case_args_in_invoke() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -166,6 +179,7 @@ fn test_snake_case_rename_all_in_invoke() {
         return_type: None,
         source_file: test_path(),
         rename_all: Some("snake_case".to_string()),
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -177,6 +191,28 @@ fn test_snake_case_rename_all_in_invoke() {
     assert!(output.contains("user_id: userId"));
 }
 
+#[test]
+fn test_reserved_word_names_are_suffixed() {
+    // `delete` / `new` can't be function or parameter names in TypeScript;
+    // the binding gets a `_` suffix while the invoke payload keeps the key.
+    let cmd = TauriCommand {
+        name: "delete".to_string(),
+        args: vec![CommandArg {
+            name: "new".to_string(),
+            ty: RustType::Primitive("bool".to_string()),
+        }],
+        return_type: None,
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+
+    let output = generate_command_function(&cmd, &default_ctx());
+
+    assert!(output.contains("export async function delete_(new_: boolean): Promise<void> {"));
+    assert!(output.contains("invoke<void>(\"delete\", { new: new_ });"));
+}
+
 #[test]
 fn test_collect_used_types_from_commands() {
     let commands = vec![
@@ -186,6 +222,7 @@ fn test_collect_used_types_from_commands() {
             return_type: Some(RustType::custom("User")),
             source_file: test_path(),
             rename_all: None,
+            docs: None,
         },
         TauriCommand {
             name: "create".to_string(),
@@ -196,6 +233,7 @@ fn test_collect_used_types_from_commands() {
             return_type: Some(RustType::custom("User")),
             source_file: test_path(),
             rename_all: None,
+            docs: None,
         },
     ];
 
@@ -203,7 +241,7 @@ fn test_collect_used_types_from_commands() {
     ctx.register_type("User");
     ctx.register_type("CreateRequest");
 
-    let types = collect_used_types(&commands, &ctx);
+    let types = collect_used_types(&commands, false, &ctx);
 
     assert!(types.contains("User"));
     assert!(types.contains("CreateRequest"));
@@ -220,10 +258,11 @@ fn test_collect_used_types_nested() {
         ))))),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     }];
 
     let ctx = ctx_with_type("User");
-    let types = collect_used_types(&commands, &ctx);
+    let types = collect_used_types(&commands, false, &ctx);
 
     assert!(types.contains("User"));
 }
@@ -245,6 +284,7 @@ fn test_naming_function_prefix() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = GeneratorContext::new(NamingConfig {
@@ -252,6 +292,7 @@ fn test_naming_function_prefix() {
         type_suffix: "".to_string(),
         function_prefix: "api".to_string(),
         function_suffix: "".to_string(),
+        type_overrides: Default::default(),
     });
 
     let output = generate_command_function(&cmd, &ctx);
@@ -266,6 +307,7 @@ fn test_naming_function_suffix() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = GeneratorContext::new(NamingConfig {
@@ -273,6 +315,7 @@ fn test_naming_function_suffix() {
         type_suffix: "".to_string(),
         function_prefix: "".to_string(),
         function_suffix: "Cmd".to_string(),
+        type_overrides: Default::default(),
     });
 
     let output = generate_command_function(&cmd, &ctx);
@@ -301,6 +344,7 @@ fn test_generate_commands_file_with_imports() {
         return_type: Some(RustType::custom("User")),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     }];
 
     let types_path = Path::new("src/generated/types.ts");
@@ -312,22 +356,67 @@ fn test_generate_commands_file_with_imports() {
     assert!(output.contains("import type { User } from"));
 }
 
+#[test]
+fn test_use_type_imports_toggles_import_style() {
+    let commands = vec![TauriCommand {
+        name: "get_user".to_string(),
+        args: vec![],
+        return_type: Some(RustType::custom("User")),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    }];
+    let types_path = Path::new("src/generated/types.ts");
+    let commands_path = Path::new("src/generated/commands.ts");
+
+    let output =
+        generate_commands_file(&commands, types_path, commands_path, &ctx_with_type("User"));
+    assert!(output.contains("import type { User } from \"./types\";"));
+
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            use_type_imports: false,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    let output = generate_commands_file(&commands, types_path, commands_path, &ctx);
+    assert!(output.contains("import { User } from \"./types\";"));
+    assert!(!output.contains("import type"));
+}
+
 #[test]
 fn test_complex_return_type() {
     let cmd = TauriCommand {
         name: "search".to_string(),
         args: vec![],
-        return_type: Some(RustType::Result(Box::new(RustType::Vec(Box::new(
-            RustType::custom("User"),
-        ))))),
+        return_type: Some(result_of(
+            RustType::Vec(Box::new(RustType::custom("User"))),
+            RustType::Primitive("String".to_string()),
+        )),
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     };
 
     let ctx = ctx_with_type("User");
     let output = generate_command_function(&cmd, &ctx);
 
     assert!(output.contains("Promise<User[]>"));
+
+    // The element type is reached through `Result` -> `Vec` -> `Custom`
+    // and must be imported, not just referenced.
+    let output = generate_commands_file(
+        &[cmd],
+        Path::new("src/generated/types.ts"),
+        Path::new("src/generated/commands.ts"),
+        &ctx,
+    );
+    assert!(
+        output.contains("import type { User } from \"./types\";"),
+        "got:\n{output}"
+    );
 }
 
 #[test]
@@ -351,6 +440,7 @@ fn test_imports_are_sorted() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     }];
 
     let types_path = Path::new("types.ts");
@@ -365,3 +455,683 @@ fn test_imports_are_sorted() {
     // Should be AType, BType, CType
     assert!(output.contains("import type { AType, BType, CType }"));
 }
+
+#[test]
+fn test_throws_falls_back_to_error_for_foreign_error_type() {
+    // `std::io::Error` is never emitted into types.ts — naming it in the
+    // JSDoc would be a dangling reference.
+    let cmd = TauriCommand {
+        name: "load_user".to_string(),
+        args: vec![],
+        return_type: Some(result_of(
+            RustType::custom("User"),
+            RustType::custom("std::io::Error"),
+        )),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+
+    let ctx = ctx_with_type("User");
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(output.contains("/**\n * @throws {Error}\n */\nexport async function loadUser"));
+    assert!(!output.contains("std::io"));
+}
+
+#[test]
+fn test_throws_references_exported_error_type() {
+    let cmd = TauriCommand {
+        name: "load_user".to_string(),
+        args: vec![],
+        return_type: Some(result_of(
+            RustType::custom("User"),
+            RustType::custom("AppError"),
+        )),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+
+    let mut ctx = ctx_with_type("User");
+    ctx.register_enum("AppError");
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(output.contains(" * @throws {AppError}\n"));
+}
+
+#[test]
+fn test_throws_maps_primitive_error_type() {
+    let cmd = TauriCommand {
+        name: "ping".to_string(),
+        args: vec![],
+        return_type: Some(result_of(
+            RustType::Unit,
+            RustType::Primitive("String".to_string()),
+        )),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+
+    let output = generate_command_function(&cmd, &default_ctx());
+
+    assert!(output.contains(" * @throws {string}\n"));
+}
+
+#[test]
+fn test_no_throws_without_error_type() {
+    let cmd = TauriCommand {
+        name: "ping".to_string(),
+        args: vec![],
+        return_type: None,
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+
+    let output = generate_command_function(&cmd, &default_ctx());
+
+    assert!(!output.contains("@throws"));
+}
+
+#[test]
+fn test_result_wrapper_emits_helper_once_and_wraps_commands() {
+    let commands = vec![
+        TauriCommand {
+            name: "get_user".to_string(),
+            args: vec![CommandArg {
+                name: "id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+            }],
+            return_type: Some(result_of(
+                RustType::custom("User"),
+                RustType::Primitive("String".to_string()),
+            )),
+            source_file: test_path(),
+            rename_all: None,
+            docs: None,
+        },
+        TauriCommand {
+            name: "ping".to_string(),
+            args: vec![],
+            return_type: None,
+            source_file: test_path(),
+            rename_all: None,
+            docs: None,
+        },
+    ];
+
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            result_wrapper: true,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    let output = generate_commands_file(
+        &commands,
+        Path::new("src/generated/types.ts"),
+        Path::new("src/generated/commands.ts"),
+        &ctx,
+    );
+
+    assert_eq!(
+        output.matches("async function invokeResult<T, E>(").count(),
+        1
+    );
+    assert!(output.contains("export type Ok<T> = { ok: true; value: T };"));
+    assert!(output.contains("export type Err<E> = { ok: false; error: E };"));
+    assert!(output.contains(
+        "export async function getUser(id: number): Promise<Ok<User> | Err<string>> {\n  return invokeResult<User, string>(\"get_user\", { id });\n}\n"
+    ));
+    assert!(output.contains(
+        "export async function ping(): Promise<Ok<void> | Err<unknown>> {\n  return invokeResult<void, unknown>(\"ping\");\n}\n"
+    ));
+    // The wrapped functions resolve on failure; no `@throws` tag.
+    assert!(!output.contains("@throws"));
+}
+
+fn ctx_with_option_style(style: crate::config::OptionReturnStyle) -> GeneratorContext {
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            option_return_style: style,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    ctx
+}
+
+fn find_user_command(return_type: RustType) -> TauriCommand {
+    TauriCommand {
+        name: "find_user".to_string(),
+        args: vec![],
+        return_type: Some(return_type),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    }
+}
+
+#[test]
+fn test_option_return_style_null() {
+    let ctx = ctx_with_option_style(crate::config::OptionReturnStyle::Null);
+    let cmd = find_user_command(RustType::Option(Box::new(RustType::custom("User"))));
+
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(output.contains("findUser(): Promise<User | null> {"));
+    assert!(output.contains("  return invoke<User | null>(\"find_user\");\n"));
+}
+
+#[test]
+fn test_result_of_option_return_unwraps_ok_to_nullable() {
+    let cmd = find_user_command(result_of(
+        RustType::Option(Box::new(RustType::custom("User"))),
+        RustType::Primitive("String".to_string()),
+    ));
+
+    let output = generate_command_function(&cmd, &ctx_with_type("User"));
+
+    // Ok(None) resolves to null; Err rejects the promise with the string.
+    assert!(output.contains(" * @throws {string}\n"));
+    assert!(output.contains("findUser(): Promise<User | null> {"));
+    assert!(output.contains("  return invoke<User | null>(\"find_user\");\n"));
+}
+
+#[test]
+fn test_option_return_style_undefined() {
+    let ctx = ctx_with_option_style(crate::config::OptionReturnStyle::Undefined);
+
+    let cmd = find_user_command(RustType::Option(Box::new(RustType::custom("User"))));
+    let output = generate_command_function(&cmd, &ctx);
+    assert!(output.contains("findUser(): Promise<User | undefined> {"));
+    assert!(output.contains("  return (await invoke<User | null>(\"find_user\")) ?? undefined;\n"));
+
+    // The `Ok` side of a `Result` is a return position too
+    let cmd = find_user_command(result_of(
+        RustType::Option(Box::new(RustType::custom("User"))),
+        RustType::Primitive("String".to_string()),
+    ));
+    let output = generate_command_function(&cmd, &ctx);
+    assert!(output.contains("findUser(): Promise<User | undefined> {"));
+}
+
+#[test]
+fn test_option_return_style_ignores_nested_options() {
+    let ctx = ctx_with_option_style(crate::config::OptionReturnStyle::Undefined);
+    let cmd = find_user_command(RustType::Vec(Box::new(RustType::Option(Box::new(
+        RustType::custom("User"),
+    )))));
+
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(output.contains("findUser(): Promise<(User | null)[]> {"));
+    assert!(output.contains("  return invoke<(User | null)[]>(\"find_user\");\n"));
+}
+
+#[test]
+fn test_option_return_style_undefined_with_result_wrapper() {
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            option_return_style: crate::config::OptionReturnStyle::Undefined,
+            result_wrapper: true,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    let cmd = find_user_command(RustType::Option(Box::new(RustType::custom("User"))));
+
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(output.contains("findUser(): Promise<Ok<User | undefined> | Err<unknown>> {"));
+    assert!(output
+        .contains("  const result = await invokeResult<User | null, unknown>(\"find_user\");\n"));
+    assert!(output.contains(
+        "  return result.ok ? { ok: true, value: result.value ?? undefined } : result;\n"
+    ));
+}
+
+fn entries_of_users() -> RustType {
+    RustType::Vec(Box::new(RustType::Tuple(vec![
+        RustType::Primitive("String".to_string()),
+        RustType::custom("User"),
+    ])))
+}
+
+#[test]
+fn test_entries_return_defaults_to_pairs() {
+    let cmd = find_user_command(entries_of_users());
+
+    let output = generate_command_function(&cmd, &ctx_with_type("User"));
+
+    assert!(output.contains("findUser(): Promise<[string, User][]> {"));
+    assert!(output.contains("  return invoke<[string, User][]>(\"find_user\");\n"));
+}
+
+#[test]
+fn test_entries_return_style_record() {
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            entries_return_style: crate::config::EntriesReturnStyle::Record,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+
+    let cmd = find_user_command(result_of(
+        entries_of_users(),
+        RustType::Primitive("String".to_string()),
+    ));
+    let output = generate_command_function(&cmd, &ctx);
+    assert!(output.contains("findUser(): Promise<Record<string, User>> {"));
+    assert!(output
+        .contains("  return Object.fromEntries(await invoke<[string, User][]>(\"find_user\"));\n"));
+
+    // Pairs keyed by something other than a string stay pairs
+    let cmd = find_user_command(RustType::Vec(Box::new(RustType::Tuple(vec![
+        RustType::Primitive("u32".to_string()),
+        RustType::custom("User"),
+    ]))));
+    let output = generate_command_function(&cmd, &ctx);
+    assert!(output.contains("findUser(): Promise<[number, User][]> {"));
+}
+
+#[test]
+fn test_doc_example_block_becomes_jsdoc_example() {
+    let cmd = TauriCommand {
+        docs: Some(
+            [
+                "Load a user by id.",
+                "",
+                "# Examples",
+                "",
+                "```",
+                "# let app = setup();",
+                "let user = get_user(1)?;",
+                "```",
+                "",
+                "```ts",
+                "const user = await getUser(1);",
+                "```",
+            ]
+            .join("\n"),
+        ),
+        ..find_user_command(result_of(
+            RustType::custom("User"),
+            RustType::Primitive("String".to_string()),
+        ))
+    };
+
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            doc_comments: true,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(
+        output.starts_with(concat!(
+            "/**\n",
+            " * Load a user by id.\n",
+            " *\n",
+            " * @example\n",
+            " * ```\n",
+            " * let user = get_user(1)?;\n",
+            " * ```\n",
+            " *\n",
+            " * @example\n",
+            " * ```ts\n",
+            " * const user = await getUser(1);\n",
+            " * ```\n",
+            " * @throws {string}\n",
+            " */\n",
+            "export async function findUser(",
+        )),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_doc_comments_off_by_default() {
+    let cmd = TauriCommand {
+        docs: Some("Load a user by id.".to_string()),
+        ..find_user_command(RustType::custom("User"))
+    };
+
+    let output = generate_command_function(&cmd, &ctx_with_type("User"));
+
+    assert!(output.starts_with("export async function findUser("));
+}
+
+#[test]
+fn test_module_group_skipped_when_it_shadows_a_function() {
+    let cmd = |name: &str, file: &str| TauriCommand {
+        source_file: PathBuf::from(file),
+        name: name.to_string(),
+        ..find_user_command(RustType::Primitive("String".to_string()))
+    };
+    let commands = vec![cmd("ping", "src/ping.rs"), cmd("pong", "src/game.rs")];
+
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            group_commands_by_module: true,
+            ..Default::default()
+        },
+    );
+    let module = |name: &str| vec!["crate".to_string(), name.to_string()];
+    ctx.register_module(Path::new("src/ping.rs"), &module("ping"));
+    ctx.register_module(Path::new("src/game.rs"), &module("game"));
+
+    let output = generate_module_groups(&commands, &ctx);
+
+    assert_eq!(output, "export const game = {\n  pong,\n};\n\n");
+}
+
+#[test]
+fn test_prefix_with_module_keeps_invoke_target() {
+    let command = |name: &str, file: &str| TauriCommand {
+        name: name.to_string(),
+        source_file: PathBuf::from(file),
+        ..find_user_command(RustType::custom("User"))
+    };
+    let commands = [
+        command("get_by_id", "src/user.rs"),
+        command("get_by_id", "src/api/team.rs"),
+        command("health", "src/lib.rs"),
+    ];
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            prefix_with_module: true,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    let module = |path: &[&str]| path.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+    ctx.register_module(Path::new("src/user.rs"), &module(&["crate", "user"]));
+    ctx.register_module(
+        Path::new("src/api/team.rs"),
+        &module(&["crate", "api", "team"]),
+    );
+    ctx.register_module(Path::new("src/lib.rs"), &module(&["crate"]));
+
+    let user = generate_command_function(&commands[0], &ctx);
+    let team = generate_command_function(&commands[1], &ctx);
+    let health = generate_command_function(&commands[2], &ctx);
+
+    assert!(
+        user.contains("export async function userGetById(): Promise<User> {"),
+        "got:\n{user}"
+    );
+    assert!(user.contains("invoke<User>(\"get_by_id\")"), "got:\n{user}");
+    assert!(
+        team.contains("export async function apiTeamGetById(): Promise<User> {"),
+        "got:\n{team}"
+    );
+    assert!(team.contains("invoke<User>(\"get_by_id\")"), "got:\n{team}");
+    // Commands at the crate root have no module to prefix
+    assert!(
+        health.contains("export async function health(): Promise<User> {"),
+        "got:\n{health}"
+    );
+}
+
+#[test]
+fn test_readonly_fields_leave_command_args_mutable() {
+    let cmd = TauriCommand {
+        args: vec![CommandArg {
+            name: "tags".to_string(),
+            ty: RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+        }],
+        ..find_user_command(RustType::Vec(Box::new(RustType::Primitive(
+            "String".to_string(),
+        ))))
+    };
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            readonly_fields: true,
+            ..Default::default()
+        },
+    );
+
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(
+        output.contains("findUser(tags: string[]): Promise<string[]> {"),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_command_map_lists_args_and_result_per_command() {
+    let command =
+        |name: &str, args: Vec<CommandArg>, return_type, rename_all: Option<&str>| TauriCommand {
+            name: name.to_string(),
+            args,
+            return_type,
+            source_file: test_path(),
+            rename_all: rename_all.map(str::to_string),
+            docs: None,
+        };
+    let user_id = || CommandArg {
+        name: "user_id".to_string(),
+        ty: RustType::Primitive("i32".to_string()),
+    };
+    let commands = vec![
+        command(
+            "get_user",
+            vec![user_id()],
+            Some(result_of(
+                RustType::custom("User"),
+                RustType::Primitive("String".to_string()),
+            )),
+            None,
+        ),
+        command("delete_user", vec![user_id()], None, Some("snake_case")),
+        command(
+            "list_users",
+            vec![],
+            Some(RustType::Vec(Box::new(RustType::custom("User")))),
+            None,
+        ),
+    ];
+
+    let output = generate_command_map_file(
+        &commands,
+        Path::new("src/bindings/types.ts"),
+        Path::new("src/bindings/commands.d.ts"),
+        &ctx_with_type("User"),
+    );
+
+    assert!(
+        output.contains("import type { User } from \"./types\";\n"),
+        "got:\n{output}"
+    );
+    assert!(
+        output.contains(
+            "export interface CommandMap {\n  \
+             delete_user: [{ user_id: number }, void];\n  \
+             get_user: [{ userId: number }, User];\n  \
+             list_users: [Record<string, never>, User[]];\n}\n"
+        ),
+        "got:\n{output}"
+    );
+    assert!(!output.contains("invoke"), "got:\n{output}");
+}
+
+#[test]
+fn test_args_validated_against_zod_schema_before_invoke() {
+    let commands = vec![
+        TauriCommand {
+            name: "get_user".to_string(),
+            args: vec![CommandArg {
+                name: "user_id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+            }],
+            return_type: Some(RustType::custom("User")),
+            source_file: test_path(),
+            rename_all: None,
+            docs: None,
+        },
+        TauriCommand {
+            name: "ping".to_string(),
+            args: vec![],
+            return_type: None,
+            source_file: test_path(),
+            rename_all: None,
+            docs: None,
+        },
+    ];
+    let ctx = ctx_with_type("User").with_args_schemas(Some(PathBuf::from("src/schemas.ts")));
+
+    let output = generate_commands_file(
+        &commands,
+        Path::new("src/types.ts"),
+        Path::new("src/commands.ts"),
+        &ctx,
+    );
+
+    assert!(
+        output.contains("import { GetUserArgsSchema } from \"./schemas\";\n"),
+        "got:\n{output}"
+    );
+    let parse = output
+        .find("GetUserArgsSchema.parse({ userId });")
+        .expect("args are parsed");
+    let invoke = output
+        .find("invoke<User>(\"get_user\", { userId })")
+        .expect("command is invoked");
+    assert!(parse < invoke, "parse must come before invoke:\n{output}");
+    assert!(
+        output.contains(
+            "    throw new Error(`Invalid arguments for command \"get_user\": ${error}`);\n"
+        ),
+        "got:\n{output}"
+    );
+    // Nothing to validate without arguments
+    assert!(!output.contains("PingArgsSchema"), "got:\n{output}");
+
+    // Without `zod_file` the wrapper invokes directly
+    let plain = generate_commands_file(
+        &commands,
+        Path::new("src/types.ts"),
+        Path::new("src/commands.ts"),
+        &ctx_with_type("User"),
+    );
+    assert!(!plain.contains("ArgsSchema"), "got:\n{plain}");
+}
+
+#[test]
+fn test_result_wrapper_imports_error_type() {
+    let commands = vec![TauriCommand {
+        name: "get_user".to_string(),
+        args: vec![],
+        return_type: Some(result_of(
+            RustType::custom("User"),
+            RustType::custom("AppError"),
+        )),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    }];
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            result_wrapper: true,
+            export_error_types: true,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    ctx.register_type("AppError");
+    let types_path = Path::new("src/types.ts");
+    let commands_path = Path::new("src/commands.ts");
+
+    let output = generate_commands_file(&commands, types_path, commands_path, &ctx);
+    assert!(
+        output.contains("import type { AppError, User } from \"./types\";\n"),
+        "got:\n{output}"
+    );
+    assert!(
+        output.contains("Promise<Ok<User> | Err<AppError>>"),
+        "got:\n{output}"
+    );
+
+    // A `@throws` tag alone doesn't import it
+    let mut plain = ctx_with_type("User");
+    plain.register_type("AppError");
+    let output = generate_commands_file(&commands, types_path, commands_path, &plain);
+    assert!(
+        output.contains("import type { User } from \"./types\";\n"),
+        "got:\n{output}"
+    );
+
+    // Under a namespace the namespace is imported
+    let ctx = ctx.with_types_namespace(Some("Api".to_string()));
+    let output = generate_commands_file(&commands, types_path, commands_path, &ctx);
+    assert!(
+        output.contains("import type { Api } from \"./types\";\n"),
+        "got:\n{output}"
+    );
+    assert!(
+        output.contains("Promise<Ok<Api.User> | Err<Api.AppError>>"),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_result_wrapper_returns_invalid_args_as_err() {
+    let cmd = TauriCommand {
+        name: "get_user".to_string(),
+        args: vec![CommandArg {
+            name: "id".to_string(),
+            ty: RustType::Primitive("i32".to_string()),
+        }],
+        return_type: Some(result_of(
+            RustType::custom("User"),
+            RustType::Primitive("String".to_string()),
+        )),
+        source_file: test_path(),
+        rename_all: None,
+        docs: None,
+    };
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        crate::config::GenerationConfig {
+            result_wrapper: true,
+            ..Default::default()
+        },
+    )
+    .with_args_schemas(Some(PathBuf::from("src/schemas.ts")));
+    ctx.register_type("User");
+
+    let output = generate_command_function(&cmd, &ctx);
+
+    assert!(
+        output.contains(
+            "export async function getUser(id: number): Promise<Ok<User> | Err<string | Error>> {\n"
+        ),
+        "got:\n{output}"
+    );
+    let parse = output.find("GetUserArgsSchema.parse({ id });").unwrap();
+    let invoke = output.find("invokeResult<User, string>(").unwrap();
+    assert!(parse < invoke, "got:\n{output}");
+    assert!(
+        output.contains(
+            "    return { ok: false, error: new Error(`Invalid arguments for command \"get_user\": ${error}`) };\n"
+        ),
+        "got:\n{output}"
+    );
+    assert!(!output.contains("throw"), "got:\n{output}");
+}
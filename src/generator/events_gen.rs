@@ -0,0 +1,114 @@
+//! `[output] events_file`: one typed `listen` wrapper per
+//! `#[tauri_event]` payload type.
+
+use crate::models::{RustType, TauriEvent};
+use crate::utils::to_pascal_case;
+use std::path::Path;
+
+use super::{
+    commands_gen::calculate_relative_import, jsdoc::render_jsdoc, sorted_by_name,
+    type_mapper::rust_to_typescript, GeneratorContext,
+};
+
+/// Generate the events file: for `#[tauri_event(name = "user-updated")]`
+/// on `struct UserUpdated`,
+///
+/// ```typescript
+/// export function onUserUpdated(handler: (payload: UserUpdated) => void): Promise<UnlistenFn> {
+///   return listen<UserUpdated>("user-updated", (event) => handler(event.payload));
+/// }
+/// ```
+///
+/// A unit struct payload is always `null`, so its handler takes no
+/// argument.
+pub fn generate_events_file(
+    events: &[TauriEvent],
+    types_file_path: &Path,
+    events_file_path: &Path,
+    ctx: &GeneratorContext,
+) -> String {
+    let mut output = ctx.file_header();
+    output.push_str(&format!(
+        "import {{ listen }} from {};\n",
+        ctx.string_literal("@tauri-apps/api/event")
+    ));
+    output.push_str(&format!(
+        "import type {{ UnlistenFn }} from {};\n",
+        ctx.string_literal("@tauri-apps/api/event")
+    ));
+
+    let payload_types =
+        ctx.type_import_names(events.iter().filter_map(|event| match &event.payload {
+            RustType::Custom { name, .. } if ctx.is_custom_type(name) => {
+                Some(ctx.format_type_name(name))
+            }
+            _ => None,
+        }));
+    if !payload_types.is_empty() {
+        let import_path = calculate_relative_import(types_file_path, events_file_path);
+        output.push_str(&format!(
+            "import type {{ {} }} from {};\n",
+            payload_types.join(", "),
+            ctx.string_literal(&import_path)
+        ));
+    }
+    output.push('\n');
+
+    for event in sorted_by_name(events, |e| &e.name) {
+        output.push_str(&generate_listener(event, ctx));
+        output.push('\n');
+    }
+    output
+}
+
+fn generate_listener(event: &TauriEvent, ctx: &GeneratorContext) -> String {
+    let name = ctx.string_literal(&event.name);
+    let (handler, listen_call) = match &event.payload {
+        RustType::Unit => (
+            "() => void".to_string(),
+            format!("listen({}, () => handler())", name),
+        ),
+        payload => {
+            let payload_ts = rust_to_typescript(payload, ctx);
+            (
+                format!("(payload: {}) => void", payload_ts),
+                format!(
+                    "listen<{}>({}, (event) => handler(event.payload))",
+                    payload_ts, name
+                ),
+            )
+        }
+    };
+
+    let mut output = render_jsdoc(ctx.doc_comment(&event.docs), &[], "");
+    output.push_str(&format!(
+        "export function {}(handler: {}): Promise<UnlistenFn> {{\n",
+        listener_name(&event.name),
+        handler
+    ));
+    output.push_str(&format!("{}return {};\n", ctx.indent(1), listen_call));
+    output.push_str("}\n");
+    output
+}
+
+/// `on` + the event name in PascalCase: `user-updated` and
+/// `user:updated` both give `onUserUpdated`
+fn listener_name(event_name: &str) -> String {
+    let words: String = event_name
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("on{}", to_pascal_case(&words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_listener_name() {
+        assert_eq!(listener_name("user-updated"), "onUserUpdated");
+        assert_eq!(listener_name("sync:state/changed"), "onSyncStateChanged");
+        assert_eq!(listener_name("app_ready"), "onAppReady");
+    }
+}
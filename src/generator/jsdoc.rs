@@ -0,0 +1,85 @@
+//! JSDoc blocks built from captured Rust doc comments.
+//!
+//! Prose is copied as-is. Fenced code blocks become `@example` tags, and
+//! the `# Examples` heading that usually introduces them is dropped. In
+//! Rust blocks, rustdoc's hidden lines (`# use foo;`) are dropped too.
+
+/// Render a `/** ... */` block from `docs` followed by `tags` (e.g.
+/// `@throws {string}`), each line prefixed with `indent`. Empty when
+/// there is nothing to say.
+pub fn render_jsdoc(docs: Option<&str>, tags: &[String], indent: &str) -> String {
+    let mut lines = docs.map(doc_lines).unwrap_or_default();
+    lines.extend(tags.iter().cloned());
+    if lines.is_empty() {
+        return String::new();
+    }
+
+    let mut out = format!("{}/**\n", indent);
+    for line in lines {
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            out.push_str(&format!("{} *\n", indent));
+        } else {
+            out.push_str(&format!("{} * {}\n", indent, line));
+        }
+    }
+    out.push_str(&format!("{} */\n", indent));
+    out
+}
+
+/// Doc text as JSDoc lines, with code fences turned into `@example`s.
+fn doc_lines(docs: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    // `Some(is_rust)` while inside a fence.
+    let mut fence: Option<bool> = None;
+
+    for line in docs.lines() {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            match fence {
+                None => {
+                    fence = Some(is_rust_fence(info));
+                    lines.push("@example".to_string());
+                    lines.push(trimmed.to_string());
+                }
+                Some(_) => {
+                    fence = None;
+                    lines.push("```".to_string());
+                }
+            }
+            continue;
+        }
+
+        match fence {
+            Some(true) if trimmed == "#" || trimmed.starts_with("# ") => {}
+            Some(_) => lines.push(line.to_string()),
+            None if is_examples_heading(trimmed) => {}
+            None => {
+                // Collapse the blank runs a dropped heading leaves behind.
+                if !(line.is_empty() && lines.last().is_some_and(|l| l.is_empty())) {
+                    lines.push(line.to_string());
+                }
+            }
+        }
+    }
+
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+    lines
+}
+
+/// Rustdoc treats an unlabelled fence, or one tagged only with test
+/// attributes, as Rust.
+fn is_rust_fence(info: &str) -> bool {
+    info.split(',').map(str::trim).all(|tag| {
+        matches!(
+            tag,
+            "" | "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail"
+        ) || tag.starts_with("edition")
+    })
+}
+
+fn is_examples_heading(line: &str) -> bool {
+    matches!(line, "# Example" | "# Examples")
+}
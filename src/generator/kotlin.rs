@@ -0,0 +1,443 @@
+//! Kotlin backend (experimental): kotlinx.serialization `@Serializable`
+//! data classes for structs, `enum class`es for enums of unit variants and
+//! a `Commands` interface with one `suspend fun` per command.
+//!
+//! Shapes kotlinx.serialization can't express without a custom serializer
+//! (enums carrying data, tuple structs) are declared as `JsonElement`
+//! aliases so references to them still compile.
+
+use crate::known_types;
+use crate::models::{
+    EnumRepresentation, RustEnum, RustStruct, RustType, StructField, StructShape, TauriCommand,
+    VariantData,
+};
+use crate::utils::{simple_name, to_camel_case, to_pascal_case};
+
+use super::backend::{Bindings, CodeGenerator};
+use super::commands_gen::command_base_name;
+use super::{sorted_by_name, GeneratorContext};
+
+/// Type of any value the backend can't model more precisely
+const JSON_ELEMENT: &str = "JsonElement";
+
+/// Kotlin hard keywords; an identifier spelled like one needs backticks.
+const KOTLIN_KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+pub struct KotlinGenerator {
+    ctx: GeneratorContext,
+}
+
+impl KotlinGenerator {
+    pub fn new(ctx: GeneratorContext) -> Self {
+        Self { ctx }
+    }
+
+    fn imports(&self) -> String {
+        [
+            "import kotlinx.serialization.SerialName",
+            "import kotlinx.serialization.Serializable",
+            "import kotlinx.serialization.json.JsonElement",
+        ]
+        .iter()
+        .map(|line| format!("{}\n", line))
+        .collect::<String>()
+            + "\n"
+    }
+
+    fn type_declarations(&self, bindings: &Bindings) -> String {
+        let mut output = String::new();
+        for s in sorted_by_name(bindings.structs, |s| &s.name) {
+            output.push_str(&self.struct_declaration(s));
+            output.push('\n');
+        }
+        for e in sorted_by_name(bindings.enums, |e| &e.name) {
+            output.push_str(&self.enum_declaration(e));
+            output.push('\n');
+        }
+        for alias in sorted_by_name(bindings.aliases, |a| &a.name) {
+            output.push_str(&format!(
+                "typealias {} = {}\n\n",
+                self.declared_name(&alias.name, &alias.generics),
+                self.kotlin_type(&alias.target)
+            ));
+        }
+        output
+    }
+
+    /// `Name` or `Name<T, U>` for the left-hand side of a declaration
+    fn declared_name(&self, name: &str, generics: &[String]) -> String {
+        let name = self.ctx.format_type_name(name);
+        if generics.is_empty() {
+            name
+        } else {
+            format!("{}<{}>", name, generics.join(", "))
+        }
+    }
+
+    fn struct_declaration(&self, s: &RustStruct) -> String {
+        let name = self.declared_name(&s.name, &s.generics);
+        match s.shape {
+            StructShape::Named if s.fields.is_empty() => {
+                format!("@Serializable\nclass {}\n", name)
+            }
+            StructShape::Named => {
+                let mut output = format!("@Serializable\ndata class {}(\n", name);
+                for field in &s.fields {
+                    output.push_str(&self.ctx.indent(1));
+                    output.push_str(&self.property(field));
+                    output.push('\n');
+                }
+                output.push_str(")\n");
+                output
+            }
+            StructShape::Newtype => match s.fields.first() {
+                Some(inner) => format!("typealias {} = {}\n", name, self.kotlin_type(&inner.ty)),
+                None => unsupported(&name, "newtype without a field"),
+            },
+            StructShape::Tuple => unsupported(&name, "tuple structs"),
+            StructShape::Unit => unsupported(&name, "unit structs"),
+        }
+    }
+
+    /// `val name: Type,` for a data class parameter. A `#[serde(flatten)]`
+    /// field has no Kotlin counterpart and is left as a comment.
+    fn property(&self, field: &StructField) -> String {
+        if field.is_flatten {
+            return format!(
+                "// `{}` is flattened, which the Kotlin backend does not support",
+                field.name
+            );
+        }
+        let (serial_name, ident) = if is_kotlin_identifier(&field.name) {
+            (String::new(), kotlin_identifier(&field.name))
+        } else {
+            (
+                format!("@SerialName(\"{}\") ", field.name),
+                kotlin_identifier(&to_camel_case(&field.name)),
+            )
+        };
+        let ty = self.kotlin_type(&field.ty);
+        if field.use_optional {
+            let ty = if ty.ends_with('?') {
+                ty
+            } else {
+                format!("{}?", ty)
+            };
+            format!("{}val {}: {} = null,", serial_name, ident, ty)
+        } else {
+            format!("{}val {}: {},", serial_name, ident, ty)
+        }
+    }
+
+    fn enum_declaration(&self, e: &RustEnum) -> String {
+        let name = self.declared_name(&e.name, &e.generics);
+        let unit_only = e
+            .variants
+            .iter()
+            .all(|v| matches!(v.data, VariantData::Unit));
+        if !unit_only || e.representation != EnumRepresentation::External {
+            return unsupported(&name, "enums with data or a non-string representation");
+        }
+
+        let mut output = format!("@Serializable\nenum class {} {{\n", name);
+        for variant in &e.variants {
+            let constant = to_pascal_case(&variant.name);
+            output.push_str(&self.ctx.indent(1));
+            if constant != variant.name {
+                output.push_str(&format!("@SerialName(\"{}\") ", variant.name));
+            }
+            output.push_str(&kotlin_identifier(&constant));
+            output.push_str(",\n");
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    fn commands_interface(&self, commands: &[TauriCommand]) -> String {
+        let mut output = String::from("interface Commands {\n");
+        for cmd in sorted_by_name(commands, |c| &c.name) {
+            let fn_name = kotlin_identifier(&command_base_name(cmd, &self.ctx));
+            let params: Vec<String> = cmd
+                .args
+                .iter()
+                .map(|arg| {
+                    format!(
+                        "{}: {}",
+                        kotlin_identifier(&to_camel_case(&arg.name)),
+                        self.kotlin_type(&arg.ty)
+                    )
+                })
+                .collect();
+            let returns = match &cmd.return_type {
+                None | Some(RustType::Unit) => String::new(),
+                Some(RustType::Result { ok, .. }) if **ok == RustType::Unit => String::new(),
+                Some(ty) => format!(": {}", self.kotlin_type(ty)),
+            };
+            output.push_str(&format!(
+                "{}suspend fun {}({}){}\n",
+                self.ctx.indent(1),
+                fn_name,
+                params.join(", "),
+                returns
+            ));
+        }
+        output.push_str("}\n");
+        output
+    }
+
+    /// The Kotlin spelling of a Rust type
+    fn kotlin_type(&self, ty: &RustType) -> String {
+        match ty {
+            RustType::Primitive(name) => primitive_to_kotlin(name).to_string(),
+            RustType::Vec(inner) => format!("List<{}>", self.kotlin_type(inner)),
+            RustType::Option(inner) => {
+                let inner = self.kotlin_type(inner);
+                if inner.ends_with('?') {
+                    inner
+                } else {
+                    format!("{}?", inner)
+                }
+            }
+            RustType::Result { ok, .. } => self.kotlin_type(ok),
+            RustType::HashMap { key, value } => format!(
+                "Map<{}, {}>",
+                self.kotlin_type(key),
+                self.kotlin_type(value)
+            ),
+            RustType::Tuple(types) if types.is_empty() => "Unit".to_string(),
+            RustType::Tuple(_) | RustType::Channel(_) | RustType::Unknown(_) => {
+                JSON_ELEMENT.to_string()
+            }
+            RustType::Custom { name, args } => {
+                let simple = simple_name(name);
+                // An unresolved type has no declaration to point at
+                if !self.ctx.is_custom_type(simple) {
+                    return JSON_ELEMENT.to_string();
+                }
+                let base = self.ctx.format_type_name(simple);
+                if args.is_empty() {
+                    base
+                } else {
+                    let args: Vec<String> = args.iter().map(|a| self.kotlin_type(a)).collect();
+                    format!("{}<{}>", base, args.join(", "))
+                }
+            }
+            RustType::Generic(name) => name.clone(),
+            RustType::Unit => "Unit".to_string(),
+        }
+    }
+}
+
+impl CodeGenerator for KotlinGenerator {
+    fn types_file(&self, bindings: &Bindings) -> String {
+        self.ctx.file_header() + &self.imports() + &self.type_declarations(bindings)
+    }
+
+    fn commands_file(&self, bindings: &Bindings) -> String {
+        // Both files share a package, so the types need no import.
+        self.ctx.file_header() + &self.commands_interface(bindings.commands)
+    }
+
+    fn single_file(&self, bindings: &Bindings) -> String {
+        self.ctx.file_header()
+            + &self.imports()
+            + &self.type_declarations(bindings)
+            + &self.commands_interface(bindings.commands)
+    }
+}
+
+/// A `JsonElement` stand-in for a type the backend can't declare yet
+fn unsupported(name: &str, what: &str) -> String {
+    format!(
+        "// Not generated: the Kotlin backend does not support {} yet\ntypealias {} = {}\n",
+        what, name, JSON_ELEMENT
+    )
+}
+
+fn primitive_to_kotlin(name: &str) -> &'static str {
+    match name {
+        "bool" => "Boolean",
+        "i8" => "Byte",
+        "i16" => "Short",
+        "i32" => "Int",
+        "i64" | "isize" => "Long",
+        "u8" => "UByte",
+        "u16" => "UShort",
+        "u32" => "UInt",
+        "u64" | "usize" => "ULong",
+        "f32" => "Float",
+        "f64" => "Double",
+        _ => match known_types::primitive_to_typescript(name) {
+            Some("string") => "String",
+            Some("number") => "Double",
+            _ => JSON_ELEMENT,
+        },
+    }
+}
+
+/// Whether `name` can be used as a Kotlin identifier, backticks aside
+fn is_kotlin_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// `name`, in backticks if it is a keyword
+fn kotlin_identifier(name: &str) -> String {
+    if KOTLIN_KEYWORDS.contains(&name) {
+        format!("`{}`", name)
+    } else {
+        name.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NamingConfig;
+    use crate::generator::test_support::{field, rust_struct};
+    use crate::models::EnumVariant;
+    use std::path::{Path, PathBuf};
+
+    fn generator() -> KotlinGenerator {
+        let mut ctx = GeneratorContext::new(NamingConfig::default());
+        ctx.register_type("User");
+        KotlinGenerator::new(ctx)
+    }
+
+    #[test]
+    fn test_kotlin_type_mapping() {
+        let generator = generator();
+        let string = || RustType::Primitive("String".to_string());
+
+        assert_eq!(generator.kotlin_type(&string()), "String");
+        assert_eq!(
+            generator.kotlin_type(&RustType::Primitive("u32".to_string())),
+            "UInt"
+        );
+        assert_eq!(
+            generator.kotlin_type(&RustType::Option(Box::new(RustType::Option(Box::new(
+                RustType::custom("crate::models::User")
+            ))))),
+            "User?"
+        );
+        assert_eq!(
+            generator.kotlin_type(&RustType::HashMap {
+                key: Box::new(string()),
+                value: Box::new(RustType::Vec(Box::new(string()))),
+            }),
+            "Map<String, List<String>>"
+        );
+        assert_eq!(
+            generator.kotlin_type(&RustType::Tuple(vec![string(), string()])),
+            "JsonElement"
+        );
+    }
+
+    #[test]
+    fn test_struct_becomes_data_class() {
+        let s = rust_struct(
+            "User",
+            vec![
+                field("id", RustType::Primitive("i64".to_string())),
+                field("display-name", RustType::Primitive("String".to_string())),
+                StructField {
+                    use_optional: true,
+                    ..field(
+                        "class",
+                        RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+                    )
+                },
+            ],
+        );
+
+        assert_eq!(
+            generator().struct_declaration(&s),
+            "@Serializable\ndata class User(\n  val id: Long,\n  @SerialName(\"display-name\") val displayName: String,\n  val `class`: String? = null,\n)\n"
+        );
+    }
+
+    #[test]
+    fn test_data_enum_falls_back_to_json_element() {
+        let e = RustEnum {
+            name: "Event".to_string(),
+            generics: vec![],
+            variants: vec![EnumVariant {
+                name: "Moved".to_string(),
+                data: VariantData::Tuple(vec![RustType::Primitive("i32".to_string())]),
+                has_explicit_rename: false,
+                discriminant: None,
+            }],
+            source_file: PathBuf::from("test.rs"),
+            representation: EnumRepresentation::External,
+            docs: None,
+        };
+
+        let output = generator().enum_declaration(&e);
+
+        assert!(output.starts_with("// Not generated:"), "{output}");
+        assert!(
+            output.ends_with("typealias Event = JsonElement\n"),
+            "{output}"
+        );
+    }
+
+    #[test]
+    fn test_commands_follow_prefix_with_module() {
+        let mut ctx = GeneratorContext::with_generation(
+            NamingConfig::default(),
+            crate::config::GenerationConfig {
+                prefix_with_module: true,
+                ..Default::default()
+            },
+        );
+        ctx.register_module(
+            Path::new("src/user.rs"),
+            &["crate".to_string(), "user".to_string()],
+        );
+        let command = TauriCommand {
+            name: "get_by_id".to_string(),
+            args: vec![],
+            return_type: None,
+            source_file: PathBuf::from("src/user.rs"),
+            rename_all: None,
+            docs: None,
+        };
+
+        let output = KotlinGenerator::new(ctx).commands_interface(&[command]);
+
+        assert!(output.contains("  suspend fun userGetById()\n"), "{output}");
+    }
+}
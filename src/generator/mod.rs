@@ -1,10 +1,24 @@
+pub mod backend;
 pub mod commands_gen;
+pub mod events_gen;
+pub mod jsdoc;
+pub mod kotlin;
+pub mod order;
 pub mod type_mapper;
 pub mod types_gen;
+pub mod zod;
 
-use std::collections::HashSet;
+#[cfg(test)]
+pub(crate) mod test_support;
 
-use crate::config::NamingConfig;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use crate::config::{GenerationConfig, NamingConfig, QuoteStyle, StyleConfig, DEFAULT_BANNER};
+use crate::known_types;
+use crate::models::{
+    EnumRepresentation, RustEnum, RustStruct, RustType, RustTypeAlias, StructShape, VariantData,
+};
 
 /// Context for code generation.
 ///
@@ -13,19 +27,123 @@ use crate::config::NamingConfig;
 /// Consumers read via the `format_*` / `is_custom_type` accessors.
 pub struct GeneratorContext {
     naming: NamingConfig,
+    generation: GenerationConfig,
+    style: StyleConfig,
+    banner: Option<String>,
+    types_namespace: Option<String>,
+    args_schemas: Option<PathBuf>,
     custom_types: HashSet<String>,
     enum_types: HashSet<String>,
+    key_types: HashSet<String>,
+    modules: HashMap<PathBuf, Vec<String>>,
 }
 
 impl GeneratorContext {
     pub fn new(naming: NamingConfig) -> Self {
+        Self::with_generation(naming, GenerationConfig::default())
+    }
+
+    /// Like [`GeneratorContext::new`], with explicit `[generation]` switches.
+    pub fn with_generation(naming: NamingConfig, generation: GenerationConfig) -> Self {
         Self {
             naming,
+            generation,
+            style: StyleConfig::default(),
+            banner: None,
+            types_namespace: None,
+            args_schemas: None,
             custom_types: HashSet::new(),
             enum_types: HashSet::new(),
+            key_types: HashSet::new(),
+            modules: HashMap::new(),
         }
     }
 
+    /// Use `[output.style]` indentation and quotes instead of two spaces
+    /// and double quotes.
+    pub fn with_style(mut self, style: StyleConfig) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Open files with `banner` (see [`OutputConfig::banner`]) instead
+    /// of the default notice.
+    ///
+    /// [`OutputConfig::banner`]: crate::config::OutputConfig::banner
+    pub fn with_banner(mut self, banner: Option<String>) -> Self {
+        self.banner = banner;
+        self
+    }
+
+    /// Declare the types inside `export namespace <name>` and refer to
+    /// them as `<name>.User`.
+    pub fn with_types_namespace(mut self, namespace: Option<String>) -> Self {
+        self.types_namespace = namespace;
+        self
+    }
+
+    /// The namespace the type declarations are wrapped in, if any
+    pub fn types_namespace(&self) -> Option<&str> {
+        self.types_namespace.as_deref()
+    }
+
+    /// Validate command arguments against the args schemas in `zod_file`
+    /// before invoking.
+    pub fn with_args_schemas(mut self, zod_file: Option<PathBuf>) -> Self {
+        self.args_schemas = zod_file;
+        self
+    }
+
+    /// The `zod_file` command arguments are validated against, if any
+    pub fn args_schemas(&self) -> Option<&Path> {
+        self.args_schemas.as_deref()
+    }
+
+    /// `level` units of the configured indentation.
+    pub fn indent(&self, level: usize) -> String {
+        self.style.indent.as_str().repeat(level)
+    }
+
+    /// `text` as a TypeScript string literal in the configured quotes.
+    pub fn string_literal(&self, text: &str) -> String {
+        let quote = match self.style.quote {
+            QuoteStyle::Single => '\'',
+            QuoteStyle::Double => '"',
+        };
+        let escaped = text
+            .replace('\\', "\\\\")
+            .replace(quote, &format!("\\{}", quote));
+        format!("{}{}{}", quote, escaped, quote)
+    }
+
+    /// The `[generation]` switches this context was built with.
+    pub fn generation(&self) -> &GenerationConfig {
+        &self.generation
+    }
+
+    /// `docs` when `doc_comments` is on, so generators can pass the
+    /// result straight to [`jsdoc::render_jsdoc`].
+    pub fn doc_comment<'a>(&self, docs: &'a Option<String>) -> Option<&'a str> {
+        if self.generation.doc_comments {
+            docs.as_deref()
+        } else {
+            None
+        }
+    }
+
+    /// Banner opening every generated file: the configured
+    /// `header_pragmas` first, then the configured or default notice.
+    pub fn file_header(&self) -> String {
+        let mut header = String::new();
+        for pragma in &self.generation.header_pragmas {
+            header.push_str(pragma);
+            header.push('\n');
+        }
+        header.push_str(self.banner.as_deref().unwrap_or(DEFAULT_BANNER));
+        header.push('\n');
+        header
+    }
+
     /// Add a custom struct / alias type name to the context.
     pub fn register_type(&mut self, name: &str) {
         self.custom_types.insert(name.to_string());
@@ -38,6 +156,75 @@ impl GeneratorContext {
         self.enum_types.insert(name.to_string());
     }
 
+    /// Register every declaration that serializes as a string or number,
+    /// and so can key a `Record`: newtypes and aliases of such a type, and
+    /// enums of unit variants written as bare strings or numbers.
+    pub fn register_key_types(
+        &mut self,
+        structs: &[RustStruct],
+        enums: &[RustEnum],
+        aliases: &[RustTypeAlias],
+    ) {
+        for e in enums {
+            let bare = match e.representation {
+                EnumRepresentation::Numeric => true,
+                EnumRepresentation::External => e
+                    .variants
+                    .iter()
+                    .all(|v| matches!(v.data, VariantData::Unit)),
+                _ => false,
+            };
+            if bare {
+                self.key_types.insert(e.name.clone());
+            }
+        }
+
+        // A newtype may wrap another newtype; repeat until nothing changes
+        let wrapped: Vec<(&str, &RustType)> = structs
+            .iter()
+            .filter(|s| s.shape == StructShape::Newtype && s.fields.len() == 1)
+            .map(|s| (s.name.as_str(), &s.fields[0].ty))
+            .chain(aliases.iter().map(|a| (a.name.as_str(), &a.target)))
+            .collect();
+        loop {
+            let before = self.key_types.len();
+            for (name, inner) in &wrapped {
+                if self.is_key(inner) {
+                    self.key_types.insert(name.to_string());
+                }
+            }
+            if self.key_types.len() == before {
+                break;
+            }
+        }
+    }
+
+    /// Whether a value of `ty` serializes as a string or number.
+    pub fn is_key(&self, ty: &RustType) -> bool {
+        match ty {
+            RustType::Primitive(name) => matches!(
+                known_types::primitive_to_typescript(name),
+                Some("string" | "number")
+            ),
+            RustType::Custom { name, args } => {
+                args.is_empty() && self.key_types.contains(crate::utils::simple_name(name))
+            }
+            _ => false,
+        }
+    }
+
+    /// Record the module path (`["crate", "user"]`) of a source file, used
+    /// by `group_commands_by_module`.
+    pub fn register_module(&mut self, file: &Path, module_path: &[String]) {
+        self.modules
+            .insert(file.to_path_buf(), module_path.to_vec());
+    }
+
+    /// Module path registered for `file`, if any.
+    pub fn module_of(&self, file: &Path) -> Option<&[String]> {
+        self.modules.get(file).map(Vec::as_slice)
+    }
+
     /// Check if a type name is registered as a custom type.
     pub fn is_custom_type(&self, name: &str) -> bool {
         self.custom_types.contains(name)
@@ -51,14 +238,42 @@ impl GeneratorContext {
         self.enum_types.contains(name)
     }
 
-    /// Apply naming configuration to a type name.
+    /// Apply naming configuration to a type name: its entry in
+    /// `type_overrides`, or the name between the type prefix and suffix.
     pub fn format_type_name(&self, name: &str) -> String {
+        if let Some(overridden) = self.naming.type_overrides.get(name) {
+            return overridden.clone();
+        }
         format!(
             "{}{}{}",
             self.naming.type_prefix, name, self.naming.type_suffix
         )
     }
 
+    /// How generated code refers to the exported type `name`: its
+    /// formatted name, qualified with the types namespace if there is one.
+    pub fn type_reference(&self, name: &str) -> String {
+        match &self.types_namespace {
+            Some(namespace) => format!("{}.{}", namespace, self.format_type_name(name)),
+            None => self.format_type_name(name),
+        }
+    }
+
+    /// Names a module imports from the types file to refer to `types`:
+    /// the types themselves, or just the namespace holding them.
+    pub fn type_import_names(&self, types: impl IntoIterator<Item = String>) -> Vec<String> {
+        let mut names: Vec<String> = types.into_iter().collect();
+        if names.is_empty() {
+            return names;
+        }
+        if let Some(namespace) = &self.types_namespace {
+            return vec![namespace.clone()];
+        }
+        names.sort();
+        names.dedup();
+        names
+    }
+
     /// Apply naming configuration to a function name.
     pub fn format_function_name(&self, name: &str) -> String {
         format!(
@@ -66,4 +281,50 @@ impl GeneratorContext {
             self.naming.function_prefix, name, self.naming.function_suffix
         )
     }
+
+    /// Render a name in property-key position (interface fields, variant
+    /// keys, tag/content keys).
+    ///
+    /// Keys that aren't plain identifiers (`first-name` from a kebab-case
+    /// rename) must be quoted to be valid TypeScript. Reserved words are
+    /// legal bare keys, so they're only quoted under `quote_reserved_keys`.
+    /// Quoting keeps the key identical to serde's JSON; renaming it would not.
+    pub fn property_key(&self, name: &str) -> String {
+        let quote = !is_ts_identifier(name)
+            || (self.generation.quote_reserved_keys && known_types::is_ts_reserved_word(name));
+        if quote {
+            self.string_literal(name)
+        } else {
+            name.to_string()
+        }
+    }
+}
+
+/// `items` ordered by name, so the generated files don't depend on the
+/// order types were collected or files were scanned in.
+pub fn sorted_by_name<T>(items: &[T], name: impl Fn(&T) -> &str) -> Vec<&T> {
+    let mut sorted: Vec<&T> = items.iter().collect();
+    sorted.sort_by(|a, b| name(a).cmp(name(b)));
+    sorted
+}
+
+/// Make `name` usable as a function or parameter name: a reserved word
+/// gets a trailing `_` (`delete` -> `delete_`). Quoting isn't an option in
+/// binding positions.
+pub fn ts_identifier(name: &str) -> String {
+    if known_types::is_ts_reserved_word(name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// Whether `name` can appear unquoted as a property key
+fn is_ts_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_alphabetic() || c == '_' || c == '$' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
 }
@@ -0,0 +1,191 @@
+//! Dependency order for exported types.
+//!
+//! TypeScript type declarations may reference each other in any order, so
+//! the types file simply sorts them by name. Value emissions (`const`
+//! schemas, `const enum`s) are evaluated top to bottom and need every
+//! referenced declaration to come first; they iterate [`dependency_order`].
+
+use std::collections::HashMap;
+
+use crate::models::{
+    walk_custom_type_names, RustEnum, RustStruct, RustType, RustTypeAlias, VariantData,
+};
+use crate::utils::simple_name;
+
+/// One exported declaration, whatever its kind.
+#[derive(Debug, Clone, Copy)]
+pub enum TypeDecl<'a> {
+    Struct(&'a RustStruct),
+    Enum(&'a RustEnum),
+    Alias(&'a RustTypeAlias),
+}
+
+impl<'a> TypeDecl<'a> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            TypeDecl::Struct(s) => &s.name,
+            TypeDecl::Enum(e) => &e.name,
+            TypeDecl::Alias(a) => &a.name,
+        }
+    }
+
+    /// Simple names of the custom types this declaration refers to, in
+    /// order of first appearance.
+    fn dependencies(&self) -> Vec<String> {
+        let mut roots: Vec<&'a RustType> = Vec::new();
+        match self {
+            TypeDecl::Struct(s) => roots.extend(s.fields.iter().map(|f| &f.ty)),
+            TypeDecl::Enum(e) => {
+                for variant in &e.variants {
+                    match &variant.data {
+                        VariantData::Unit => {}
+                        VariantData::Tuple(types) => roots.extend(types.iter()),
+                        VariantData::Struct(fields) => roots.extend(fields.iter().map(|f| &f.ty)),
+                    }
+                }
+            }
+            TypeDecl::Alias(a) => roots.push(&a.target),
+        }
+
+        let mut deps: Vec<String> = Vec::new();
+        for ty in roots {
+            walk_custom_type_names(ty, &mut |name| {
+                let name = simple_name(name);
+                if !deps.iter().any(|d| d == name) {
+                    deps.push(name.to_string());
+                }
+            });
+        }
+        deps
+    }
+}
+
+/// Every struct, enum and alias, ordered so that a declaration comes after
+/// the declarations it references. Unrelated declarations keep their input
+/// order (structs, then enums, then aliases), so a sorted input stays
+/// sorted wherever dependencies allow.
+///
+/// Recursive types have no such order; a cycle is broken at the edge that
+/// closes it, and the emitter has to defer that reference (`z.lazy`).
+pub fn dependency_order<'a>(
+    structs: &'a [RustStruct],
+    enums: &'a [RustEnum],
+    aliases: &'a [RustTypeAlias],
+) -> Vec<TypeDecl<'a>> {
+    let decls: Vec<TypeDecl<'a>> = structs
+        .iter()
+        .map(TypeDecl::Struct)
+        .chain(enums.iter().map(TypeDecl::Enum))
+        .chain(aliases.iter().map(TypeDecl::Alias))
+        .collect();
+
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for (i, decl) in decls.iter().enumerate() {
+        index.entry(decl.name()).or_insert(i);
+    }
+
+    let mut state = vec![Visit::Pending; decls.len()];
+    let mut ordered = Vec::with_capacity(decls.len());
+    for i in 0..decls.len() {
+        visit(i, &decls, &index, &mut state, &mut ordered);
+    }
+    ordered
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Visit {
+    Pending,
+    InProgress,
+    Done,
+}
+
+/// Depth-first post-order: emit `i` once everything it references is out.
+fn visit<'a>(
+    i: usize,
+    decls: &[TypeDecl<'a>],
+    index: &HashMap<&str, usize>,
+    state: &mut [Visit],
+    ordered: &mut Vec<TypeDecl<'a>>,
+) {
+    if state[i] != Visit::Pending {
+        return;
+    }
+    state[i] = Visit::InProgress;
+    for dep in decls[i].dependencies() {
+        if let Some(&j) = index.get(dep.as_str()) {
+            visit(j, decls, index, state, ordered);
+        }
+    }
+    state[i] = Visit::Done;
+    ordered.push(decls[i]);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::test_support::{field, rust_struct};
+    use crate::models::{EnumRepresentation, EnumVariant};
+    use std::path::PathBuf;
+
+    fn names(decls: &[TypeDecl]) -> Vec<String> {
+        decls.iter().map(|d| d.name().to_string()).collect()
+    }
+
+    #[test]
+    fn test_referenced_types_come_first() {
+        let structs = vec![
+            rust_struct(
+                "User",
+                vec![
+                    field("address", RustType::custom("crate::models::Address")),
+                    field("role", RustType::custom("Role")),
+                ],
+            ),
+            rust_struct("Address", vec![field("city", RustType::custom("City"))]),
+        ];
+        let enums = vec![RustEnum {
+            name: "Role".to_string(),
+            generics: vec![],
+            variants: vec![EnumVariant {
+                name: "Admin".to_string(),
+                data: VariantData::Unit,
+                has_explicit_rename: false,
+                discriminant: None,
+            }],
+            source_file: PathBuf::from("test.rs"),
+            representation: EnumRepresentation::External,
+            docs: None,
+        }];
+        let aliases = vec![RustTypeAlias {
+            name: "City".to_string(),
+            generics: vec![],
+            target: RustType::Primitive("String".to_string()),
+            source_file: PathBuf::from("test.rs"),
+        }];
+
+        let ordered = dependency_order(&structs, &enums, &aliases);
+
+        assert_eq!(names(&ordered), ["City", "Address", "Role", "User"]);
+    }
+
+    #[test]
+    fn test_unrelated_types_keep_input_order_and_cycles_terminate() {
+        let structs = vec![
+            rust_struct("Zeta", vec![]),
+            rust_struct(
+                "Node",
+                vec![field(
+                    "children",
+                    RustType::Vec(Box::new(RustType::custom("Node"))),
+                )],
+            ),
+            rust_struct("Ping", vec![field("pong", RustType::custom("Pong"))]),
+            rust_struct("Pong", vec![field("ping", RustType::custom("Ping"))]),
+            rust_struct("Alpha", vec![]),
+        ];
+
+        let ordered = dependency_order(&structs, &[], &[]);
+
+        assert_eq!(names(&ordered), ["Zeta", "Node", "Pong", "Ping", "Alpha"]);
+    }
+}
@@ -0,0 +1,31 @@
+//! Model builders shared by the generator unit tests.
+
+use std::path::PathBuf;
+
+use crate::models::{DerivedTypes, RustStruct, RustType, StructField, StructShape};
+
+/// A plain named field: no rename, not optional, not flattened.
+pub(crate) fn field(name: &str, ty: RustType) -> StructField {
+    StructField {
+        name: name.to_string(),
+        ty,
+        has_explicit_rename: false,
+        use_optional: false,
+        keeps_null: false,
+        is_flatten: false,
+        docs: None,
+    }
+}
+
+/// A non-generic named struct declared in `test.rs`.
+pub(crate) fn rust_struct(name: &str, fields: Vec<StructField>) -> RustStruct {
+    RustStruct {
+        name: name.to_string(),
+        generics: vec![],
+        fields,
+        shape: StructShape::Named,
+        source_file: PathBuf::from("test.rs"),
+        derived: DerivedTypes::default(),
+        docs: None,
+    }
+}
@@ -1,20 +1,63 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::config::{DecimalType, MapKeyFallback};
 use crate::known_types;
-use crate::models::RustType;
+use crate::models::{walk_types, RustType, VariantData};
 
+use super::backend::Bindings;
 use super::GeneratorContext;
 
 /// Convert a Rust type to its TypeScript equivalent
 pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> String {
+    to_typescript(rust_type, ctx, false)
+}
+
+/// Like [`rust_to_typescript`], for the type of an interface member: under
+/// `readonly_fields` every array in it becomes `readonly T[]`, and under
+/// `deep_readonly` every map and tuple too.
+pub fn member_type_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> String {
+    to_typescript(rust_type, ctx, ctx.generation().readonly_members())
+}
+
+/// Like [`rust_to_typescript`], for the right-hand side of a declaration
+/// (alias, newtype, enum variant payload): readonly all the way down
+/// under `deep_readonly`.
+pub fn declared_type_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> String {
+    to_typescript(rust_type, ctx, ctx.generation().deep_readonly)
+}
+
+/// `readonly [A, B]` under `deep_readonly`, `[A, B]` otherwise
+pub fn tuple_to_typescript(elements: &[String], readonly: bool) -> String {
+    if readonly {
+        format!("readonly [{}]", elements.join(", "))
+    } else {
+        format!("[{}]", elements.join(", "))
+    }
+}
+
+fn to_typescript(rust_type: &RustType, ctx: &GeneratorContext, readonly_arrays: bool) -> String {
+    // Maps and tuples only turn readonly under `deep_readonly`
+    let deep = readonly_arrays && ctx.generation().deep_readonly;
     match rust_type {
-        RustType::Primitive(name) => primitive_to_typescript(name),
+        RustType::Primitive(name) => match branded_numeric_alias(name, ctx) {
+            Some(alias) => ctx.type_reference(&alias),
+            None => primitive_to_typescript(name, ctx),
+        },
 
         RustType::Vec(inner) => {
-            let inner_ts = rust_to_typescript(inner, ctx);
-            // Wrap in parentheses if it's a union type (contains |)
-            if inner_ts.contains('|') {
-                format!("({})[]", inner_ts)
+            let inner_ts = to_typescript(inner, ctx, readonly_arrays);
+            // Wrap in parentheses if it's a union type (contains |) or
+            // itself a readonly array
+            let element = if inner_ts.contains('|') || inner_ts.starts_with("readonly ") {
+                format!("({})", inner_ts)
+            } else {
+                inner_ts
+            };
+            if readonly_arrays {
+                format!("readonly {}[]", element)
             } else {
-                format!("{}[]", inner_ts)
+                format!("{}[]", element)
             }
         }
 
@@ -26,43 +69,47 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             while let RustType::Option(deeper) = current {
                 current = deeper;
             }
-            let inner_ts = rust_to_typescript(current, ctx);
+            let inner_ts = to_typescript(current, ctx, readonly_arrays);
             format!("{} | null", inner_ts)
         }
 
-        RustType::Result(ok) => {
+        RustType::Result { ok, .. } => {
             // For Result types, we return the Ok type
             // The error will be handled by Promise rejection
-            rust_to_typescript(ok, ctx)
+            to_typescript(ok, ctx, readonly_arrays)
         }
 
         RustType::Channel(inner) => {
-            let inner_ts = rust_to_typescript(inner, ctx);
+            let inner_ts = to_typescript(inner, ctx, readonly_arrays);
             format!("Channel<{}>", inner_ts)
         }
 
         RustType::HashMap { key, value } => {
-            let key_ts = rust_to_typescript(key, ctx);
-            let value_ts = rust_to_typescript(value, ctx);
-
-            // Check if strict key usage is safe for TypeScript Record
-            let use_param_key = match &**key {
-                // bool keys become strings in JSON ("true"/"false") but are invalid in TS Record<bool, ...>
-                RustType::Primitive(p) if p == "bool" => false,
-                // numbers/strings are fine
-                RustType::Primitive(_) => true,
-                // Custom types (enums, newtypes) are assumed to be valid string/number keys
-                RustType::Custom { name: _, .. } => true,
-                // Generic Params are assumed to be valid
-                RustType::Generic(_) => true,
-                // Complex types (Vec, Option, etc) cannot be keys in TS Record
-                _ => false,
-            };
+            if map_as_entries(key, ctx) {
+                let entries = RustType::Vec(Box::new(RustType::Tuple(vec![
+                    (**key).clone(),
+                    (**value).clone(),
+                ])));
+                return to_typescript(&entries, ctx, readonly_arrays);
+            }
+            let key_ts = to_typescript(key, ctx, readonly_arrays);
+            let value_ts = to_typescript(value, ctx, readonly_arrays);
 
-            if use_param_key {
+            // TypeScript only accepts string and number keys in a Record;
+            // JSON keys are strings either way. The others are reported
+            // once by `warn_unkeyable_map_keys`.
+            let record = if keys_as_string(key, ctx) {
+                format!("Record<string, {}>", value_ts)
+            } else if ctx.generation().partial_enum_records && is_enum_key(key, ctx) {
+                // A map keyed by an enum need not hold every variant
+                format!("Partial<Record<{}, {}>>", key_ts, value_ts)
+            } else {
                 format!("Record<{}, {}>", key_ts, value_ts)
+            };
+            if deep {
+                format!("Readonly<{}>", record)
             } else {
-                format!("Record<string, {}>", value_ts)
+                record
             }
         }
 
@@ -70,18 +117,29 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
             if types.is_empty() {
                 "void".to_string()
             } else {
-                let type_strs: Vec<_> = types.iter().map(|t| rust_to_typescript(t, ctx)).collect();
-                format!("[{}]", type_strs.join(", "))
+                let type_strs: Vec<_> = types
+                    .iter()
+                    .map(|t| to_typescript(t, ctx, readonly_arrays))
+                    .collect();
+                tuple_to_typescript(&type_strs, deep)
             }
         }
 
         RustType::Custom { name, args } => {
-            let base = render_custom_name(name, ctx);
+            let simple = crate::utils::simple_name(name);
+            // Nothing declares a type the pipeline couldn't resolve, so
+            // naming it would leave a dangling reference
+            if !ctx.is_custom_type(simple) {
+                return "unknown".to_string();
+            }
+            let base = ctx.type_reference(simple);
             if args.is_empty() {
                 base
             } else {
-                let rendered: Vec<String> =
-                    args.iter().map(|a| rust_to_typescript(a, ctx)).collect();
+                let rendered: Vec<String> = args
+                    .iter()
+                    .map(|a| to_typescript(a, ctx, readonly_arrays))
+                    .collect();
                 format!("{}<{}>", base, rendered.join(", "))
             }
         }
@@ -100,23 +158,147 @@ pub fn rust_to_typescript(rust_type: &RustType, ctx: &GeneratorContext) -> Strin
     }
 }
 
-/// TypeScript doesn't have module paths, so every `Custom(name)` needs to
-/// be reduced to its final segment before we look it up in the context.
-/// Otherwise a Rust reference like `crate::types::User` leaks into the
-/// generated output verbatim (`crate::types::User` is not valid TS).
-fn render_custom_name(path: &str, ctx: &GeneratorContext) -> String {
-    let simple = crate::utils::simple_name(path);
-    if ctx.is_custom_type(simple) {
-        ctx.format_type_name(simple)
+/// Whether a map keyed by `key` is typed as its `[K, V][]` entries: under
+/// `map_key_fallback = "entries"`, for keys known not to serialize as a
+/// string or number. Primitives (`bool` becomes `"true"`), generic
+/// parameters and unresolved types, which nothing declares an entry type
+/// for, keep the `Record`; `warn_unkeyable_map_keys` reports the first
+/// and last.
+pub fn map_as_entries(key: &RustType, ctx: &GeneratorContext) -> bool {
+    ctx.generation().map_key_fallback == MapKeyFallback::Entries
+        && match key {
+            RustType::Primitive(_) | RustType::Generic(_) => false,
+            RustType::Custom { name, .. } => {
+                ctx.is_custom_type(crate::utils::simple_name(name)) && !ctx.is_key(key)
+            }
+            _ => true,
+        }
+}
+
+/// Whether a map keyed by `key`, unless [`map_as_entries`], is typed as
+/// the `Record<string, V>` fallback: its key is neither a string or number
+/// key nor a generic parameter (assumed to be one). `bool` keys become
+/// strings in JSON ("true"/"false") but are invalid in `Record<bool, V>`.
+fn keys_as_string(key: &RustType, ctx: &GeneratorContext) -> bool {
+    !ctx.is_key(key) && !matches!(key, RustType::Generic(_))
+}
+
+/// Warn once per map key type that falls back to `Record<string, V>`, at
+/// the first declaration or command using it.
+pub fn warn_unkeyable_map_keys(bindings: &Bindings, ctx: &GeneratorContext) {
+    let mut warned = HashSet::new();
+    let mut check = |ty: &RustType, file: &Path| {
+        walk_types(ty, &mut |node| {
+            let RustType::HashMap { key, .. } = node else {
+                return;
+            };
+            if map_as_entries(key, ctx) || !keys_as_string(key, ctx) {
+                return;
+            }
+            let (name, reason) = match &**key {
+                RustType::Custom { name, .. } => {
+                    let simple = crate::utils::simple_name(name);
+                    if ctx.is_custom_type(simple) {
+                        (
+                            simple.to_string(),
+                            "does not serialize as a string or number",
+                        )
+                    } else {
+                        // Nothing says how an unresolved type serializes
+                        (simple.to_string(), "is unresolved")
+                    }
+                }
+                RustType::Primitive(name) => (
+                    name.clone(),
+                    "has no TypeScript type a Record can be keyed by",
+                ),
+                RustType::Unknown(desc) => (desc.clone(), "is unresolved"),
+                _ => (
+                    rust_to_typescript(key, ctx),
+                    "does not serialize as a string or number",
+                ),
+            };
+            if warned.insert(name.clone()) {
+                crate::diagnostics::current().warn_in(
+                    file,
+                    None,
+                    format!(
+                        "Map key type '{}' in {} {}; using 'Record<string, V>'",
+                        name,
+                        file.display(),
+                        reason
+                    ),
+                );
+            }
+        });
+    };
+
+    for s in bindings.structs {
+        for field in &s.fields {
+            check(&field.ty, &s.source_file);
+        }
+    }
+    for e in bindings.enums {
+        for variant in &e.variants {
+            match &variant.data {
+                VariantData::Unit => {}
+                VariantData::Tuple(types) => {
+                    for ty in types {
+                        check(ty, &e.source_file);
+                    }
+                }
+                VariantData::Struct(fields) => {
+                    for field in fields {
+                        check(&field.ty, &e.source_file);
+                    }
+                }
+            }
+        }
+    }
+    for alias in bindings.aliases {
+        check(&alias.target, &alias.source_file);
+    }
+    for cmd in bindings.commands {
+        for arg in &cmd.args {
+            check(&arg.ty, &cmd.source_file);
+        }
+        if let Some(ty) = &cmd.return_type {
+            check(ty, &cmd.source_file);
+        }
+    }
+    for event in bindings.events {
+        check(&event.payload, &event.source_file);
+    }
+}
+
+fn is_enum_key(key: &RustType, ctx: &GeneratorContext) -> bool {
+    matches!(key, RustType::Custom { name, .. } if ctx.is_enum(crate::utils::simple_name(name)))
+}
+
+/// Alias name for a numeric primitive under `numeric_branding`
+/// (`i32` → `I32`, `usize` → `Usize`). `None` when branding is off or
+/// `name` isn't a built-in integer/float.
+pub fn branded_numeric_alias(name: &str, ctx: &GeneratorContext) -> Option<String> {
+    if ctx.generation().numeric_branding && known_types::is_numeric_primitive(name) {
+        Some(crate::utils::to_pascal_case(name))
     } else {
-        simple.to_string()
+        None
+    }
+}
+
+/// TypeScript type of a known Rust primitive, honouring `decimal_type`
+pub fn known_primitive_type(name: &str, ctx: &GeneratorContext) -> Option<&'static str> {
+    if known_types::DECIMAL_TYPES.contains(&name)
+        && ctx.generation().decimal_type == DecimalType::Number
+    {
+        return Some("number");
     }
+    known_types::primitive_to_typescript(name)
 }
 
 /// Convert a Rust primitive type name to TypeScript
-fn primitive_to_typescript(name: &str) -> String {
-    // Use the centralized known_types module
-    if let Some(ts_type) = known_types::primitive_to_typescript(name) {
+fn primitive_to_typescript(name: &str, ctx: &GeneratorContext) -> String {
+    if let Some(ts_type) = known_primitive_type(name, ctx) {
         return ts_type.to_string();
     }
 
@@ -144,27 +326,27 @@ mod tests {
 
     #[test]
     fn test_primitive_to_typescript() {
-        assert_eq!(primitive_to_typescript("String"), "string");
-        assert_eq!(primitive_to_typescript("i32"), "number");
-        assert_eq!(primitive_to_typescript("u64"), "number");
-        assert_eq!(primitive_to_typescript("f32"), "number");
-        assert_eq!(primitive_to_typescript("bool"), "boolean");
+        assert_eq!(primitive_to_typescript("String", &default_ctx()), "string");
+        assert_eq!(primitive_to_typescript("i32", &default_ctx()), "number");
+        assert_eq!(primitive_to_typescript("u64", &default_ctx()), "number");
+        assert_eq!(primitive_to_typescript("f32", &default_ctx()), "number");
+        assert_eq!(primitive_to_typescript("bool", &default_ctx()), "boolean");
     }
 
     #[test]
     fn test_primitive_all_integers() {
         for int_type in ["i8", "i16", "i32", "i64", "i128", "isize"] {
-            assert_eq!(primitive_to_typescript(int_type), "number");
+            assert_eq!(primitive_to_typescript(int_type, &default_ctx()), "number");
         }
         for uint_type in ["u8", "u16", "u32", "u64", "u128", "usize"] {
-            assert_eq!(primitive_to_typescript(uint_type), "number");
+            assert_eq!(primitive_to_typescript(uint_type, &default_ctx()), "number");
         }
     }
 
     #[test]
     fn test_primitive_floats() {
-        assert_eq!(primitive_to_typescript("f32"), "number");
-        assert_eq!(primitive_to_typescript("f64"), "number");
+        assert_eq!(primitive_to_typescript("f32", &default_ctx()), "number");
+        assert_eq!(primitive_to_typescript("f64", &default_ctx()), "number");
     }
 
     // (No `test_to_camel_case` here — the canonical tests live in
@@ -200,17 +382,38 @@ mod tests {
         assert_eq!(rust_to_typescript(&ty, &ctx), "User | null");
     }
 
+    #[test]
+    fn test_double_option_collapses_to_one_null() {
+        let ctx = ctx_with_type("User");
+        let string = RustType::Option(Box::new(RustType::Option(Box::new(RustType::Primitive(
+            "String".to_string(),
+        )))));
+        assert_eq!(rust_to_typescript(&string, &ctx), "string | null");
+        let user = RustType::Option(Box::new(RustType::Option(Box::new(RustType::custom(
+            "User",
+        )))));
+        assert_eq!(rust_to_typescript(&user, &ctx), "User | null");
+        // Members go through the same collapse
+        assert_eq!(member_type_to_typescript(&user, &ctx), "User | null");
+    }
+
     #[test]
     fn test_result_to_typescript() {
         let ctx = ctx_with_type("User");
-        let ty = RustType::Result(Box::new(RustType::custom("User")));
+        let ty = RustType::Result {
+            ok: Box::new(RustType::custom("User")),
+            err: Some(Box::new(RustType::Primitive("String".to_string()))),
+        };
         assert_eq!(rust_to_typescript(&ty, &ctx), "User");
     }
 
     #[test]
     fn test_result_with_vec() {
         let ctx = ctx_with_type("Item");
-        let ty = RustType::Result(Box::new(RustType::Vec(Box::new(RustType::custom("Item")))));
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Vec(Box::new(RustType::custom("Item")))),
+            err: None,
+        };
         assert_eq!(rust_to_typescript(&ty, &ctx), "Item[]");
     }
 
@@ -234,6 +437,146 @@ mod tests {
         assert_eq!(rust_to_typescript(&ty, &ctx), "Record<string, User>");
     }
 
+    fn map_of(key: RustType) -> RustType {
+        RustType::HashMap {
+            key: Box::new(key),
+            value: Box::new(RustType::custom("User")),
+        }
+    }
+
+    /// Context knowing `Role` (unit enum), `UserId` (newtype of `u64`),
+    /// `Handle` (newtype of `UserId`) and `Point` (struct)
+    fn ctx_with_key_candidates(generation: crate::config::GenerationConfig) -> GeneratorContext {
+        use crate::models::{
+            DerivedTypes, EnumRepresentation, EnumVariant, RustEnum, RustStruct, StructField,
+            StructShape, VariantData,
+        };
+        use std::path::PathBuf;
+
+        let newtype = |name: &str, inner: RustType| RustStruct {
+            name: name.to_string(),
+            generics: vec![],
+            fields: vec![StructField {
+                name: "0".to_string(),
+                ty: inner,
+                has_explicit_rename: false,
+                use_optional: false,
+                keeps_null: false,
+                is_flatten: false,
+                docs: None,
+            }],
+            shape: StructShape::Newtype,
+            source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
+        };
+        let structs = vec![
+            newtype("Handle", RustType::custom("UserId")),
+            newtype("UserId", RustType::Primitive("u64".to_string())),
+            RustStruct {
+                shape: StructShape::Named,
+                ..newtype("Point", RustType::Primitive("i32".to_string()))
+            },
+        ];
+        let enums = vec![RustEnum {
+            name: "Role".to_string(),
+            generics: vec![],
+            variants: vec![EnumVariant {
+                name: "Admin".to_string(),
+                data: VariantData::Unit,
+                has_explicit_rename: false,
+                discriminant: None,
+            }],
+            source_file: PathBuf::from("test.rs"),
+            representation: EnumRepresentation::External,
+            docs: None,
+        }];
+
+        let mut ctx = GeneratorContext::with_generation(NamingConfig::default(), generation);
+        for name in ["User", "Handle", "UserId", "Point"] {
+            ctx.register_type(name);
+        }
+        ctx.register_enum("Role");
+        ctx.register_key_types(&structs, &enums, &[]);
+        ctx
+    }
+
+    #[test]
+    fn test_hashmap_key_types() {
+        let ctx = ctx_with_key_candidates(Default::default());
+
+        let map = |key: RustType| rust_to_typescript(&map_of(key), &ctx);
+        assert_eq!(
+            map(RustType::Primitive("i32".to_string())),
+            "Record<number, User>"
+        );
+        // `true`/`false` are string keys on the wire, but not a Record key type
+        assert_eq!(
+            map(RustType::Primitive("bool".to_string())),
+            "Record<string, User>"
+        );
+        // A unit enum is a union of string literals
+        assert_eq!(map(RustType::custom("Role")), "Record<Role, User>");
+        // Newtypes of a number, directly or through another newtype
+        assert_eq!(map(RustType::custom("UserId")), "Record<UserId, User>");
+        assert_eq!(map(RustType::custom("Handle")), "Record<Handle, User>");
+        // An object type can't key a Record: the map is typed as its entries
+        // `HashMap<Point, i32>`
+        let point_counts = RustType::HashMap {
+            key: Box::new(RustType::custom("Point")),
+            value: Box::new(RustType::Primitive("i32".to_string())),
+        };
+        assert_eq!(rust_to_typescript(&point_counts, &ctx), "[Point, number][]");
+        assert_eq!(
+            map(RustType::Tuple(vec![
+                RustType::Primitive("i32".to_string()),
+                RustType::Primitive("i32".to_string()),
+            ])),
+            "[[number, number], User][]"
+        );
+        // Nothing declares an unresolved key, so there is no entry type,
+        // and it may well serialize as a string
+        assert_eq!(map(RustType::custom("Unknown")), "Record<string, User>");
+        assert!(!map_as_entries(&RustType::custom("Unknown"), &ctx));
+    }
+
+    #[test]
+    fn test_map_key_fallback_record() {
+        let ctx = ctx_with_key_candidates(crate::config::GenerationConfig {
+            map_key_fallback: MapKeyFallback::Record,
+            ..Default::default()
+        });
+
+        let map = |key: RustType| rust_to_typescript(&map_of(key), &ctx);
+        assert_eq!(map(RustType::custom("Point")), "Record<string, User>");
+        assert_eq!(
+            map(RustType::Tuple(vec![
+                RustType::Primitive("i32".to_string()),
+                RustType::Primitive("i32".to_string()),
+            ])),
+            "Record<string, User>"
+        );
+        // Keys JSON can hold are unaffected
+        assert_eq!(map(RustType::custom("UserId")), "Record<UserId, User>");
+    }
+
+    #[test]
+    fn test_partial_enum_records() {
+        let ctx = ctx_with_key_candidates(crate::config::GenerationConfig {
+            partial_enum_records: true,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            rust_to_typescript(&map_of(RustType::custom("Role")), &ctx),
+            "Partial<Record<Role, User>>"
+        );
+        assert_eq!(
+            rust_to_typescript(&map_of(RustType::custom("UserId")), &ctx),
+            "Record<UserId, User>"
+        );
+    }
+
     #[test]
     fn test_tuple_to_typescript() {
         let ctx = default_ctx();
@@ -245,6 +588,28 @@ mod tests {
         assert_eq!(rust_to_typescript(&ty, &ctx), "[number, string, boolean]");
     }
 
+    #[test]
+    fn test_tuple_with_option_elements() {
+        let ctx = default_ctx();
+        let option = |name: &str| RustType::Option(Box::new(RustType::Primitive(name.to_string())));
+        // Commas bound each element, so a union needs no parentheses
+        let ty = RustType::Tuple(vec![
+            option("i32"),
+            RustType::Primitive("String".to_string()),
+        ]);
+        assert_eq!(rust_to_typescript(&ty, &ctx), "[number | null, string]");
+
+        // ...except as an array element
+        let ty = RustType::Tuple(vec![
+            RustType::Vec(Box::new(option("i32"))),
+            option("String"),
+        ]);
+        assert_eq!(
+            rust_to_typescript(&ty, &ctx),
+            "[(number | null)[], string | null]"
+        );
+    }
+
     #[test]
     fn test_empty_tuple_to_void() {
         let ctx = default_ctx();
@@ -270,7 +635,13 @@ mod tests {
     fn test_custom_type_unregistered() {
         let ctx = default_ctx();
         let ty = RustType::custom("UnknownType");
-        assert_eq!(rust_to_typescript(&ty, &ctx), "UnknownType");
+        // Never declared in the types file, so not referenced by name
+        assert_eq!(rust_to_typescript(&ty, &ctx), "unknown");
+        let ty = RustType::Custom {
+            name: "crate::api::Page".to_string(),
+            args: vec![RustType::custom("UnknownType")],
+        };
+        assert_eq!(rust_to_typescript(&ty, &ctx), "unknown");
     }
 
     #[test]
@@ -282,58 +653,100 @@ mod tests {
 
     #[test]
     fn test_external_types_chrono() {
-        assert_eq!(primitive_to_typescript("DateTime"), "string");
-        assert_eq!(primitive_to_typescript("NaiveDateTime"), "string");
-        assert_eq!(primitive_to_typescript("NaiveDate"), "string");
-        assert_eq!(primitive_to_typescript("NaiveTime"), "string");
+        assert_eq!(
+            primitive_to_typescript("DateTime", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("NaiveDateTime", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("NaiveDate", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("NaiveTime", &default_ctx()),
+            "string"
+        );
     }
 
     #[test]
     fn test_external_types_time() {
-        assert_eq!(primitive_to_typescript("OffsetDateTime"), "string");
-        assert_eq!(primitive_to_typescript("PrimitiveDateTime"), "string");
-        assert_eq!(primitive_to_typescript("Date"), "string");
-        assert_eq!(primitive_to_typescript("Time"), "string");
+        assert_eq!(
+            primitive_to_typescript("OffsetDateTime", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("PrimitiveDateTime", &default_ctx()),
+            "string"
+        );
+        assert_eq!(primitive_to_typescript("Date", &default_ctx()), "string");
+        assert_eq!(primitive_to_typescript("Time", &default_ctx()), "string");
     }
 
     #[test]
     fn test_external_types_uuid() {
-        assert_eq!(primitive_to_typescript("Uuid"), "string");
+        assert_eq!(primitive_to_typescript("Uuid", &default_ctx()), "string");
     }
 
     #[test]
     fn test_external_types_decimal() {
-        assert_eq!(primitive_to_typescript("Decimal"), "string");
-        assert_eq!(primitive_to_typescript("BigDecimal"), "string");
+        assert_eq!(primitive_to_typescript("Decimal", &default_ctx()), "string");
+        assert_eq!(
+            primitive_to_typescript("BigDecimal", &default_ctx()),
+            "string"
+        );
     }
 
     #[test]
     fn test_external_types_path() {
-        assert_eq!(primitive_to_typescript("PathBuf"), "string");
-        assert_eq!(primitive_to_typescript("Path"), "string");
+        assert_eq!(primitive_to_typescript("PathBuf", &default_ctx()), "string");
+        assert_eq!(primitive_to_typescript("Path", &default_ctx()), "string");
     }
 
     #[test]
     fn test_external_types_network() {
-        assert_eq!(primitive_to_typescript("IpAddr"), "string");
-        assert_eq!(primitive_to_typescript("Ipv4Addr"), "string");
-        assert_eq!(primitive_to_typescript("Ipv6Addr"), "string");
-        assert_eq!(primitive_to_typescript("Url"), "string");
+        assert_eq!(primitive_to_typescript("IpAddr", &default_ctx()), "string");
+        assert_eq!(
+            primitive_to_typescript("Ipv4Addr", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("Ipv6Addr", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("SocketAddr", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("SocketAddrV4", &default_ctx()),
+            "string"
+        );
+        assert_eq!(
+            primitive_to_typescript("SocketAddrV6", &default_ctx()),
+            "string"
+        );
+        assert_eq!(primitive_to_typescript("Url", &default_ctx()), "string");
     }
 
     #[test]
     fn test_duration_to_number() {
-        assert_eq!(primitive_to_typescript("Duration"), "number");
+        assert_eq!(
+            primitive_to_typescript("Duration", &default_ctx()),
+            "number"
+        );
     }
 
     #[test]
     fn test_serde_value_to_unknown() {
-        assert_eq!(primitive_to_typescript("Value"), "unknown");
+        assert_eq!(primitive_to_typescript("Value", &default_ctx()), "unknown");
     }
 
     #[test]
     fn test_bytes_to_number_array() {
-        assert_eq!(primitive_to_typescript("Bytes"), "number[]");
+        assert_eq!(primitive_to_typescript("Bytes", &default_ctx()), "number[]");
     }
 
     #[test]
@@ -343,6 +756,7 @@ mod tests {
             type_suffix: "".to_string(),
             function_prefix: "".to_string(),
             function_suffix: "".to_string(),
+            type_overrides: Default::default(),
         });
         ctx.register_type("User");
         let ty = RustType::custom("User");
@@ -356,18 +770,38 @@ mod tests {
             type_suffix: "DTO".to_string(),
             function_prefix: "".to_string(),
             function_suffix: "".to_string(),
+            type_overrides: Default::default(),
         });
         ctx.register_type("User");
         let ty = RustType::custom("User");
         assert_eq!(rust_to_typescript(&ty, &ctx), "UserDTO");
     }
 
+    #[test]
+    fn test_numeric_branding_references_alias() {
+        let ctx = GeneratorContext::with_generation(
+            NamingConfig::default(),
+            crate::config::GenerationConfig {
+                numeric_branding: true,
+                ..Default::default()
+            },
+        );
+        let ty = RustType::Vec(Box::new(RustType::Primitive("u64".to_string())));
+        assert_eq!(rust_to_typescript(&ty, &ctx), "U64[]");
+        // Non-numeric primitives are untouched.
+        let ty = RustType::Primitive("String".to_string());
+        assert_eq!(rust_to_typescript(&ty, &ctx), "string");
+    }
+
     #[test]
     fn test_complex_nested_type() {
         let ctx = ctx_with_type("User");
-        let ty = RustType::Result(Box::new(RustType::Vec(Box::new(RustType::Option(
-            Box::new(RustType::custom("User")),
-        )))));
+        let ty = RustType::Result {
+            ok: Box::new(RustType::Vec(Box::new(RustType::Option(Box::new(
+                RustType::custom("User"),
+            ))))),
+            err: None,
+        };
         assert_eq!(rust_to_typescript(&ty, &ctx), "(User | null)[]");
     }
 }
@@ -1,8 +1,22 @@
+use std::collections::BTreeSet;
+
+use crate::config::{FlattenStyle, OptionalFieldStyle, UnitEnumStyle};
 use crate::models::{
-    EnumRepresentation, RustEnum, RustStruct, RustTypeAlias, StructShape, VariantData,
+    EnumRepresentation, RustEnum, RustStruct, RustType, RustTypeAlias, StructField, StructShape,
+    TauriCommand, VariantData,
 };
 
-use super::{type_mapper::rust_to_typescript, GeneratorContext};
+use crate::utils::to_pascal_case;
+
+use super::{
+    jsdoc::render_jsdoc,
+    sorted_by_name,
+    type_mapper::{
+        branded_numeric_alias, declared_type_to_typescript, member_type_to_typescript,
+        rust_to_typescript, tuple_to_typescript,
+    },
+    GeneratorContext,
+};
 
 /// Generate TypeScript types file content
 pub fn generate_types_file(
@@ -11,26 +25,38 @@ pub fn generate_types_file(
     aliases: &[RustTypeAlias],
     ctx: &GeneratorContext,
 ) -> String {
-    let mut output = String::new();
+    let mut output = ctx.file_header();
+    output.push_str(&generate_type_declarations(structs, enums, aliases, ctx));
+    output
+}
 
-    // Add header comment
-    output.push_str("// This file was auto-generated by tauri-ts-generator\n");
-    output.push_str("// Do not edit this file manually\n\n");
+/// The declarations of the types file without its header, for embedding
+/// in a combined bindings file.
+pub fn generate_type_declarations(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    aliases: &[RustTypeAlias],
+    ctx: &GeneratorContext,
+) -> String {
+    let mut output = String::new();
 
     // Generate interfaces for structs
-    for s in structs {
-        output.push_str(&generate_interface(s, ctx));
+    for s in sorted_by_name(structs, |s| &s.name) {
+        output.push_str(&render_jsdoc(ctx.doc_comment(&s.docs), &[], ""));
+        output.push_str(&generate_interface(s, structs, ctx));
+        output.push_str(&generate_derived_types(s, ctx));
         output.push('\n');
     }
 
     // Generate types for enums
-    for e in enums {
+    for e in sorted_by_name(enums, |e| &e.name) {
+        output.push_str(&render_jsdoc(ctx.doc_comment(&e.docs), &[], ""));
         output.push_str(&generate_enum_type(e, ctx));
         output.push('\n');
     }
 
     // Generate type aliases
-    for alias in aliases {
+    for alias in sorted_by_name(aliases, |a| &a.name) {
         output.push_str(&generate_alias_type(alias, ctx));
         output.push('\n');
     }
@@ -38,32 +64,122 @@ pub fn generate_types_file(
     output
 }
 
-/// Render a single struct field to TypeScript format (name, optional marker, type)
-fn render_field(
-    field: &crate::models::StructField,
+/// `export namespace <namespace> { ... }` around `declarations`, indented
+/// one level.
+pub fn wrap_in_namespace(declarations: &str, namespace: &str, ctx: &GeneratorContext) -> String {
+    let indent = ctx.indent(1);
+    let mut output = format!("export namespace {} {{\n", namespace);
+    for line in declarations.trim_end().lines() {
+        if !line.is_empty() {
+            output.push_str(&indent);
+            output.push_str(line);
+        }
+        output.push('\n');
+    }
+    output.push_str("}\n\n");
+    output
+}
+
+/// Emit `export type I32 = number;` for every numeric primitive referenced
+/// anywhere in the generated output. Only meaningful with
+/// `numeric_branding`; returns an empty string otherwise.
+///
+/// Commands are walked too: a `fn get(id: u64)` references `U64` from
+/// commands.ts even when no struct does.
+pub fn generate_numeric_aliases(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    aliases: &[RustTypeAlias],
+    commands: &[TauriCommand],
     ctx: &GeneratorContext,
-) -> (String, &'static str, String) {
-    // If use_optional is true and type is Option<T>, generate field?: T instead of field: T | null
-    let (optional_marker, ts_type) = if field.use_optional {
-        if let crate::models::RustType::Option(inner) = &field.ty {
-            ("?", rust_to_typescript(inner, ctx))
-        } else {
-            ("", rust_to_typescript(&field.ty, ctx))
+) -> String {
+    let mut roots: Vec<&RustType> = Vec::new();
+    roots.extend(structs.iter().flat_map(|s| s.fields.iter().map(|f| &f.ty)));
+    for e in enums {
+        for variant in &e.variants {
+            match &variant.data {
+                VariantData::Unit => {}
+                VariantData::Tuple(types) => roots.extend(types.iter()),
+                VariantData::Struct(fields) => roots.extend(fields.iter().map(|f| &f.ty)),
+            }
         }
-    } else {
-        ("", rust_to_typescript(&field.ty, ctx))
-    };
+    }
+    roots.extend(aliases.iter().map(|a| &a.target));
+    for cmd in commands {
+        roots.extend(cmd.args.iter().map(|a| &a.ty));
+        roots.extend(cmd.return_type.iter());
+    }
+
+    let mut used: BTreeSet<String> = BTreeSet::new();
+    for ty in roots {
+        crate::models::walk_types(ty, &mut |node| {
+            if let RustType::Primitive(name) = node {
+                if let Some(alias) = branded_numeric_alias(name, ctx) {
+                    used.insert(alias);
+                }
+            }
+        });
+    }
+
+    let mut output = String::new();
+    for alias in &used {
+        output.push_str(&format!(
+            "export type {} = number;\n",
+            ctx.format_type_name(alias)
+        ));
+    }
+    if !used.is_empty() {
+        output.push('\n');
+    }
+    output
+}
+
+/// Render a single struct field as an object-type member,
+/// `[readonly ]name[?]: type`. The one place member modifiers are composed:
+/// `readonly` precedes the key, `?` follows it and `| null` stays in the type.
+/// An optional member's type gets no `| undefined` unless `optional_fields`
+/// asks for it, so the output holds under `exactOptionalPropertyTypes`.
+fn render_member(field: &StructField, ctx: &GeneratorContext) -> String {
+    let (optional_marker, ts_type) = member_type(field, ctx);
 
     // Field name already reflects serde configuration from parser
     // (rename, rename_all, or original name if no serde attrs)
-    let field_name = field.name.clone();
+    let field_name = ctx.property_key(&field.name);
+
+    let readonly = if ctx.generation().readonly_members() {
+        "readonly "
+    } else {
+        ""
+    };
 
-    (field_name, optional_marker, ts_type)
+    format!("{}{}{}: {}", readonly, field_name, optional_marker, ts_type)
+}
+
+/// The `?` marker (or nothing) and the type of a member
+fn member_type(field: &StructField, ctx: &GeneratorContext) -> (&'static str, String) {
+    // If use_optional is true and type is Option<T>, generate field?: T instead of field: T | null,
+    // unless the field keeps its null (`#[serde(default)]`): field?: T | null.
+    // A non-Option optional field (`#[serde(skip_serializing)]`, `#[serde(default)]`) is just `field?: T`.
+    if field.use_optional {
+        let present = match &field.ty {
+            crate::models::RustType::Option(inner) if !field.keeps_null => inner,
+            ty => ty,
+        };
+        let ts_type = member_type_to_typescript(present, ctx);
+        match ctx.generation().optional_fields {
+            OptionalFieldStyle::Exact => ("?", ts_type),
+            OptionalFieldStyle::Undefined => ("?", format!("{} | undefined", ts_type)),
+        }
+    } else {
+        ("", member_type_to_typescript(&field.ty, ctx))
+    }
 }
 
 /// Generate a TypeScript interface from a Rust struct
-/// If the struct has #[serde(flatten)] fields, generate a type alias with intersection
-fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
+/// If the struct has #[serde(flatten)] fields, they are declared as
+/// `[generation] flatten_style` asks; `structs` are the exported structs,
+/// whose members `inline` copies in
+fn generate_interface(s: &RustStruct, structs: &[RustStruct], ctx: &GeneratorContext) -> String {
     let mut output = String::new();
 
     let interface_name = ctx.format_type_name(&s.name);
@@ -82,7 +198,7 @@ fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
             return format!("export type {}{} = null;\n", interface_name, generics_str);
         }
         StructShape::Newtype if s.fields.len() == 1 => {
-            let inner = rust_to_typescript(&s.fields[0].ty, ctx);
+            let inner = declared_type_to_typescript(&s.fields[0].ty, ctx);
             return format!(
                 "export type {}{} = {};\n",
                 interface_name, generics_str, inner
@@ -92,29 +208,25 @@ fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
             let elems: Vec<String> = s
                 .fields
                 .iter()
-                .map(|f| rust_to_typescript(&f.ty, ctx))
+                .map(|f| declared_type_to_typescript(&f.ty, ctx))
                 .collect();
             return format!(
-                "export type {}{} = [{}];\n",
+                "export type {}{} = {};\n",
                 interface_name,
                 generics_str,
-                elems.join(", ")
+                tuple_to_typescript(&elems, ctx.generation().deep_readonly)
             );
         }
         _ => {}
     }
 
-    // Separate normal fields from flattened fields
-    let (flatten_fields, normal_fields): (Vec<_>, Vec<_>) =
-        s.fields.iter().partition(|f| f.is_flatten);
-
     // `#[serde(flatten)]` requires the flattened field to serialize as a
     // map. A bare string-union enum (external-tagged unit variants like
     // `enum Role { Admin, User }`) serializes as a string, which at
     // runtime panics in serde — and in TypeScript an intersection
     // `{ … } & "Admin" | "User"` silently reduces to `never`. Warn so
     // the user notices the misuse instead of only finding out at runtime.
-    for flat in &flatten_fields {
+    for flat in s.fields.iter().filter(|f| f.is_flatten) {
         if let crate::models::RustType::Custom { name, .. } = &flat.ty {
             let simple = crate::utils::simple_name(name);
             if ctx.is_enum(simple) {
@@ -129,62 +241,221 @@ fn generate_interface(s: &RustStruct, ctx: &GeneratorContext) -> String {
         }
     }
 
-    if flatten_fields.is_empty() {
-        // No flatten fields - generate regular interface
+    let mut flat = FlattenedStruct::default();
+    flat.split(s, structs, ctx, &mut vec![s.name.as_str()]);
+
+    let indent = ctx.indent(1);
+    let mut body = String::new();
+    for field in &flat.members {
+        body.push_str(&render_jsdoc(ctx.doc_comment(&field.docs), &[], &indent));
+        body.push_str(&format!("{}{};\n", indent, render_member(field, ctx)));
+    }
+    if let Some(signature) = flat.index_signature(ctx) {
+        body.push_str(&format!("{}{};\n", indent, signature));
+    }
+
+    if flat.parts.is_empty() {
+        // No intersection - generate regular interface
         output.push_str(&format!(
             "export interface {}{} {{\n",
             interface_name, generics_str
         ));
-
-        for field in &s.fields {
-            let (field_name, optional_marker, ts_type) = render_field(field, ctx);
-            output.push_str(&format!(
-                "  {}{}: {};\n",
-                field_name, optional_marker, ts_type
-            ));
-        }
-
+        output.push_str(&body);
         output.push_str("}\n");
     } else {
         // Has flatten fields - generate type alias with intersection
+        let mut parts = Vec::new();
+        if !body.is_empty() {
+            parts.push(format!("{{\n{}}}", body));
+        }
+        parts.extend(flat.parts);
+
         output.push_str(&format!(
-            "export type {}{} = ",
-            interface_name, generics_str
+            "export type {}{} = {};\n",
+            interface_name,
+            generics_str,
+            parts.join(" & ")
         ));
+    }
 
-        // Generate inline object for normal fields
-        if normal_fields.is_empty() {
-            // No normal fields, start with first flatten type
-            let mut flatten_types: Vec<String> = flatten_fields
-                .iter()
-                .map(|f| rust_to_typescript(&f.ty, ctx))
-                .collect();
-            output.push_str(&flatten_types.remove(0));
-            for ft in flatten_types {
-                output.push_str(&format!(" & {}", ft));
+    output
+}
+
+/// A struct's fields sorted by how `flatten_style` declares them
+#[derive(Default)]
+struct FlattenedStruct<'a> {
+    /// Members of the object type, the struct's own and inlined ones
+    members: Vec<&'a StructField>,
+    /// Value types of flattened maps, declared as one index signature
+    map_values: Vec<String>,
+    /// Types the object type is intersected with
+    parts: Vec<String>,
+}
+
+impl<'a> FlattenedStruct<'a> {
+    /// Sort the fields of `s`. `inlining` holds the structs being inlined,
+    /// so a struct flattening itself is intersected instead.
+    fn split(
+        &mut self,
+        s: &'a RustStruct,
+        structs: &'a [RustStruct],
+        ctx: &GeneratorContext,
+        inlining: &mut Vec<&'a str>,
+    ) {
+        let style = ctx.generation().flatten_style;
+        for field in &s.fields {
+            if !field.is_flatten {
+                self.members.push(field);
+                continue;
             }
-        } else {
-            // Generate inline object for normal fields
-            output.push_str("{\n");
-            for field in normal_fields {
-                let (field_name, optional_marker, ts_type) = render_field(field, ctx);
-                output.push_str(&format!(
-                    "  {}{}: {};\n",
-                    field_name, optional_marker, ts_type
-                ));
+            if let RustType::HashMap { value, .. } = &field.ty {
+                if style != FlattenStyle::Intersection {
+                    self.map_values.push(rust_to_typescript(value, ctx));
+                    continue;
+                }
+            }
+            if style == FlattenStyle::Inline {
+                if let Some(inner) = inlinable_struct(&field.ty, structs, inlining) {
+                    inlining.push(&inner.name);
+                    self.split(inner, structs, ctx, inlining);
+                    inlining.pop();
+                    continue;
+                }
+            }
+            match flatten_part(field, ctx) {
+                Some(part) => self.parts.push(part),
+                None => {
+                    crate::diagnostics::warn(format!(
+                        "#[serde(flatten)] on field '{}' of struct '{}': cannot inline '{}', \
+                        emitting it as a nested property instead.",
+                        field.name,
+                        s.name,
+                        rust_to_typescript(&field.ty, ctx)
+                    ));
+                    self.members.push(field);
+                }
             }
-            output.push('}');
+        }
+    }
 
-            // Add intersection with flatten types
-            for field in flatten_fields {
-                let ts_type = rust_to_typescript(&field.ty, ctx);
-                output.push_str(&format!(" & {}", ts_type));
+    /// `[key: string]: V` for the flattened maps. TypeScript checks every
+    /// member against the signature, so their types join the union.
+    fn index_signature(&self, ctx: &GeneratorContext) -> Option<String> {
+        if self.map_values.is_empty() {
+            return None;
+        }
+        let mut types: Vec<String> = Vec::new();
+        let mut add = |ty: String| {
+            if !types.contains(&ty) {
+                types.push(ty);
+            }
+        };
+        for value in &self.map_values {
+            add(value.clone());
+        }
+        for field in &self.members {
+            let (optional_marker, ts_type) = member_type(field, ctx);
+            add(ts_type);
+            if !optional_marker.is_empty() {
+                add("undefined".to_string());
             }
         }
+        let readonly = if ctx.generation().readonly_members() {
+            "readonly "
+        } else {
+            ""
+        };
+        Some(format!("{}[key: string]: {}", readonly, types.join(" | ")))
+    }
+}
+
+/// The exported, non-generic struct with named fields `ty` refers to,
+/// unless it is already being inlined
+fn inlinable_struct<'a>(
+    ty: &RustType,
+    structs: &'a [RustStruct],
+    inlining: &[&str],
+) -> Option<&'a RustStruct> {
+    let RustType::Custom { name, .. } = ty else {
+        return None;
+    };
+    let name = crate::utils::simple_name(name);
+    structs.iter().find(|s| {
+        s.name == name
+            && s.shape == StructShape::Named
+            && s.generics.is_empty()
+            && !inlining.contains(&s.name.as_str())
+    })
+}
+
+/// The intersection part a `#[serde(flatten)]` field contributes: the
+/// struct itself for an exported type or generic parameter, or an index
+/// signature for a map, whose entries land directly in the parent object.
+/// `None` when the target has no object shape we can name.
+fn flatten_part(field: &StructField, ctx: &GeneratorContext) -> Option<String> {
+    match &field.ty {
+        RustType::Custom { name, .. } if ctx.is_custom_type(crate::utils::simple_name(name)) => {
+            Some(rust_to_typescript(&field.ty, ctx))
+        }
+        RustType::Generic(_) => Some(rust_to_typescript(&field.ty, ctx)),
+        RustType::HashMap { value, .. } => Some(format!(
+            "{{ [key: string]: {} }}",
+            rust_to_typescript(value, ctx)
+        )),
+        _ => None,
+    }
+}
 
-        output.push_str(";\n");
+/// Emit the `#[ts(update)]` / `#[ts(create(...))]` DTO aliases for a struct:
+///
+/// ```ts
+/// export type UserUpdate = Partial<User>;
+/// export type UserCreate = Omit<User, "id" | "created_at">;
+/// ```
+fn generate_derived_types(s: &RustStruct, ctx: &GeneratorContext) -> String {
+    let derived = &s.derived;
+    if !derived.update && derived.create.is_none() {
+        return String::new();
+    }
+    if s.shape != StructShape::Named {
+        crate::diagnostics::warn(format!(
+            "#[ts(update)] / #[ts(create)] on '{}' ignored: only structs with named \
+            fields serialize as objects",
+            s.name
+        ));
+        return String::new();
     }
 
+    let generics_str = if s.generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", s.generics.join(", "))
+    };
+    let base = format!("{}{}", ctx.format_type_name(&s.name), generics_str);
+
+    let mut output = String::new();
+    if derived.update {
+        output.push_str(&format!(
+            "export type {}{} = Partial<{}>;\n",
+            ctx.format_type_name(&format!("{}Update", s.name)),
+            generics_str,
+            base
+        ));
+    }
+    if let Some(omitted) = &derived.create {
+        let target = if omitted.is_empty() {
+            base
+        } else {
+            let keys: Vec<String> = omitted.iter().map(|k| ctx.string_literal(k)).collect();
+            format!("Omit<{}, {}>", base, keys.join(" | "))
+        };
+        output.push_str(&format!(
+            "export type {}{} = {};\n",
+            ctx.format_type_name(&format!("{}Create", s.name)),
+            generics_str,
+            target
+        ));
+    }
     output
 }
 
@@ -201,6 +472,27 @@ fn generate_enum_type(e: &RustEnum, ctx: &GeneratorContext) -> String {
         format!("<{}>", e.generics.join(", "))
     };
 
+    // `enum Wrapper { Only(Data) }` → `type Wrapper = Data`. This drops
+    // the tag serde still writes for tagged enums, hence opt-in.
+    if ctx.generation().collapse_single_variant_enums {
+        if let [only] = e.variants.as_slice() {
+            if !matches!(only.data, VariantData::Unit) {
+                return format!(
+                    "export type {}{} = {};\n",
+                    type_name,
+                    generics_str,
+                    generate_variant(only, &EnumRepresentation::Untagged, ctx)
+                );
+            }
+        }
+    }
+
+    if ctx.generation().unit_enum_style == UnitEnumStyle::Enum {
+        if let Some(declaration) = generate_ts_enum(e, &type_name, ctx) {
+            return declaration;
+        }
+    }
+
     let variants: Vec<String> = e
         .variants
         .iter()
@@ -213,14 +505,109 @@ fn generate_enum_type(e: &RustEnum, ctx: &GeneratorContext) -> String {
             type_name, generics_str
         ));
     } else {
+        let separator = format!("\n{}| ", ctx.indent(1));
         output.push_str(&format!(
-            "export type {}{} =\n  | {};\n",
+            "export type {}{} ={}{};\n",
             type_name,
             generics_str,
-            variants.join("\n  | ")
+            separator,
+            variants.join(&separator)
         ));
     }
 
+    if ctx.generation().type_guards {
+        output.push_str(&generate_type_guards(e, &type_name, &generics_str, ctx));
+    }
+
+    output
+}
+
+/// `export enum Level { Low = 1, High = 10 }` for a non-generic, unit-only
+/// enum that serializes as a bare string or number. `None` when the enum
+/// doesn't fit, or a discriminant is unknown; the caller falls back to a
+/// union.
+fn generate_ts_enum(e: &RustEnum, type_name: &str, ctx: &GeneratorContext) -> Option<String> {
+    let string_valued = match e.representation {
+        EnumRepresentation::External => true,
+        EnumRepresentation::Numeric => false,
+        _ => return None,
+    };
+    if !e.generics.is_empty()
+        || e.variants.is_empty()
+        || e.variants
+            .iter()
+            .any(|v| !matches!(v.data, VariantData::Unit))
+    {
+        return None;
+    }
+
+    let mut output = format!("export enum {} {{\n", type_name);
+    for variant in &e.variants {
+        let value = if string_valued {
+            ctx.string_literal(&variant.name)
+        } else {
+            variant.discriminant?.to_string()
+        };
+        output.push_str(&format!(
+            "{}{} = {},\n",
+            ctx.indent(1),
+            ctx.property_key(&variant.name),
+            value
+        ));
+    }
+    output.push_str("}\n");
+    Some(output)
+}
+
+/// Emit one narrowing guard per variant of a tagged enum:
+///
+/// ```ts
+/// export function isUserRoleAdmin(value: UserRole): value is Extract<UserRole, { type: "Admin" }> {
+///   return value.type === "Admin";
+/// }
+/// ```
+///
+/// The enum name is part of the guard name so two enums sharing a variant
+/// name don't collide. Externally tagged and untagged enums have no common
+/// discriminant property and get no guards.
+fn generate_type_guards(
+    e: &RustEnum,
+    type_name: &str,
+    generics_str: &str,
+    ctx: &GeneratorContext,
+) -> String {
+    let tag = match &e.representation {
+        EnumRepresentation::Internal { tag } | EnumRepresentation::Adjacent { tag, .. } => tag,
+        EnumRepresentation::External
+        | EnumRepresentation::Untagged
+        | EnumRepresentation::Numeric => return String::new(),
+    };
+
+    let key = ctx.property_key(tag);
+    let access = if key != *tag {
+        format!("value[{}]", key)
+    } else {
+        format!("value.{}", key)
+    };
+
+    let mut output = String::new();
+    for variant in &e.variants {
+        let guard_name = format!("is{}{}", e.name, to_pascal_case(&variant.name));
+        let full_type = format!("{}{}", type_name, generics_str);
+        let literal = ctx.string_literal(&variant.name);
+        output.push_str(&format!(
+            "\nexport function {}{}(value: {}): value is Extract<{}, {{ {}: {} }}> {{\n{}return {} === {};\n}}\n",
+            guard_name,
+            generics_str,
+            full_type,
+            full_type,
+            key,
+            literal,
+            ctx.indent(1),
+            access,
+            literal
+        ));
+    }
     output
 }
 
@@ -230,30 +617,40 @@ fn generate_variant(
     ctx: &GeneratorContext,
 ) -> String {
     match representation {
+        EnumRepresentation::Numeric => variant
+            .discriminant
+            .map_or_else(|| "number".to_string(), |value| value.to_string()),
         EnumRepresentation::External => match &variant.data {
-            VariantData::Unit => format!("\"{}\"", variant.name),
+            VariantData::Unit => ctx.string_literal(&variant.name),
             VariantData::Tuple(types) => {
-                let ts_types: Vec<_> = types.iter().map(|t| rust_to_typescript(t, ctx)).collect();
+                let ts_types: Vec<_> = types
+                    .iter()
+                    .map(|t| declared_type_to_typescript(t, ctx))
+                    .collect();
                 let inner = if ts_types.len() == 1 {
                     ts_types[0].clone()
                 } else {
-                    format!("[{}]", ts_types.join(", "))
+                    tuple_to_typescript(&ts_types, ctx.generation().deep_readonly)
                 };
-                format!("{{ {}: {} }}", variant.name, inner)
+                format!("{{ {}: {} }}", ctx.property_key(&variant.name), inner)
             }
             VariantData::Struct(fields) => {
                 let inner = generate_struct_body(fields, ctx);
-                format!("{{ {}: {} }}", variant.name, inner)
+                format!("{{ {}: {} }}", ctx.property_key(&variant.name), inner)
             }
         },
         EnumRepresentation::Internal { tag } => {
+            let tag = ctx.property_key(tag);
             match &variant.data {
-                VariantData::Unit => format!("{{ {}: \"{}\" }}", tag, variant.name),
+                VariantData::Unit => {
+                    format!("{{ {}: {} }}", tag, ctx.string_literal(&variant.name))
+                }
                 VariantData::Struct(fields) => {
-                    let mut body = generate_struct_body(fields, ctx);
-                    // Remove opening brace and insert tag
-                    body.remove(0); // remove '{'
-                    format!("{{ {}: \"{}\",{}", tag, variant.name, body)
+                    // The tag sits alongside the variant's own fields.
+                    let mut members =
+                        vec![format!("{}: {}", tag, ctx.string_literal(&variant.name))];
+                    members.extend(struct_members(fields, ctx));
+                    format!("{{ {} }}", members.join("; "))
                 }
                 VariantData::Tuple(types) => {
                     // Serde's internal tagging doesn't support tuple variants (it errors at runtime).
@@ -266,9 +663,14 @@ fn generate_variant(
                             correctly with serde. Consider using adjacent tagging instead.",
                             variant.name
                         ));
-                        let inner_ts = rust_to_typescript(&types[0], ctx);
+                        let inner_ts = declared_type_to_typescript(&types[0], ctx);
                         // Generate: { tag: "Name" } & InnerType
-                        format!("({{ {}: \"{}\" }} & {})", tag, variant.name, inner_ts)
+                        format!(
+                            "({{ {}: {} }} & {})",
+                            tag,
+                            ctx.string_literal(&variant.name),
+                            inner_ts
+                        )
                     } else {
                         crate::diagnostics::warn(format!(
                             "Internal tagging does not support tuple variants with \
@@ -277,41 +679,59 @@ fn generate_variant(
                             variant.name
                         ));
                         // Fall back to just the tag since this won't work in serde anyway
-                        format!("{{ {}: \"{}\" }}", tag, variant.name)
+                        format!("{{ {}: {} }}", tag, ctx.string_literal(&variant.name))
                     }
                 }
             }
         }
-        EnumRepresentation::Adjacent { tag, content } => match &variant.data {
-            VariantData::Unit => format!("{{ {}: \"{}\" }}", tag, variant.name),
-            VariantData::Tuple(types) => {
-                let ts_types: Vec<_> = types.iter().map(|t| rust_to_typescript(t, ctx)).collect();
-                let inner = if ts_types.len() == 1 {
-                    ts_types[0].clone()
-                } else {
-                    format!("[{}]", ts_types.join(", "))
-                };
-                format!(
-                    "{{ {}: \"{}\"; {}: {} }}",
-                    tag, variant.name, content, inner
-                )
-            }
-            VariantData::Struct(fields) => {
-                let inner = generate_struct_body(fields, ctx);
-                format!(
-                    "{{ {}: \"{}\"; {}: {} }}",
-                    tag, variant.name, content, inner
-                )
+        EnumRepresentation::Adjacent { tag, content } => {
+            let (tag, content) = (ctx.property_key(tag), ctx.property_key(content));
+            match &variant.data {
+                VariantData::Unit => {
+                    format!("{{ {}: {} }}", tag, ctx.string_literal(&variant.name))
+                }
+                VariantData::Tuple(types) => {
+                    let ts_types: Vec<_> = types
+                        .iter()
+                        .map(|t| declared_type_to_typescript(t, ctx))
+                        .collect();
+                    let inner = if ts_types.len() == 1 {
+                        ts_types[0].clone()
+                    } else {
+                        tuple_to_typescript(&ts_types, ctx.generation().deep_readonly)
+                    };
+                    format!(
+                        "{{ {}: {}; {}: {} }}",
+                        tag,
+                        ctx.string_literal(&variant.name),
+                        content,
+                        inner
+                    )
+                }
+                VariantData::Struct(fields) => {
+                    let inner = generate_struct_body(fields, ctx);
+                    format!(
+                        "{{ {}: {}; {}: {} }}",
+                        tag,
+                        ctx.string_literal(&variant.name),
+                        content,
+                        inner
+                    )
+                }
             }
-        },
+        }
         EnumRepresentation::Untagged => match &variant.data {
-            VariantData::Unit => "null".to_string(), // Untagged unit matches null? Or nothing? Serde says it errors if it can't match. Usually untagged is for matching shapes.
+            // serde writes an untagged unit variant as `null`, not its name
+            VariantData::Unit => "null".to_string(),
             VariantData::Tuple(types) => {
-                let ts_types: Vec<_> = types.iter().map(|t| rust_to_typescript(t, ctx)).collect();
+                let ts_types: Vec<_> = types
+                    .iter()
+                    .map(|t| declared_type_to_typescript(t, ctx))
+                    .collect();
                 if ts_types.len() == 1 {
                     ts_types[0].clone()
                 } else {
-                    format!("[{}]", ts_types.join(", "))
+                    tuple_to_typescript(&ts_types, ctx.generation().deep_readonly)
                 }
             }
             VariantData::Struct(fields) => generate_struct_body(fields, ctx),
@@ -329,7 +749,7 @@ fn generate_alias_type(alias: &RustTypeAlias, ctx: &GeneratorContext) -> String
         format!("<{}>", alias.generics.join(", "))
     };
 
-    let target_ts = rust_to_typescript(&alias.target, ctx);
+    let target_ts = declared_type_to_typescript(&alias.target, ctx);
 
     format!(
         "export type {}{} = {};\n",
@@ -338,14 +758,15 @@ fn generate_alias_type(alias: &RustTypeAlias, ctx: &GeneratorContext) -> String
 }
 
 fn generate_struct_body(fields: &[crate::models::StructField], ctx: &GeneratorContext) -> String {
-    let params: Vec<String> = fields
+    format!("{{ {} }}", struct_members(fields, ctx).join("; "))
+}
+
+/// Render each field as a `name?: type` member of an inline object type.
+fn struct_members(fields: &[crate::models::StructField], ctx: &GeneratorContext) -> Vec<String> {
+    fields
         .iter()
-        .map(|field| {
-            let (field_name, optional_marker, ts_type) = render_field(field, ctx);
-            format!("{}{}: {}", field_name, optional_marker, ts_type)
-        })
-        .collect();
-    format!("{{ {} }}", params.join("; "))
+        .map(|field| render_member(field, ctx))
+        .collect()
 }
 
 #[cfg(test)]
@@ -2,8 +2,10 @@
 //! file to keep implementation readable.
 
 use super::*;
-use crate::config::NamingConfig;
-use crate::models::{EnumVariant, RustType, RustTypeAlias, StructField, StructShape, VariantData};
+use crate::config::{DecimalType, FlattenStyle, GenerationConfig, NamingConfig};
+use crate::models::{
+    DerivedTypes, EnumVariant, RustType, RustTypeAlias, StructField, StructShape, VariantData,
+};
 use std::path::PathBuf;
 
 fn test_path() -> PathBuf {
@@ -23,24 +25,30 @@ fn test_generate_simple_interface() {
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: None,
             },
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "name".to_string(),
                 ty: RustType::Primitive("String".to_string()),
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export interface User"));
     assert!(output.contains("id: number"));
@@ -56,24 +64,30 @@ fn test_generate_interface_with_generics() {
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "data".to_string(),
                 ty: RustType::Generic("T".to_string()),
+                docs: None,
             },
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "count".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export interface Wrapper<T>"));
     assert!(output.contains("data: T"));
@@ -89,24 +103,30 @@ fn test_generate_interface_with_multiple_generics() {
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "key".to_string(),
                 ty: RustType::Generic("K".to_string()),
+                docs: None,
             },
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "value".to_string(),
                 ty: RustType::Generic("V".to_string()),
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export interface Pair<K, V>"));
 }
@@ -121,20 +141,24 @@ fn test_generate_simple_enum() {
                 has_explicit_rename: false,
                 name: "Active".to_string(),
                 data: VariantData::Unit,
+                discriminant: None,
             },
             EnumVariant {
                 has_explicit_rename: false,
                 name: "Inactive".to_string(),
                 data: VariantData::Unit,
+                discriminant: None,
             },
             EnumVariant {
                 has_explicit_rename: false,
                 name: "Pending".to_string(),
                 data: VariantData::Unit,
+                discriminant: None,
             },
         ],
         source_file: test_path(),
         representation: EnumRepresentation::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -156,15 +180,18 @@ fn test_generate_complex_enum_with_tuple() {
                 has_explicit_rename: false,
                 name: "Text".to_string(),
                 data: VariantData::Tuple(vec![RustType::Primitive("String".to_string())]),
+                discriminant: None,
             },
             EnumVariant {
                 has_explicit_rename: false,
                 name: "Number".to_string(),
                 data: VariantData::Tuple(vec![RustType::Primitive("i32".to_string())]),
+                discriminant: None,
             },
         ],
         source_file: test_path(),
         representation: EnumRepresentation::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -176,6 +203,39 @@ fn test_generate_complex_enum_with_tuple() {
     assert!(output.contains("Number: number"));
 }
 
+#[test]
+fn test_generate_untagged_enum_tuple_variants_as_tuples() {
+    let f32_ty = || RustType::Primitive("f32".to_string());
+    let e = RustEnum {
+        name: "Shape".to_string(),
+        generics: vec![],
+        variants: vec![
+            EnumVariant {
+                has_explicit_rename: false,
+                name: "Point".to_string(),
+                data: VariantData::Tuple(vec![f32_ty(), f32_ty()]),
+                discriminant: None,
+            },
+            EnumVariant {
+                has_explicit_rename: false,
+                name: "Radius".to_string(),
+                data: VariantData::Tuple(vec![f32_ty()]),
+                discriminant: None,
+            },
+        ],
+        source_file: test_path(),
+        representation: EnumRepresentation::Untagged,
+        docs: None,
+    };
+
+    let output = generate_enum_type(&e, &default_ctx());
+
+    // A multi-field variant serializes as a JSON array; a newtype as its payload
+    assert!(output.contains("| [number, number]"), "{output}");
+    assert!(output.contains("| number;"), "{output}");
+    assert!(!output.contains("Point"), "{output}");
+}
+
 #[test]
 fn test_generate_complex_enum_with_struct() {
     let e = RustEnum {
@@ -188,21 +248,26 @@ fn test_generate_complex_enum_with_struct() {
                 data: VariantData::Struct(vec![StructField {
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
                     name: "permissions".to_string(),
                     ty: RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+                    docs: None,
                 }]),
+                discriminant: None,
             },
             EnumVariant {
                 has_explicit_rename: false,
                 name: "User".to_string(),
                 data: VariantData::Unit,
+                discriminant: None,
             },
         ],
         source_file: test_path(),
         representation: EnumRepresentation::Internal {
             tag: "type".to_string(),
         },
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -223,24 +288,30 @@ fn test_field_names_preserved_without_serde_attrs() {
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "user_id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: None,
             },
             StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "first_name".to_string(),
                 ty: RustType::Primitive("String".to_string()),
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     // Without serde rename_all, fields keep their original snake_case names
     assert!(output.contains("user_id: number"));
@@ -255,10 +326,12 @@ fn test_generate_empty_struct() {
         fields: vec![],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export interface Empty"));
     assert!(output.contains("{\n}\n"));
@@ -299,12 +372,16 @@ fn test_generate_multiple_types() {
             fields: vec![StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "id".to_string(),
                 ty: RustType::Primitive("i32".to_string()),
+                docs: None,
             }],
             shape: StructShape::Named,
             source_file: test_path(),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
         RustStruct {
             name: "Item".to_string(),
@@ -312,12 +389,16 @@ fn test_generate_multiple_types() {
             fields: vec![StructField {
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
                 name: "name".to_string(),
                 ty: RustType::Primitive("String".to_string()),
+                docs: None,
             }],
             shape: StructShape::Named,
             source_file: test_path(),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
     ];
 
@@ -328,9 +409,11 @@ fn test_generate_multiple_types() {
             has_explicit_rename: false,
             name: "Active".to_string(),
             data: VariantData::Unit,
+            discriminant: None,
         }],
         source_file: test_path(),
         representation: EnumRepresentation::default(),
+        docs: None,
     }];
 
     let ctx = default_ctx();
@@ -349,16 +432,20 @@ fn test_type_with_option_field() {
         fields: vec![StructField {
             has_explicit_rename: false,
             use_optional: false,
+            keeps_null: false,
             is_flatten: false,
             name: "email".to_string(),
             ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
+            docs: None,
         }],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("email: string | null"));
 }
@@ -371,20 +458,83 @@ fn test_type_with_vec_field() {
         fields: vec![StructField {
             has_explicit_rename: false,
             use_optional: false,
+            keeps_null: false,
             is_flatten: false,
             name: "tags".to_string(),
             ty: RustType::Vec(Box::new(RustType::Primitive("String".to_string()))),
+            docs: None,
         }],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("tags: string[]"));
 }
 
+#[test]
+fn test_socket_addr_field_is_string() {
+    let s = RustStruct {
+        name: "Server".to_string(),
+        generics: vec![],
+        fields: vec![StructField {
+            has_explicit_rename: false,
+            use_optional: false,
+            keeps_null: false,
+            is_flatten: false,
+            name: "addr".to_string(),
+            ty: RustType::Primitive("SocketAddr".to_string()),
+            docs: None,
+        }],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+
+    let output = generate_interface(&s, &[], &default_ctx());
+
+    assert!(output.contains("addr: string;"));
+}
+
+#[test]
+fn test_decimal_type_switches_between_string_and_number() {
+    let s = RustStruct {
+        name: "Invoice".to_string(),
+        generics: vec![],
+        fields: vec![StructField {
+            has_explicit_rename: false,
+            use_optional: false,
+            keeps_null: false,
+            is_flatten: false,
+            name: "total".to_string(),
+            ty: RustType::Primitive("Decimal".to_string()),
+            docs: None,
+        }],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+
+    let output = generate_interface(&s, &[], &default_ctx());
+    assert!(output.contains("total: string;"), "got:\n{output}");
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            decimal_type: DecimalType::Number,
+            ..Default::default()
+        },
+    );
+    let output = generate_interface(&s, &[], &ctx);
+    assert!(output.contains("total: number;"), "got:\n{output}");
+}
+
 #[test]
 fn test_naming_prefix() {
     let s = RustStruct {
@@ -393,6 +543,8 @@ fn test_naming_prefix() {
         fields: vec![],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = GeneratorContext::new(NamingConfig {
@@ -400,8 +552,9 @@ fn test_naming_prefix() {
         type_suffix: "".to_string(),
         function_prefix: "".to_string(),
         function_suffix: "".to_string(),
+        type_overrides: Default::default(),
     });
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export interface IUser"));
 }
@@ -420,29 +573,37 @@ fn test_field_names_match_serde_serialization() {
                 ty: RustType::Primitive("String".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "API_KEY".to_string(), // serde(rename = "API_KEY") -> API_KEY
                 ty: RustType::Primitive("String".to_string()),
                 has_explicit_rename: true,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "camelCaseField".to_string(), // serde(rename_all = "camelCase") -> camelCaseField
                 ty: RustType::Primitive("bool".to_string()),
                 has_explicit_rename: true,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     // Without serde attrs, field keeps original name
     assert!(output.contains("user_name: string"));
@@ -463,15 +624,18 @@ fn test_enum_variant_explicit_rename_skips_camel_case() {
                 name: "Active".to_string(),
                 data: VariantData::Unit,
                 has_explicit_rename: false,
+                discriminant: None,
             },
             EnumVariant {
                 name: "INACTIVE_STATE".to_string(),
                 data: VariantData::Unit,
                 has_explicit_rename: true, // Explicitly renamed
+                discriminant: None,
             },
         ],
         source_file: test_path(),
         representation: EnumRepresentation::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -497,20 +661,26 @@ fn test_enum_struct_variant_field_names() {
                     ty: RustType::Primitive("i32".to_string()),
                     has_explicit_rename: false, // No serde rename -> keeps user_id
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
                 StructField {
                     name: "TIMESTAMP".to_string(),
                     ty: RustType::Primitive("i64".to_string()),
                     has_explicit_rename: true, // serde(rename = "TIMESTAMP") -> TIMESTAMP
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
             ]),
             has_explicit_rename: false,
+            discriminant: None,
         }],
         source_file: test_path(),
         representation: EnumRepresentation::default(), // External tagging
+        docs: None,
     };
 
     let ctx = default_ctx();
@@ -520,6 +690,91 @@ fn test_enum_struct_variant_field_names() {
     assert!(output.contains("TIMESTAMP: number")); // Explicit rename preserved
 }
 
+#[test]
+fn test_option_vec_field_nullable_array_vs_optional() {
+    // `Option<Vec<User>>` is either a nullable array (`User[] | null`) or,
+    // with `#[ts(optional)]`, an omittable array (`users?: User[]`) — never
+    // both `?` and `| null` at once: only the outer `Option` is unwrapped.
+    let field = |use_optional| StructField {
+        name: "users".to_string(),
+        ty: RustType::Option(Box::new(RustType::Vec(Box::new(RustType::custom("User"))))),
+        has_explicit_rename: false,
+        use_optional,
+        keeps_null: false,
+        is_flatten: false,
+        docs: None,
+    };
+    let s = RustStruct {
+        name: "Team".to_string(),
+        generics: vec![],
+        fields: vec![field(false)],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+    let s_optional = RustStruct {
+        fields: vec![field(true)],
+        ..s.clone()
+    };
+
+    let mut ctx = default_ctx();
+    ctx.register_type("User");
+
+    let output = generate_interface(&s, &[], &ctx);
+    assert!(
+        output.contains("  users: User[] | null;\n"),
+        "got:\n{output}"
+    );
+
+    let output = generate_interface(&s_optional, &[], &ctx);
+    assert!(output.contains("  users?: User[];\n"), "got:\n{output}");
+    assert!(!output.contains("null"), "got:\n{output}");
+}
+
+#[test]
+fn test_reserved_and_non_identifier_property_keys() {
+    let field = |name: &str| StructField {
+        name: name.to_string(),
+        ty: RustType::Primitive("bool".to_string()),
+        has_explicit_rename: false,
+        use_optional: false,
+        keeps_null: false,
+        is_flatten: false,
+        docs: None,
+    };
+    let s = RustStruct {
+        name: "Flags".to_string(),
+        generics: vec![],
+        fields: vec![field("delete"), field("dry-run")],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+
+    // Reserved words are valid bare keys; `dry-run` never is.
+    let output = generate_interface(&s, &[], &default_ctx());
+    assert!(output.contains("  delete: boolean;\n"), "got:\n{output}");
+    assert!(
+        output.contains("  \"dry-run\": boolean;\n"),
+        "got:\n{output}"
+    );
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            quote_reserved_keys: true,
+            ..Default::default()
+        },
+    );
+    let output = generate_interface(&s, &[], &ctx);
+    assert!(
+        output.contains("  \"delete\": boolean;\n"),
+        "got:\n{output}"
+    );
+}
+
 #[test]
 fn test_generate_ts_undefined_field() {
     let s = RustStruct {
@@ -531,25 +786,45 @@ fn test_generate_ts_undefined_field() {
                 ty: RustType::Option(Box::new(RustType::Primitive("f32".to_string()))),
                 has_explicit_rename: false,
                 use_optional: true,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "name".to_string(),
                 ty: RustType::Option(Box::new(RustType::Primitive("String".to_string()))),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
-    assert!(output.contains("volume?: number"));
+    assert!(output.contains("volume?: number;"));
     assert!(output.contains("name: string | null"));
+    // Compatible with exactOptionalPropertyTypes by default
+    assert!(!output.contains("undefined"), "{output}");
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            optional_fields: OptionalFieldStyle::Undefined,
+            ..Default::default()
+        },
+    );
+    let output = generate_interface(&s, &[], &ctx);
+
+    assert!(output.contains("volume?: number | undefined;"));
+    assert!(output.contains("name: string | null;"));
 }
 
 #[test]
@@ -563,23 +838,29 @@ fn test_generate_interface_with_flatten() {
                 ty: RustType::Primitive("String".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "address".to_string(),
                 ty: RustType::custom("Address"),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: true,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let mut ctx = default_ctx();
     ctx.register_type("Address");
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     // Should generate type alias with intersection
     assert!(
@@ -608,31 +889,39 @@ fn test_generate_interface_with_multiple_flatten() {
                 ty: RustType::Primitive("i32".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "address".to_string(),
                 ty: RustType::custom("Address"),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: true,
+                docs: None,
             },
             StructField {
                 name: "meta".to_string(),
                 ty: RustType::custom("Metadata"),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: true,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let mut ctx = default_ctx();
     ctx.register_type("Address");
     ctx.register_type("Metadata");
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export type User ="));
     assert!(output.contains("id: number"));
@@ -652,24 +941,30 @@ fn test_generate_interface_only_flatten() {
                 ty: RustType::custom("TypeA"),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: true,
+                docs: None,
             },
             StructField {
                 name: "b".to_string(),
                 ty: RustType::custom("TypeB"),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: true,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let mut ctx = default_ctx();
     ctx.register_type("TypeA");
     ctx.register_type("TypeB");
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(output.contains("export type Combined = TypeA & TypeB;"));
 }
@@ -685,14 +980,18 @@ fn test_generate_interface_without_flatten_remains_interface() {
             ty: RustType::Primitive("i32".to_string()),
             has_explicit_rename: false,
             use_optional: false,
+            keeps_null: false,
             is_flatten: false,
+            docs: None,
         }],
         shape: StructShape::Named,
         source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
     };
 
     let ctx = default_ctx();
-    let output = generate_interface(&s, &ctx);
+    let output = generate_interface(&s, &[], &ctx);
 
     assert!(
         output.contains("export interface Simple"),
@@ -703,3 +1002,551 @@ fn test_generate_interface_without_flatten_remains_interface() {
         "Should not be a type alias"
     );
 }
+
+#[test]
+fn test_type_guards_emitted_per_variant() {
+    let e = RustEnum {
+        name: "UserRole".to_string(),
+        generics: vec![],
+        variants: vec![
+            EnumVariant {
+                has_explicit_rename: false,
+                name: "Admin".to_string(),
+                data: VariantData::Unit,
+                discriminant: None,
+            },
+            EnumVariant {
+                has_explicit_rename: false,
+                name: "Guest".to_string(),
+                data: VariantData::Tuple(vec![RustType::Primitive("String".to_string())]),
+                discriminant: None,
+            },
+        ],
+        source_file: test_path(),
+        representation: EnumRepresentation::Adjacent {
+            tag: "type".to_string(),
+            content: "data".to_string(),
+        },
+        docs: None,
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            type_guards: true,
+            ..Default::default()
+        },
+    );
+    let output = generate_enum_type(&e, &ctx);
+
+    assert!(output.contains(
+        "export function isUserRoleAdmin(value: UserRole): value is Extract<UserRole, { type: \"Admin\" }> {\n  return value.type === \"Admin\";\n}\n"
+    ));
+    assert!(output.contains(
+        "export function isUserRoleGuest(value: UserRole): value is Extract<UserRole, { type: \"Guest\" }> {\n  return value.type === \"Guest\";\n}\n"
+    ));
+
+    // Off by default.
+    assert!(!generate_enum_type(&e, &default_ctx()).contains("export function"));
+}
+
+#[test]
+fn test_type_guards_skip_externally_tagged_enums() {
+    let e = RustEnum {
+        name: "Status".to_string(),
+        generics: vec![],
+        variants: vec![EnumVariant {
+            has_explicit_rename: false,
+            name: "Active".to_string(),
+            data: VariantData::Unit,
+            discriminant: None,
+        }],
+        source_file: test_path(),
+        representation: EnumRepresentation::External,
+        docs: None,
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            type_guards: true,
+            ..Default::default()
+        },
+    );
+    assert!(!generate_enum_type(&e, &ctx).contains("export function"));
+}
+
+#[test]
+fn test_update_and_create_types_from_markers() {
+    let s = RustStruct {
+        name: "User".to_string(),
+        generics: vec![],
+        fields: vec![
+            StructField {
+                has_explicit_rename: false,
+                use_optional: false,
+                keeps_null: false,
+                is_flatten: false,
+                name: "id".to_string(),
+                ty: RustType::Primitive("i32".to_string()),
+                docs: None,
+            },
+            StructField {
+                has_explicit_rename: false,
+                use_optional: false,
+                keeps_null: false,
+                is_flatten: false,
+                name: "name".to_string(),
+                ty: RustType::Primitive("String".to_string()),
+                docs: None,
+            },
+        ],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes {
+            update: true,
+            create: Some(vec!["id".to_string()]),
+        },
+        docs: None,
+    };
+
+    let output = generate_types_file(&[s], &[], &[], &default_ctx());
+
+    assert!(output.contains("export type UserUpdate = Partial<User>;\n"));
+    assert!(output.contains("export type UserCreate = Omit<User, \"id\">;\n"));
+}
+
+#[test]
+fn test_readonly_optional_nullable_member_syntax() {
+    let field = |name: &str, ty: RustType, use_optional: bool| StructField {
+        has_explicit_rename: false,
+        use_optional,
+        keeps_null: false,
+        is_flatten: false,
+        name: name.to_string(),
+        ty,
+        docs: None,
+    };
+    let string = || RustType::Primitive("String".to_string());
+    let s = RustStruct {
+        name: "Profile".to_string(),
+        generics: vec![],
+        fields: vec![
+            field("id", RustType::Primitive("i32".to_string()), false),
+            // `#[serde(default)] Option<Option<String>>`: may be missing,
+            // and null when present
+            field(
+                "nickname",
+                RustType::Option(Box::new(RustType::Option(Box::new(string())))),
+                true,
+            ),
+            field("bio", RustType::Option(Box::new(string())), false),
+        ],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            readonly_fields: true,
+            ..Default::default()
+        },
+    );
+    let output = generate_interface(&s, &[], &ctx);
+
+    assert_eq!(
+        output,
+        "export interface Profile {\n  readonly id: number;\n  readonly nickname?: string | null;\n  readonly bio: string | null;\n}\n"
+    );
+}
+
+#[test]
+fn test_multiline_docs_render_as_one_jsdoc_block() {
+    let s = RustStruct {
+        name: "User".to_string(),
+        generics: vec![],
+        fields: vec![StructField {
+            has_explicit_rename: false,
+            use_optional: false,
+            keeps_null: false,
+            is_flatten: false,
+            name: "id".to_string(),
+            ty: RustType::Primitive("i32".to_string()),
+            docs: Some("Primary key.\n\nNever reused.".to_string()),
+        }],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: Some("A user account.\nCreated on sign-up.".to_string()),
+    };
+    let e = RustEnum {
+        name: "Role".to_string(),
+        generics: vec![],
+        variants: vec![EnumVariant {
+            name: "Admin".to_string(),
+            data: VariantData::Unit,
+            has_explicit_rename: false,
+            discriminant: None,
+        }],
+        source_file: test_path(),
+        representation: EnumRepresentation::default(),
+        docs: Some("Access level.".to_string()),
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            doc_comments: true,
+            ..Default::default()
+        },
+    );
+    let (structs, enums) = ([s], [e]);
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
+
+    assert!(
+        output.contains(concat!(
+            "/**\n",
+            " * A user account.\n",
+            " * Created on sign-up.\n",
+            " */\n",
+            "export interface User {\n",
+            "  /**\n",
+            "   * Primary key.\n",
+            "   *\n",
+            "   * Never reused.\n",
+            "   */\n",
+            "  id: number;\n",
+            "}\n",
+        )),
+        "got:\n{output}"
+    );
+    assert!(output.contains("/**\n * Access level.\n */\nexport type Role ="));
+
+    // Off by default.
+    let plain = generate_types_file(&structs, &enums, &[], &default_ctx());
+    assert!(!plain.contains("/**"), "got:\n{plain}");
+}
+
+#[test]
+fn test_collapse_single_variant_enum_toggle() {
+    let wrapper = |data: VariantData| RustEnum {
+        name: "Wrapper".to_string(),
+        generics: vec![],
+        variants: vec![EnumVariant {
+            has_explicit_rename: false,
+            name: "Only".to_string(),
+            data,
+            discriminant: None,
+        }],
+        source_file: test_path(),
+        representation: EnumRepresentation::default(),
+        docs: None,
+    };
+    let newtype = wrapper(VariantData::Tuple(vec![RustType::custom("Data")]));
+
+    let mut ctx = default_ctx();
+    ctx.register_type("Data");
+    assert_eq!(
+        generate_enum_type(&newtype, &ctx),
+        "export type Wrapper =\n  | { Only: Data };\n"
+    );
+
+    let mut collapsing = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            collapse_single_variant_enums: true,
+            ..Default::default()
+        },
+    );
+    collapsing.register_type("Data");
+    assert_eq!(
+        generate_enum_type(&newtype, &collapsing),
+        "export type Wrapper = Data;\n"
+    );
+
+    let pair = wrapper(VariantData::Tuple(vec![
+        RustType::Primitive("String".to_string()),
+        RustType::Primitive("i32".to_string()),
+    ]));
+    assert_eq!(
+        generate_enum_type(&pair, &collapsing),
+        "export type Wrapper = [string, number];\n"
+    );
+
+    // A unit variant has no payload to collapse to.
+    assert_eq!(
+        generate_enum_type(&wrapper(VariantData::Unit), &collapsing),
+        "export type Wrapper =\n  | \"Only\";\n"
+    );
+}
+
+#[test]
+fn test_readonly_fields_cover_arrays_and_struct_variants() {
+    let field = |name: &str, ty: RustType| StructField {
+        has_explicit_rename: false,
+        use_optional: false,
+        keeps_null: false,
+        is_flatten: false,
+        name: name.to_string(),
+        ty,
+        docs: None,
+    };
+    let string = || RustType::Primitive("String".to_string());
+    let s = RustStruct {
+        name: "Post".to_string(),
+        generics: vec![],
+        fields: vec![
+            field("id", RustType::Primitive("i32".to_string())),
+            field("tags", RustType::Vec(Box::new(string()))),
+            field(
+                "grid",
+                RustType::Vec(Box::new(RustType::Vec(Box::new(string())))),
+            ),
+        ],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+    let e = RustEnum {
+        name: "Event".to_string(),
+        generics: vec![],
+        variants: vec![EnumVariant {
+            has_explicit_rename: false,
+            name: "Tagged".to_string(),
+            data: VariantData::Struct(vec![field("tags", RustType::Vec(Box::new(string())))]),
+            discriminant: None,
+        }],
+        source_file: test_path(),
+        representation: EnumRepresentation::default(),
+        docs: None,
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            readonly_fields: true,
+            ..Default::default()
+        },
+    );
+
+    let interface = generate_interface(&s, &[], &ctx);
+    assert!(
+        interface.contains("  readonly id: number;\n"),
+        "got:\n{interface}"
+    );
+    assert!(
+        interface.contains("  readonly tags: readonly string[];\n"),
+        "got:\n{interface}"
+    );
+    assert!(
+        interface.contains("  readonly grid: readonly (readonly string[])[];\n"),
+        "got:\n{interface}"
+    );
+    assert_eq!(
+        generate_enum_type(&e, &ctx),
+        "export type Event =\n  | { Tagged: { readonly tags: readonly string[] } };\n"
+    );
+}
+
+#[test]
+fn test_deep_readonly_covers_nested_arrays_maps_and_tuples() {
+    let field = |name: &str, ty: RustType| StructField {
+        has_explicit_rename: false,
+        use_optional: false,
+        keeps_null: false,
+        is_flatten: false,
+        name: name.to_string(),
+        ty,
+        docs: None,
+    };
+    let string = || RustType::Primitive("String".to_string());
+    let strings = || RustType::Vec(Box::new(string()));
+    let index = || RustType::HashMap {
+        key: Box::new(string()),
+        value: Box::new(strings()),
+    };
+    let s = RustStruct {
+        name: "Post".to_string(),
+        generics: vec![],
+        fields: vec![
+            field("grid", RustType::Vec(Box::new(strings()))),
+            field("index", index()),
+            field(
+                "pairs",
+                RustType::Vec(Box::new(RustType::Tuple(vec![string(), strings()]))),
+            ),
+        ],
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+    let e = RustEnum {
+        name: "Event".to_string(),
+        generics: vec![],
+        variants: vec![EnumVariant {
+            has_explicit_rename: false,
+            name: "Moved".to_string(),
+            data: VariantData::Tuple(vec![strings(), index()]),
+            discriminant: None,
+        }],
+        source_file: test_path(),
+        representation: EnumRepresentation::default(),
+        docs: None,
+    };
+    let alias = RustTypeAlias {
+        name: "Index".to_string(),
+        generics: vec![],
+        target: index(),
+        source_file: test_path(),
+    };
+
+    let ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            deep_readonly: true,
+            ..Default::default()
+        },
+    );
+
+    let interface = generate_interface(&s, &[], &ctx);
+    assert!(
+        interface.contains("  readonly grid: readonly (readonly string[])[];\n"),
+        "got:\n{interface}"
+    );
+    assert!(
+        interface.contains("  readonly index: Readonly<Record<string, readonly string[]>>;\n"),
+        "got:\n{interface}"
+    );
+    assert!(
+        interface
+            .contains("  readonly pairs: readonly (readonly [string, readonly string[]])[];\n"),
+        "got:\n{interface}"
+    );
+    assert_eq!(
+        generate_enum_type(&e, &ctx),
+        "export type Event =\n  | { Moved: readonly [readonly string[], Readonly<Record<string, readonly string[]>>] };\n"
+    );
+    assert_eq!(
+        generate_alias_type(&alias, &ctx),
+        "export type Index = Readonly<Record<string, readonly string[]>>;\n"
+    );
+
+    // `readonly_fields` alone stops at arrays in members
+    let shallow = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            readonly_fields: true,
+            ..Default::default()
+        },
+    );
+    let interface = generate_interface(&s, &[], &shallow);
+    assert!(
+        interface.contains("  readonly index: Record<string, readonly string[]>;\n"),
+        "got:\n{interface}"
+    );
+    assert_eq!(
+        generate_alias_type(&alias, &shallow),
+        "export type Index = Record<string, string[]>;\n"
+    );
+}
+
+fn flatten_fixture() -> Vec<RustStruct> {
+    let field = |name: &str, ty: RustType, is_flatten: bool| StructField {
+        name: name.to_string(),
+        ty,
+        has_explicit_rename: false,
+        use_optional: false,
+        keeps_null: false,
+        is_flatten,
+        docs: None,
+    };
+    let named = |name: &str, fields: Vec<StructField>| RustStruct {
+        name: name.to_string(),
+        generics: vec![],
+        fields,
+        shape: StructShape::Named,
+        source_file: test_path(),
+        derived: DerivedTypes::default(),
+        docs: None,
+    };
+    let string = || RustType::Primitive("String".to_string());
+    vec![
+        named(
+            "User",
+            vec![
+                field("id", RustType::Primitive("u32".to_string()), false),
+                field("audit", RustType::custom("Audit"), true),
+                field(
+                    "extra",
+                    RustType::HashMap {
+                        key: Box::new(string()),
+                        value: Box::new(string()),
+                    },
+                    true,
+                ),
+            ],
+        ),
+        named("Audit", vec![field("createdBy", string(), false)]),
+    ]
+}
+
+fn flatten_ctx(flatten_style: FlattenStyle) -> GeneratorContext {
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            flatten_style,
+            ..Default::default()
+        },
+    );
+    ctx.register_type("User");
+    ctx.register_type("Audit");
+    ctx
+}
+
+#[test]
+fn test_flatten_style_intersection_and_inline() {
+    let structs = flatten_fixture();
+
+    let output = generate_interface(
+        &structs[0],
+        &structs,
+        &flatten_ctx(FlattenStyle::Intersection),
+    );
+    assert_eq!(
+        output,
+        "export type User = {\n  id: number;\n} & Audit & { [key: string]: string };\n"
+    );
+
+    let output = generate_interface(&structs[0], &structs, &flatten_ctx(FlattenStyle::Inline));
+    assert_eq!(
+        output,
+        "export interface User {\n  id: number;\n  createdBy: string;\n  \
+         [key: string]: string | number;\n}\n"
+    );
+}
+
+#[test]
+fn test_flatten_style_index_signature() {
+    let structs = flatten_fixture();
+    let ctx = flatten_ctx(FlattenStyle::IndexSignature);
+
+    // The map becomes a member, the named struct stays intersected
+    let output = generate_interface(&structs[0], &structs, &ctx);
+    assert_eq!(
+        output,
+        "export type User = {\n  id: number;\n  [key: string]: string | number;\n} & Audit;\n"
+    );
+
+    // Without another flattened struct it is a plain interface
+    let mut map_only = structs[0].clone();
+    map_only.fields.remove(1);
+    assert_eq!(
+        generate_interface(&map_only, &structs, &ctx),
+        "export interface User {\n  id: number;\n  [key: string]: string | number;\n}\n"
+    );
+}
@@ -0,0 +1,450 @@
+//! Zod schemas mirroring the exported types (`output.zod_file`), for
+//! validating command results at runtime.
+//!
+//! Every struct, enum and alias becomes `export const XSchema = ...` plus
+//! `export type X = z.infer<typeof XSchema>`. Generic declarations become
+//! schema factories (`PageSchema(UserSchema)`) and have no inferred type.
+//! Every command taking arguments gets `export const GetUserArgsSchema`,
+//! which the command functions parse their arguments with before invoking.
+//! Schemas are `const`s, so they are emitted in
+//! [`dependency_order`](super::order::dependency_order); a reference that
+//! can't be declared first (recursion) is deferred with `z.lazy`.
+
+use std::collections::HashSet;
+
+use crate::models::{
+    EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustType, RustTypeAlias, StructField,
+    StructShape, TauriCommand, VariantData,
+};
+use crate::utils::simple_name;
+
+use super::commands_gen::args_schema_name;
+use super::jsdoc::render_jsdoc;
+use super::order::{dependency_order, TypeDecl};
+use super::type_mapper::{known_primitive_type, map_as_entries};
+use super::{sorted_by_name, GeneratorContext};
+
+/// Generate the contents of `zod_file`
+pub fn generate_zod_file(
+    structs: &[RustStruct],
+    enums: &[RustEnum],
+    aliases: &[RustTypeAlias],
+    commands: &[TauriCommand],
+    ctx: &GeneratorContext,
+) -> String {
+    let mut output = ctx.file_header();
+    output.push_str(&format!(
+        "import {{ z }} from {};\n",
+        ctx.string_literal("zod")
+    ));
+
+    let mut schemas = Schemas {
+        ctx,
+        declared: HashSet::new(),
+    };
+    for decl in dependency_order(structs, enums, aliases) {
+        output.push('\n');
+        output.push_str(&schemas.declaration(decl));
+    }
+    for cmd in sorted_by_name(commands, |c| &c.name) {
+        if !cmd.args.is_empty() {
+            output.push('\n');
+            output.push_str(&schemas.args_declaration(cmd));
+        }
+    }
+    output
+}
+
+/// Name of the schema const for the type `name`
+fn schema_name(name: &str, ctx: &GeneratorContext) -> String {
+    format!("{}Schema", ctx.format_type_name(name))
+}
+
+struct Schemas<'a> {
+    ctx: &'a GeneratorContext,
+    /// Simple names of the types whose schema is already declared
+    declared: HashSet<&'a str>,
+}
+
+impl<'a> Schemas<'a> {
+    fn declaration(&mut self, decl: TypeDecl<'a>) -> String {
+        let (generics, docs, schema) = match decl {
+            TypeDecl::Struct(s) => (&s.generics, &s.docs, self.struct_schema(s)),
+            TypeDecl::Enum(e) => (&e.generics, &e.docs, self.enum_schema(e)),
+            TypeDecl::Alias(a) => (&a.generics, &None, self.schema(&a.target)),
+        };
+        self.declared.insert(decl.name());
+
+        let name = schema_name(decl.name(), self.ctx);
+        let mut output = render_jsdoc(self.ctx.doc_comment(docs), &[], "");
+        if generics.is_empty() {
+            output.push_str(&format!("export const {} = {};\n", name, schema));
+            output.push_str(&format!(
+                "export type {} = z.infer<typeof {}>;\n",
+                self.ctx.format_type_name(decl.name()),
+                name
+            ));
+        } else {
+            // One schema argument per type parameter, named like it
+            let params: Vec<String> = generics
+                .iter()
+                .map(|g| format!("{} extends z.ZodTypeAny", g))
+                .collect();
+            let args: Vec<String> = generics.iter().map(|g| format!("{}: {}", g, g)).collect();
+            output.push_str(&format!(
+                "export const {} = <{}>({}) => {};\n",
+                name,
+                params.join(", "),
+                args.join(", "),
+                schema
+            ));
+        }
+        output
+    }
+
+    /// `export const GetUserArgsSchema = z.object({ ... });`: the object
+    /// `invoke` sends, keyed like the command function keys it
+    fn args_declaration(&self, cmd: &TauriCommand) -> String {
+        let members = cmd
+            .args
+            .iter()
+            .map(|arg| {
+                format!(
+                    "{}: {}",
+                    self.ctx.property_key(&cmd.arg_wire_name(arg)),
+                    self.schema(&arg.ty)
+                )
+            })
+            .collect();
+        format!(
+            "export const {} = {};\n",
+            args_schema_name(cmd, self.ctx),
+            self.object(members, &[], true)
+        )
+    }
+
+    fn struct_schema(&self, s: &RustStruct) -> String {
+        match s.shape {
+            StructShape::Unit => "z.null()".to_string(),
+            StructShape::Newtype if s.fields.len() == 1 => self.schema(&s.fields[0].ty),
+            StructShape::Tuple => self.tuple(s.fields.iter().map(|f| &f.ty)),
+            _ => self.object(Vec::new(), &s.fields, true),
+        }
+    }
+
+    /// `z.object({ ... })` of `members` (e.g. a tag) and the named fields,
+    /// one per line when `multiline`. Each `#[serde(flatten)]` field is
+    /// intersected with it (`.and(...)`).
+    fn object(&self, mut members: Vec<String>, fields: &[StructField], multiline: bool) -> String {
+        let mut flattened = Vec::new();
+        for field in fields {
+            match self.flatten_part(field) {
+                Some(part) => flattened.push(part),
+                None => members.push(format!(
+                    "{}: {}",
+                    self.ctx.property_key(&field.name),
+                    self.member(field)
+                )),
+            }
+        }
+
+        let mut object = if members.is_empty() {
+            "z.object({})".to_string()
+        } else if multiline {
+            let indent = self.ctx.indent(1);
+            let body: String = members
+                .iter()
+                .map(|m| format!("{}{},\n", indent, m))
+                .collect();
+            format!("z.object({{\n{}}})", body)
+        } else {
+            format!("z.object({{ {} }})", members.join(", "))
+        };
+        for part in flattened {
+            object.push_str(&format!(".and({})", part));
+        }
+        object
+    }
+
+    /// The schema a `#[serde(flatten)]` field is intersected with; `None`
+    /// for a regular field, or a flatten target with no object shape, which
+    /// stays a nested member as in the types file.
+    fn flatten_part(&self, field: &StructField) -> Option<String> {
+        if !field.is_flatten {
+            return None;
+        }
+        match &field.ty {
+            RustType::Custom { name, .. } if self.ctx.is_custom_type(simple_name(name)) => {
+                Some(self.schema(&field.ty))
+            }
+            RustType::Generic(_) => Some(self.schema(&field.ty)),
+            RustType::HashMap { value, .. } => {
+                Some(format!("z.record(z.string(), {})", self.schema(value)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Schema of an object member, `.optional()` when it may be missing.
+    /// Like the types file, an optional `Option<T>` drops its `null` unless
+    /// the field keeps it.
+    fn member(&self, field: &StructField) -> String {
+        if !field.use_optional {
+            return self.schema(&field.ty);
+        }
+        let present = match &field.ty {
+            RustType::Option(inner) if !field.keeps_null => inner,
+            ty => ty,
+        };
+        format!("{}.optional()", self.schema(present))
+    }
+
+    fn enum_schema(&self, e: &RustEnum) -> String {
+        let variants: Vec<String> = e
+            .variants
+            .iter()
+            .map(|variant| self.variant(variant, &e.representation))
+            .collect();
+        match variants.as_slice() {
+            [] => "z.never()".to_string(),
+            [only] => only.clone(),
+            _ => {
+                let indent = self.ctx.indent(1);
+                let body: String = variants
+                    .iter()
+                    .map(|v| format!("{}{},\n", indent, v))
+                    .collect();
+                format!("z.union([\n{}])", body)
+            }
+        }
+    }
+
+    /// Schema of one variant, following the same serde shapes as the
+    /// types file
+    fn variant(&self, variant: &EnumVariant, representation: &EnumRepresentation) -> String {
+        let name = self.literal(&variant.name);
+        match representation {
+            EnumRepresentation::Numeric => variant
+                .discriminant
+                .map_or_else(|| "z.number()".to_string(), |d| format!("z.literal({})", d)),
+            EnumRepresentation::External => match &variant.data {
+                VariantData::Unit => name,
+                data => format!(
+                    "z.object({{ {}: {} }})",
+                    self.ctx.property_key(&variant.name),
+                    self.variant_content(data)
+                ),
+            },
+            EnumRepresentation::Internal { tag } => {
+                let tag = format!("{}: {}", self.ctx.property_key(tag), name);
+                match &variant.data {
+                    VariantData::Unit => format!("z.object({{ {} }})", tag),
+                    VariantData::Struct(fields) => self.object(vec![tag], fields, false),
+                    // Only a newtype variant carries its payload; serde
+                    // rejects other tuple variants under internal tagging
+                    VariantData::Tuple(types) if types.len() == 1 => {
+                        format!("z.object({{ {} }}).and({})", tag, self.schema(&types[0]))
+                    }
+                    VariantData::Tuple(_) => format!("z.object({{ {} }})", tag),
+                }
+            }
+            EnumRepresentation::Adjacent { tag, content } => {
+                let tag = format!("{}: {}", self.ctx.property_key(tag), name);
+                match &variant.data {
+                    VariantData::Unit => format!("z.object({{ {} }})", tag),
+                    data => format!(
+                        "z.object({{ {}, {}: {} }})",
+                        tag,
+                        self.ctx.property_key(content),
+                        self.variant_content(data)
+                    ),
+                }
+            }
+            EnumRepresentation::Untagged => match &variant.data {
+                VariantData::Unit => "z.null()".to_string(),
+                data => self.variant_content(data),
+            },
+        }
+    }
+
+    /// The payload of a tuple or struct variant: the single value of a
+    /// newtype variant, an array of a tuple variant, an object of a struct
+    /// variant
+    fn variant_content(&self, data: &VariantData) -> String {
+        match data {
+            VariantData::Unit => "z.null()".to_string(),
+            VariantData::Tuple(types) if types.len() == 1 => self.schema(&types[0]),
+            VariantData::Tuple(types) => self.tuple(types.iter()),
+            VariantData::Struct(fields) => self.object(Vec::new(), fields, false),
+        }
+    }
+
+    fn tuple<'t>(&self, types: impl Iterator<Item = &'t RustType>) -> String {
+        let elements: Vec<String> = types.map(|t| self.schema(t)).collect();
+        format!("z.tuple([{}])", elements.join(", "))
+    }
+
+    fn literal(&self, value: &str) -> String {
+        format!("z.literal({})", self.ctx.string_literal(value))
+    }
+
+    /// The schema validating a value of `ty`
+    fn schema(&self, ty: &RustType) -> String {
+        match ty {
+            RustType::Primitive(name) => primitive_schema(name, self.ctx),
+            RustType::Vec(inner) => format!("z.array({})", self.schema(inner)),
+            RustType::Option(inner) => {
+                // `Option<Option<T>>` is a single null on the wire
+                let mut current: &RustType = inner;
+                while let RustType::Option(deeper) = current {
+                    current = deeper;
+                }
+                format!("{}.nullable()", self.schema(current))
+            }
+            RustType::Result { ok, .. } => self.schema(ok),
+            RustType::HashMap { key, value } if map_as_entries(key, self.ctx) => {
+                format!("z.array({})", self.tuple([&**key, &**value].into_iter()))
+            }
+            // JSON object keys are always strings, whatever the Rust key
+            RustType::HashMap { value, .. } => {
+                format!("z.record(z.string(), {})", self.schema(value))
+            }
+            RustType::Tuple(types) if types.is_empty() => "z.null()".to_string(),
+            RustType::Tuple(types) => self.tuple(types.iter()),
+            RustType::Custom { name, args } => {
+                let simple = simple_name(name);
+                if !self.ctx.is_custom_type(simple) {
+                    return "z.unknown()".to_string();
+                }
+                let mut reference = schema_name(simple, self.ctx);
+                if !args.is_empty() {
+                    let args: Vec<String> = args.iter().map(|a| self.schema(a)).collect();
+                    reference = format!("{}({})", reference, args.join(", "));
+                }
+                if self.declared.contains(simple) {
+                    reference
+                } else {
+                    format!("z.lazy(() => {})", reference)
+                }
+            }
+            RustType::Generic(name) => name.clone(),
+            RustType::Unit => "z.null()".to_string(),
+            RustType::Channel(_) | RustType::Unknown(_) => "z.unknown()".to_string(),
+        }
+    }
+}
+
+/// Schema of a Rust primitive, by the TypeScript type it maps to
+fn primitive_schema(name: &str, ctx: &GeneratorContext) -> String {
+    match known_primitive_type(name, ctx) {
+        Some("string") => "z.string()",
+        Some("number") => "z.number()",
+        Some("boolean") => "z.boolean()",
+        Some("number[]") => "z.array(z.number())",
+        _ => "z.unknown()",
+    }
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::NamingConfig;
+    use crate::generator::test_support::{field, rust_struct};
+    use crate::models::CommandArg;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_recursive_reference_is_lazy() {
+        let structs = vec![rust_struct(
+            "Node",
+            vec![field(
+                "children",
+                RustType::Vec(Box::new(RustType::custom("Node"))),
+            )],
+        )];
+        let mut ctx = GeneratorContext::new(NamingConfig::default());
+        ctx.register_type("Node");
+
+        let output = generate_zod_file(&structs, &[], &[], &[], &ctx);
+
+        assert!(
+            output.contains(
+                "export const NodeSchema = z.object({\n  children: z.array(z.lazy(() => NodeSchema)),\n});\n"
+            ),
+            "got:\n{output}"
+        );
+    }
+
+    #[test]
+    fn test_command_args_schema() {
+        let command = TauriCommand {
+            name: "rename_user".to_string(),
+            args: vec![
+                CommandArg {
+                    name: "user_id".to_string(),
+                    ty: RustType::Primitive("u32".to_string()),
+                },
+                CommandArg {
+                    name: "user".to_string(),
+                    ty: RustType::custom("User"),
+                },
+            ],
+            return_type: None,
+            source_file: PathBuf::from("test.rs"),
+            rename_all: None,
+            docs: None,
+        };
+        let mut ctx = GeneratorContext::new(NamingConfig::default());
+        ctx.register_type("User");
+
+        let output = generate_zod_file(&[rust_struct("User", vec![])], &[], &[], &[command], &ctx);
+
+        assert!(
+            output.ends_with(
+                "export const RenameUserArgsSchema = z.object({\n  userId: z.number(),\n  user: UserSchema,\n});\n"
+            ),
+            "got:\n{output}"
+        );
+    }
+
+    #[test]
+    fn test_generic_struct_becomes_schema_factory() {
+        let mut page = rust_struct(
+            "Page",
+            vec![field(
+                "items",
+                RustType::Vec(Box::new(RustType::Generic("T".to_string()))),
+            )],
+        );
+        page.generics = vec!["T".to_string()];
+        let user = rust_struct("User", vec![]);
+        let aliases = vec![RustTypeAlias {
+            name: "UserPage".to_string(),
+            generics: vec![],
+            target: RustType::Custom {
+                name: "Page".to_string(),
+                args: vec![RustType::custom("User")],
+            },
+            source_file: PathBuf::from("test.rs"),
+        }];
+        let mut ctx = GeneratorContext::new(NamingConfig::default());
+        for name in ["Page", "User", "UserPage"] {
+            ctx.register_type(name);
+        }
+
+        let output = generate_zod_file(&[page, user], &[], &aliases, &[], &ctx);
+
+        assert!(
+            output.contains(
+                "export const PageSchema = <T extends z.ZodTypeAny>(T: T) => z.object({\n  items: z.array(T),\n});\n"
+            ),
+            "got:\n{output}"
+        );
+        assert!(!output.contains("typeof PageSchema"), "got:\n{output}");
+        assert!(
+            output.contains("export const UserPageSchema = PageSchema(UserSchema);\n"),
+            "got:\n{output}"
+        );
+    }
+}
@@ -15,6 +15,17 @@ pub const UNSIGNED_INTEGER_TYPES: &[&str] = &["u8", "u16", "u32", "u64", "u128",
 /// Floating point types
 pub const FLOAT_TYPES: &[&str] = &["f32", "f64"];
 
+/// `std::num::NonZeroU32` and friends: the integer they wrap, as serde
+/// writes them as plain numbers (`NonZeroU32` → `u32`)
+pub fn nonzero_integer(name: &str) -> Option<&'static str> {
+    let width = name.strip_prefix("NonZero")?;
+    SIGNED_INTEGER_TYPES
+        .iter()
+        .chain(UNSIGNED_INTEGER_TYPES)
+        .find(|int| int.eq_ignore_ascii_case(width))
+        .copied()
+}
+
 /// Boolean type
 pub const BOOL_TYPE: &str = "bool";
 
@@ -42,19 +53,47 @@ pub const EXTERNAL_STRING_TYPES: &[&str] = &[
     "IpAddr",
     "Ipv4Addr",
     "Ipv6Addr",
+    "SocketAddr",
+    "SocketAddrV4",
+    "SocketAddrV6",
     // url
     "Url",
 ];
 
+/// Date-time types whose wire format can be switched to a Unix timestamp
+/// through `#[serde(with = "...")]` (`time::serde::timestamp`,
+/// `chrono::serde::ts_seconds`, ...)
+pub const DATE_TIME_TYPES: &[&str] = &[
+    "DateTime",
+    "NaiveDateTime",
+    "OffsetDateTime",
+    "PrimitiveDateTime",
+    "UtcDateTime",
+];
+
+/// Arbitrary-precision decimals (`rust_decimal`, `bigdecimal`), strings
+/// by default; `[generation] decimal_type` can switch them to numbers
+pub const DECIMAL_TYPES: &[&str] = &["Decimal", "BigDecimal"];
+
 /// Types that serialize to numbers
 pub const EXTERNAL_NUMBER_TYPES: &[&str] = &["Duration"];
 
 /// serde_json::Value - any JSON value
 pub const JSON_VALUE_TYPE: &str = "Value";
 
+/// `serde_json::value::RawValue`, an unparsed JSON fragment (usually
+/// behind a `Box`)
+pub const JSON_RAW_VALUE_TYPE: &str = "RawValue";
+
+/// `serde_json::Map<String, Value>`, a JSON object
+pub const JSON_MAP_TYPE: &str = "Map";
+
 /// Bytes type
 pub const BYTES_TYPE: &str = "Bytes";
 
+/// `std::marker::PhantomData`, a zero-sized marker carrying no data
+pub const PHANTOM_DATA_TYPE: &str = "PhantomData";
+
 /// Check if a type name is a known primitive type
 pub fn is_primitive_type(name: &str) -> bool {
     PRIMITIVE_STRING_TYPES.contains(&name)
@@ -64,6 +103,13 @@ pub fn is_primitive_type(name: &str) -> bool {
         || name == BOOL_TYPE
 }
 
+/// Check if a type name is a built-in integer or float type
+pub fn is_numeric_primitive(name: &str) -> bool {
+    SIGNED_INTEGER_TYPES.contains(&name)
+        || UNSIGNED_INTEGER_TYPES.contains(&name)
+        || FLOAT_TYPES.contains(&name)
+}
+
 /// Check if a type name is a known external type (serializes to string)
 pub fn is_external_string_type(name: &str) -> bool {
     EXTERNAL_STRING_TYPES.contains(&name)
@@ -79,10 +125,7 @@ pub fn primitive_to_typescript(name: &str) -> Option<&'static str> {
     if PRIMITIVE_STRING_TYPES.contains(&name) {
         return Some("string");
     }
-    if SIGNED_INTEGER_TYPES.contains(&name)
-        || UNSIGNED_INTEGER_TYPES.contains(&name)
-        || FLOAT_TYPES.contains(&name)
-    {
+    if is_numeric_primitive(name) {
         return Some("number");
     }
     if name == BOOL_TYPE {
@@ -94,7 +137,7 @@ pub fn primitive_to_typescript(name: &str) -> Option<&'static str> {
     if EXTERNAL_NUMBER_TYPES.contains(&name) {
         return Some("number");
     }
-    if name == JSON_VALUE_TYPE {
+    if name == JSON_VALUE_TYPE || name == JSON_RAW_VALUE_TYPE {
         return Some("unknown");
     }
     if name == BYTES_TYPE {
@@ -103,6 +146,107 @@ pub fn primitive_to_typescript(name: &str) -> Option<&'static str> {
     None
 }
 
+/// JavaScript/TypeScript reserved words (including strict-mode and
+/// module-only ones) that can't be used as a binding or function name.
+pub const TS_RESERVED_WORDS: &[&str] = &[
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Check if a name is a TypeScript reserved word
+pub fn is_ts_reserved_word(name: &str) -> bool {
+    TS_RESERVED_WORDS.contains(&name)
+}
+
+/// Browser globals a generated function would shadow in the modules that
+/// import it: `window` functions, the fetch/timer/encoding helpers and
+/// the constructors most often called without a receiver.
+pub const JS_GLOBALS: &[&str] = &[
+    "alert",
+    "atob",
+    "blur",
+    "btoa",
+    "clearInterval",
+    "clearTimeout",
+    "close",
+    "confirm",
+    "decodeURI",
+    "decodeURIComponent",
+    "encodeURI",
+    "encodeURIComponent",
+    "escape",
+    "eval",
+    "fetch",
+    "focus",
+    "isFinite",
+    "isNaN",
+    "open",
+    "parseFloat",
+    "parseInt",
+    "postMessage",
+    "print",
+    "prompt",
+    "queueMicrotask",
+    "requestAnimationFrame",
+    "scroll",
+    "scrollTo",
+    "setInterval",
+    "setTimeout",
+    "stop",
+    "structuredClone",
+    "unescape",
+];
+
+/// Check if a name is a browser global that a function of that name
+/// would shadow
+pub fn is_js_global(name: &str) -> bool {
+    JS_GLOBALS.contains(&name)
+}
+
 /// Tauri special types that are injected by the framework and not passed from frontend
 pub const TAURI_SPECIAL_TYPES: &[&str] =
     &["State", "Window", "AppHandle", "Webview", "WebviewWindow"];
@@ -127,6 +271,34 @@ mod tests {
         assert!(!is_primitive_type("DateTime"));
     }
 
+    #[test]
+    fn test_is_ts_reserved_word() {
+        assert!(is_ts_reserved_word("delete"));
+        assert!(is_ts_reserved_word("new"));
+        assert!(is_ts_reserved_word("function"));
+        assert!(!is_ts_reserved_word("type"));
+        assert!(!is_ts_reserved_word("user"));
+    }
+
+    #[test]
+    fn test_nonzero_integer() {
+        assert_eq!(nonzero_integer("NonZeroU32"), Some("u32"));
+        assert_eq!(nonzero_integer("NonZeroI64"), Some("i64"));
+        assert_eq!(nonzero_integer("NonZeroUsize"), Some("usize"));
+        assert_eq!(nonzero_integer("NonZeroF32"), None);
+        assert_eq!(nonzero_integer("NonZero"), None);
+        assert_eq!(nonzero_integer("U32"), None);
+    }
+
+    #[test]
+    fn test_is_js_global() {
+        assert!(is_js_global("fetch"));
+        assert!(is_js_global("alert"));
+        assert!(is_js_global("eval"));
+        assert!(!is_js_global("getUser"));
+        assert!(!is_js_global("Fetch"));
+    }
+
     #[test]
     fn test_is_external_string_type() {
         assert!(is_external_string_type("DateTime"));
@@ -147,6 +319,7 @@ mod tests {
         assert_eq!(primitive_to_typescript("DateTime"), Some("string"));
         assert_eq!(primitive_to_typescript("Duration"), Some("number"));
         assert_eq!(primitive_to_typescript("Value"), Some("unknown"));
+        assert_eq!(primitive_to_typescript("RawValue"), Some("unknown"));
         assert_eq!(primitive_to_typescript("Bytes"), Some("number[]"));
         assert_eq!(primitive_to_typescript("User"), None);
     }
@@ -31,6 +31,10 @@
 //!     Ok(())
 //! }
 //! ```
+//!
+//! [`Pipeline::generate_strings`](pipeline::Pipeline::generate_strings)
+//! returns the generated files instead of writing them, e.g. from a build
+//! script.
 
 pub mod cargo_expand;
 pub mod cli;
@@ -44,6 +48,7 @@ pub mod pipeline;
 pub mod resolver;
 pub mod scanner;
 pub mod utils;
+pub mod workspace;
 
-// Re-export the TS derive macro for user convenience
-pub use tauri_ts_generator_derive::TS;
+// Re-export the TS derive macro and the event marker for user convenience
+pub use tauri_ts_generator_derive::{tauri_event, TS};
@@ -1,14 +1,51 @@
 use anyhow::Result;
 use tauri_ts_generator::cli::{Cli, Commands};
 use tauri_ts_generator::config::Config;
-use tauri_ts_generator::pipeline::Pipeline;
+use tauri_ts_generator::diagnostics::AnnotationFormat;
+use tauri_ts_generator::pipeline::{OutputSelection, Pipeline};
 
 fn main() -> Result<()> {
     let cli = Cli::parse_args();
 
     match cli.command {
-        Commands::Generate { config, verbose } => {
-            run_generate(&config, verbose)?;
+        Commands::Generate {
+            config,
+            verbose,
+            types_only,
+            commands_only,
+            stdout,
+            watch,
+            since,
+            strict,
+            explain,
+            annotations,
+        } => {
+            if let Some(type_name) = explain {
+                run_explain(&config, verbose, annotations, &type_name)?;
+                return Ok(());
+            }
+            let selection = if types_only {
+                OutputSelection::TypesOnly
+            } else if commands_only {
+                OutputSelection::CommandsOnly
+            } else {
+                OutputSelection::All
+            };
+            run_generate(
+                &config,
+                verbose,
+                annotations,
+                selection,
+                strict,
+                GenerateMode::from_flags(stdout, watch, since.as_deref()),
+            )?;
+        }
+        Commands::Check {
+            config,
+            verbose,
+            annotations,
+        } => {
+            run_check(&config, verbose, annotations)?;
         }
         Commands::Init { output, force } => {
             run_init(&output, force)?;
@@ -18,16 +55,98 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// How `generate` produces its output
+enum GenerateMode<'a> {
+    /// Write the output files once
+    Write,
+    /// `--stdout`: print them instead
+    Stdout,
+    /// `--watch`
+    Watch,
+    /// `--since <REF>`
+    Since(&'a str),
+}
+
+impl<'a> GenerateMode<'a> {
+    /// The flags are mutually exclusive; clap rejects combinations
+    fn from_flags(stdout: bool, watch: bool, since: Option<&'a str>) -> Self {
+        match (stdout, watch, since) {
+            (true, _, _) => GenerateMode::Stdout,
+            (_, true, _) => GenerateMode::Watch,
+            (_, _, Some(git_ref)) => GenerateMode::Since(git_ref),
+            _ => GenerateMode::Write,
+        }
+    }
+}
+
 /// Run the generate command
-fn run_generate(config_path: &std::path::Path, verbose: bool) -> Result<()> {
+fn run_generate(
+    config_path: &std::path::Path,
+    verbose: bool,
+    annotations: AnnotationFormat,
+    selection: OutputSelection,
+    strict: bool,
+    mode: GenerateMode,
+) -> Result<()> {
+    let config = Config::load(config_path)?;
+
+    let mut pipeline = Pipeline::new(verbose)
+        .with_annotations(annotations)
+        .with_output_selection(selection)
+        .strict(strict);
+    if matches!(mode, GenerateMode::Stdout) {
+        pipeline = pipeline.with_status_on_stderr();
+        if verbose {
+            eprintln!("Loaded configuration from: {}", config_path.display());
+        }
+    } else if verbose {
+        println!("Loaded configuration from: {}", config_path.display());
+    }
+
+    match mode {
+        GenerateMode::Write => pipeline.run(&config),
+        GenerateMode::Stdout => {
+            print!("{}", pipeline.generate_strings(&config)?);
+            Ok(())
+        }
+        GenerateMode::Watch => pipeline.watch(&config),
+        GenerateMode::Since(git_ref) => pipeline.run_since(&config, git_ref),
+    }
+}
+
+/// Run the check command
+fn run_check(
+    config_path: &std::path::Path,
+    verbose: bool,
+    annotations: AnnotationFormat,
+) -> Result<()> {
+    let config = Config::load(config_path)?;
+
+    if verbose {
+        println!("Loaded configuration from: {}", config_path.display());
+    }
+
+    Pipeline::new(verbose)
+        .with_annotations(annotations)
+        .check(&config)
+}
+
+/// Run `generate --explain <type>`
+fn run_explain(
+    config_path: &std::path::Path,
+    verbose: bool,
+    annotations: AnnotationFormat,
+    type_name: &str,
+) -> Result<()> {
     let config = Config::load(config_path)?;
 
     if verbose {
         println!("Loaded configuration from: {}", config_path.display());
     }
 
-    let pipeline = Pipeline::new(verbose);
-    pipeline.run(&config)
+    Pipeline::new(verbose)
+        .with_annotations(annotations)
+        .explain(&config, type_name)
 }
 
 /// Run the init command
@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 
 use super::RustType;
+use crate::utils::to_camel_case;
 
 /// Represents a parsed Tauri command
 #[derive(Debug, Clone)]
@@ -16,6 +17,31 @@ pub struct TauriCommand {
     /// Value of rename_all attribute from #[tauri::command(rename_all = "...")]
     /// Defaults to "camelCase" behavior when None
     pub rename_all: Option<String>,
+    /// Doc comment on the function, one line per `///` line
+    pub docs: Option<String>,
+}
+
+impl TauriCommand {
+    /// Error type `E` of a `Result<T, E>` return. `None` for non-`Result`
+    /// returns and for aliases that hide the error (`anyhow::Result<T>`).
+    pub fn error_type(&self) -> Option<&RustType> {
+        match &self.return_type {
+            Some(RustType::Result { err, .. }) => err.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Key Tauri reads `arg` from in the `invoke` payload: the argument
+    /// name as written under `rename_all = "snake_case"`, camelCased
+    /// otherwise (Tauri's default). Every generated payload and payload
+    /// type goes through here.
+    pub fn arg_wire_name(&self, arg: &CommandArg) -> String {
+        if self.rename_all.as_deref() == Some("snake_case") {
+            arg.name.clone()
+        } else {
+            to_camel_case(&arg.name)
+        }
+    }
 }
 
 /// Represents a function argument
@@ -26,3 +52,43 @@ pub struct CommandArg {
     /// Argument type
     pub ty: RustType,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(rename_all: Option<&str>) -> TauriCommand {
+        TauriCommand {
+            name: "get_user".to_string(),
+            args: vec![],
+            return_type: None,
+            source_file: PathBuf::from("test.rs"),
+            rename_all: rename_all.map(str::to_string),
+            docs: None,
+        }
+    }
+
+    fn arg(name: &str) -> CommandArg {
+        CommandArg {
+            name: name.to_string(),
+            ty: RustType::Primitive("i32".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_arg_wire_name_defaults_to_camel_case() {
+        assert_eq!(command(None).arg_wire_name(&arg("user_id")), "userId");
+        assert_eq!(
+            command(Some("camelCase")).arg_wire_name(&arg("user_id")),
+            "userId"
+        );
+        assert_eq!(command(None).arg_wire_name(&arg("id")), "id");
+    }
+
+    #[test]
+    fn test_arg_wire_name_keeps_snake_case() {
+        let cmd = command(Some("snake_case"));
+        assert_eq!(cmd.arg_wire_name(&arg("user_id")), "user_id");
+        assert_eq!(cmd.arg_wire_name(&arg("id")), "id");
+    }
+}
@@ -0,0 +1,18 @@
+use std::path::PathBuf;
+
+use super::RustType;
+
+/// A payload type marked `#[tauri_event(name = "...")]`: the backend
+/// emits it under `name`, the frontend `listen`s for it
+#[derive(Debug, Clone)]
+pub struct TauriEvent {
+    /// Event name passed to `emit` and `listen`
+    pub name: String,
+    /// The marked type, or `Unit` for a unit struct (sent as `null`, so
+    /// listeners get no payload)
+    pub payload: RustType,
+    /// Source file where the marked type was found
+    pub source_file: PathBuf,
+    /// Doc comment on the marked type
+    pub docs: Option<String>,
+}
@@ -1,12 +1,14 @@
-//! Data models for representing Tauri commands and Rust types.
+//! Data models for representing Tauri commands, events and Rust types.
 
 mod command;
+mod event;
 mod rust_type;
 mod types;
 
 pub use command::{CommandArg, TauriCommand};
-pub use rust_type::{walk_custom_type_names, RustType};
+pub use event::TauriEvent;
+pub use rust_type::{walk_custom_type_names, walk_types, RustType};
 pub use types::{
-    EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustTypeAlias, StructField, StructShape,
-    VariantData,
+    DerivedTypes, EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustTypeAlias,
+    StructField, StructShape, VariantData,
 };
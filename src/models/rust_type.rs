@@ -7,8 +7,13 @@ pub enum RustType {
     Vec(Box<RustType>),
     /// `Option<T>`
     Option(Box<RustType>),
-    /// `Result<T, E>` - only Ok type is used for TypeScript generation
-    Result(Box<RustType>),
+    /// `Result<T, E>`. Only `ok` is the resolved payload; `err` is what the
+    /// invoke promise rejects with. `err` is `None` when the error type is
+    /// hidden behind a one-argument alias (`anyhow::Result<T>`).
+    Result {
+        ok: Box<RustType>,
+        err: Option<Box<RustType>>,
+    },
     /// `HashMap<K, V>`
     HashMap {
         key: Box<RustType>,
@@ -38,26 +43,41 @@ pub enum RustType {
 /// import collector (what types are referenced in a generated file?)
 /// need the same recursion; only the visitor action differs.
 pub fn walk_custom_type_names<F: FnMut(&str)>(ty: &RustType, visit: &mut F) {
-    match ty {
-        RustType::Custom { name, args } => {
+    walk_types(ty, &mut |node| {
+        if let RustType::Custom { name, .. } = node {
             visit(name);
+        }
+    });
+}
+
+/// Walk a `RustType` tree depth-first, invoking `visit` on every node
+/// (the root included). The single place that knows how each variant
+/// nests its children.
+///
+/// Only the `ok` side of a `Result` is walked — it's the payload that
+/// ends up in the generated types. Callers that care about the error
+/// side read it explicitly.
+pub fn walk_types<F: FnMut(&RustType)>(ty: &RustType, visit: &mut F) {
+    visit(ty);
+    match ty {
+        RustType::Custom { args, .. } => {
             for arg in args {
-                walk_custom_type_names(arg, visit);
+                walk_types(arg, visit);
             }
         }
         RustType::Vec(inner)
         | RustType::Option(inner)
-        | RustType::Result(inner)
+        | RustType::Result { ok: inner, .. }
         | RustType::Channel(inner) => {
-            walk_custom_type_names(inner, visit);
+            walk_types(inner, visit);
         }
         RustType::HashMap { key, value } => {
-            walk_custom_type_names(key, visit);
-            walk_custom_type_names(value, visit);
+            walk_types(key, visit);
+            walk_types(value, visit);
         }
         RustType::Tuple(types) => {
             for t in types {
-                walk_custom_type_names(t, visit);
+                walk_types(t, visit);
             }
         }
         RustType::Primitive(_) | RustType::Generic(_) | RustType::Unit | RustType::Unknown(_) => {}
@@ -113,8 +133,11 @@ mod tests {
     }
 
     #[test]
-    fn walks_into_result() {
-        let ty = RustType::Result(Box::new(RustType::custom("Response")));
+    fn walks_into_result_ok_side_only() {
+        let ty = RustType::Result {
+            ok: Box::new(RustType::custom("Response")),
+            err: Some(Box::new(RustType::custom("AppError"))),
+        };
         assert_eq!(collect(&ty), vec!["Response"]);
     }
 
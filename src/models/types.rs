@@ -16,6 +16,23 @@ pub struct RustStruct {
     pub shape: StructShape,
     /// Source file where the struct was found
     pub source_file: PathBuf,
+    /// Utility types derived from this struct via `#[ts(update)]` /
+    /// `#[ts(create(...))]`
+    pub derived: DerivedTypes,
+    /// Doc comment on the struct
+    pub docs: Option<String>,
+}
+
+/// DTO variants emitted next to a struct's interface.
+///
+/// - `update` — `#[ts(update)]` → `type UserUpdate = Partial<User>`.
+/// - `create` — `#[ts(create(omit("id")))]` →
+///   `type UserCreate = Omit<User, "id">`; holds the serialized names of
+///   the omitted fields (empty for a bare `#[ts(create)]`).
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DerivedTypes {
+    pub update: bool,
+    pub create: Option<Vec<String>>,
 }
 
 /// How serde serializes this struct.
@@ -48,9 +65,15 @@ pub struct StructField {
     /// Whether to use undefined instead of null for Option types
     /// Set via #[ts(optional)] attribute
     pub use_optional: bool,
+    /// Whether an optional `Option<T>` field keeps `| null` in its type
+    /// (`field?: T | null`). Set for `#[serde(default)]`, which lets the
+    /// field be omitted but still serializes `None` as `null`
+    pub keeps_null: bool,
     /// Whether the field is flattened via #[serde(flatten)]
     /// If true, the field's type will be intersected with the parent type in TypeScript
     pub is_flatten: bool,
+    /// Doc comment on the field
+    pub docs: Option<String>,
 }
 
 /// Represents a parsed Rust enum
@@ -66,6 +89,8 @@ pub struct RustEnum {
     pub source_file: PathBuf,
     /// Serde representation of the enum (External, Internal, Adjacent, Untagged)
     pub representation: EnumRepresentation,
+    /// Doc comment on the enum
+    pub docs: Option<String>,
 }
 
 /// Represents a parsed Rust type alias
@@ -93,6 +118,9 @@ pub enum EnumRepresentation {
     Adjacent { tag: String, content: String },
     /// #[serde(untagged)] -> { ... }
     Untagged,
+    /// `#[derive(Serialize_repr)]` on a unit-only enum -> the variant's
+    /// integer discriminant
+    Numeric,
 }
 
 /// Represents an enum variant
@@ -106,6 +134,9 @@ pub struct EnumVariant {
     /// Whether the name came from a serde `rename` or `rename_all` attribute.
     /// Informational only — `name` is always authoritative for TypeScript output.
     pub has_explicit_rename: bool,
+    /// Value the variant serializes as under [`EnumRepresentation::Numeric`];
+    /// `None` for every other representation.
+    pub discriminant: Option<i64>,
 }
 
 /// Represents the data associated with an enum variant
@@ -1,26 +1,176 @@
 use crate::models::{CommandArg, RustType, TauriCommand};
 use anyhow::Result;
+use std::collections::HashSet;
 use std::path::Path;
 use syn::{FnArg, ReturnType};
 
+use super::docs::extract_docs;
 use super::type_extractor::parse_type;
 
+/// Options for [`parse_commands_with`].
+#[derive(Debug, Clone, Default)]
+pub struct CommandParseOptions {
+    /// Also return commands defined inside `#[cfg(test)]` items
+    pub include_tests: bool,
+    /// Attribute paths accepted as command markers on top of
+    /// `tauri::command`, e.g. `tauri_plugin::command`
+    pub extra_attributes: Vec<String>,
+}
+
 /// Parse a Rust source file and extract Tauri commands.
 ///
 /// Looks for `#[tauri::command]` (or `#[command]`) on free fns, impl
-/// methods, and items inside inline `mod` blocks at any depth.
+/// methods, and items inside inline `mod` blocks at any depth. A name a
+/// module imports the macro under (`use tauri::command as cmd;`) counts
+/// too, within that module. Items gated behind `#[cfg(test)]` are skipped; see
+/// [`parse_commands_with_tests`] to keep them.
 pub fn parse_commands(content: &str, source_file: &Path) -> Result<Vec<TauriCommand>> {
+    parse_commands_with(content, source_file, &CommandParseOptions::default())
+}
+
+/// Like [`parse_commands`], but also returns commands defined inside
+/// `#[cfg(test)]` functions, impls and modules.
+pub fn parse_commands_with_tests(content: &str, source_file: &Path) -> Result<Vec<TauriCommand>> {
+    let options = CommandParseOptions {
+        include_tests: true,
+        ..Default::default()
+    };
+    parse_commands_with(content, source_file, &options)
+}
+
+/// [`parse_commands`] with explicit [`CommandParseOptions`].
+pub fn parse_commands_with(
+    content: &str,
+    source_file: &Path,
+    options: &CommandParseOptions,
+) -> Result<Vec<TauriCommand>> {
     let syntax = syn::parse_file(content)?;
+    let markers = CommandMarkers::for_file(&syntax.items, &options.extra_attributes);
+    let include_cfg_test = options.include_tests;
     let mut commands = Vec::new();
     walk_for_commands(
         &syntax.items,
-        &|_sig, attrs| attrs.iter().any(is_tauri_command_attr),
+        &|_sig, attrs, markers| {
+            attrs.iter().any(|attr| markers.matches(attr))
+                && (include_cfg_test || !is_cfg_test(attrs))
+        },
+        &|attrs| include_cfg_test || !is_cfg_test(attrs),
+        &markers,
         source_file,
         &mut commands,
     );
     Ok(commands)
 }
 
+/// The attribute paths that mark a command in one module.
+struct CommandMarkers {
+    /// `tauri::command`, bare `command` and the configured extra paths,
+    /// recognized in every module
+    base: HashSet<String>,
+    /// `base` plus the names this module's `use` items bind them to
+    paths: HashSet<String>,
+}
+
+impl CommandMarkers {
+    /// The markers at the root of a file whose items are `items`.
+    fn for_file(items: &[syn::Item], extra: &[String]) -> Self {
+        let base: HashSet<String> = ["tauri::command", "command"]
+            .into_iter()
+            .map(String::from)
+            .chain(extra.iter().map(|p| p.trim_start_matches("::").to_string()))
+            .collect();
+        let root = Self {
+            paths: base.clone(),
+            base,
+        };
+        root.for_module(items)
+    }
+
+    /// The markers inside a module nested in this one, whose items are
+    /// `items`. A `use` alias only counts in the module declaring it, as
+    /// in Rust: `use super::cmd;` and `use super::*;` bring this module's
+    /// aliases in.
+    fn for_module(&self, items: &[syn::Item]) -> Self {
+        let mut paths = self.base.clone();
+        let mut bindings = Vec::new();
+        collect_use_bindings(items, &mut bindings);
+        for (path, bound) in bindings {
+            match path.strip_prefix("super::") {
+                Some("*") => paths.extend(self.paths.iter().cloned()),
+                Some(parent) if self.paths.contains(parent) => {
+                    paths.insert(bound);
+                }
+                _ if paths.contains(&path) => {
+                    paths.insert(bound);
+                }
+                _ => {}
+            }
+        }
+        Self {
+            base: self.base.clone(),
+            paths,
+        }
+    }
+
+    fn matches(&self, attr: &syn::Attribute) -> bool {
+        let path = match &attr.meta {
+            syn::Meta::Path(path) => path,
+            syn::Meta::List(list) => &list.path,
+            _ => return false,
+        };
+        let written: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+        self.paths.contains(&written.join("::"))
+    }
+}
+
+impl Default for CommandMarkers {
+    fn default() -> Self {
+        Self::for_file(&[], &[])
+    }
+}
+
+/// Every `(full path, bound name)` pair the `use` items directly in
+/// `items` introduce: `use tauri::command as cmd;` yields
+/// `("tauri::command", "cmd")`, and a glob `use super::*;` yields
+/// `("super::*", "*")`.
+fn collect_use_bindings(items: &[syn::Item], out: &mut Vec<(String, String)>) {
+    fn walk(tree: &syn::UseTree, prefix: &mut Vec<String>, out: &mut Vec<(String, String)>) {
+        let full = |prefix: &[String], name: String| {
+            let mut segments = prefix.to_vec();
+            segments.push(name);
+            segments.join("::")
+        };
+        match tree {
+            syn::UseTree::Path(path) => {
+                prefix.push(path.ident.to_string());
+                walk(&path.tree, prefix, out);
+                prefix.pop();
+            }
+            syn::UseTree::Group(group) => {
+                for inner in &group.items {
+                    walk(inner, prefix, out);
+                }
+            }
+            syn::UseTree::Name(name) => {
+                out.push((full(prefix, name.ident.to_string()), name.ident.to_string()));
+            }
+            syn::UseTree::Rename(rename) => {
+                out.push((
+                    full(prefix, rename.ident.to_string()),
+                    rename.rename.to_string(),
+                ));
+            }
+            syn::UseTree::Glob(_) => out.push((full(prefix, "*".to_string()), "*".to_string())),
+        }
+    }
+
+    for item in items {
+        if let syn::Item::Use(use_item) = item {
+            walk(&use_item.tree, &mut Vec::new(), out);
+        }
+    }
+}
+
 /// Parse Tauri commands from `cargo expand` output.
 ///
 /// `#[tauri::command]` is a procedural macro, so by the time the source
@@ -63,7 +213,9 @@ pub fn parse_expanded_commands(content: &str, source_file: &Path) -> Result<Vec<
     let mut commands = Vec::new();
     walk_for_commands(
         &syntax.items,
-        &|sig, _attrs| command_names.contains(&sig.ident.to_string()),
+        &|sig, _attrs, _markers| command_names.contains(&sig.ident.to_string()),
+        &|_attrs| true,
+        &CommandMarkers::default(),
         source_file,
         &mut commands,
     );
@@ -78,40 +230,40 @@ pub fn parse_expanded_commands(content: &str, source_file: &Path) -> Result<Vec<
 ///
 /// The two call sites differ only in the predicate they supply
 /// (attribute check vs name-set check), so the walker stays generic
-/// over `Fn(&Signature, &[Attribute]) -> bool`.
-fn walk_for_commands<F>(
+/// over `Fn(&Signature, &[Attribute], &CommandMarkers) -> bool`.
+/// `descend` decides whether an impl block or inline module is entered
+/// at all. `markers` are the command attributes of the current module,
+/// rescoped on entering an inline one; they also locate the attribute
+/// that carries `rename_all`.
+fn walk_for_commands<F, D>(
     items: &[syn::Item],
     is_command: &F,
+    descend: &D,
+    markers: &CommandMarkers,
     source_file: &Path,
     out: &mut Vec<TauriCommand>,
 ) where
-    F: Fn(&syn::Signature, &[syn::Attribute]) -> bool,
+    F: Fn(&syn::Signature, &[syn::Attribute], &CommandMarkers) -> bool,
+    D: Fn(&[syn::Attribute]) -> bool,
 {
     for item in items {
         match item {
-            syn::Item::Fn(func) if is_command(&func.sig, &func.attrs) => {
-                out.push(parse_command_from_signature(
-                    &func.sig,
-                    &func.attrs,
-                    source_file,
-                ));
+            syn::Item::Fn(func) if is_command(&func.sig, &func.attrs, markers) => {
+                push_command(&func.sig, &func.attrs, markers, source_file, out);
             }
-            syn::Item::Impl(impl_block) => {
+            syn::Item::Impl(impl_block) if descend(&impl_block.attrs) => {
                 for impl_item in &impl_block.items {
                     if let syn::ImplItem::Fn(method) = impl_item {
-                        if is_command(&method.sig, &method.attrs) {
-                            out.push(parse_command_from_signature(
-                                &method.sig,
-                                &method.attrs,
-                                source_file,
-                            ));
+                        if is_command(&method.sig, &method.attrs, markers) {
+                            push_command(&method.sig, &method.attrs, markers, source_file, out);
                         }
                     }
                 }
             }
-            syn::Item::Mod(module) => {
+            syn::Item::Mod(module) if descend(&module.attrs) => {
                 if let Some((_, inner)) = &module.content {
-                    walk_for_commands(inner, is_command, source_file, out);
+                    let markers = markers.for_module(inner);
+                    walk_for_commands(inner, is_command, descend, &markers, source_file, out);
                 }
             }
             _ => {}
@@ -119,6 +271,52 @@ fn walk_for_commands<F>(
     }
 }
 
+fn push_command(
+    sig: &syn::Signature,
+    attrs: &[syn::Attribute],
+    markers: &CommandMarkers,
+    source_file: &Path,
+    out: &mut Vec<TauriCommand>,
+) {
+    if !is_generic_command(sig, source_file) {
+        out.push(parse_command_from_signature(
+            sig,
+            attrs,
+            markers,
+            source_file,
+        ));
+    }
+}
+
+/// Commands generic over types or consts (`fn get<T: DeserializeOwned>() -> T`)
+/// have no concrete TypeScript signature; warn and report them so the
+/// walker skips them instead of emitting an unbound `Promise<T>`.
+/// Lifetime parameters are harmless and don't count.
+fn is_generic_command(sig: &syn::Signature, source_file: &Path) -> bool {
+    let params: Vec<String> = sig
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            syn::GenericParam::Type(ty) => Some(ty.ident.to_string()),
+            syn::GenericParam::Const(c) => Some(c.ident.to_string()),
+            syn::GenericParam::Lifetime(_) => None,
+        })
+        .collect();
+    if params.is_empty() {
+        return false;
+    }
+
+    crate::diagnostics::warn(format!(
+        "Skipping generic command '{}' in {}: type parameter(s) {} have no concrete \
+        TypeScript type. Wrap it in a non-generic #[tauri::command] per instantiation.",
+        sig.ident,
+        source_file.display(),
+        params.join(", ")
+    ));
+    true
+}
+
 /// Recursively gather every `X` from `pub use __cmd__X;` (and bare
 /// `use __cmd__X;`) anywhere in the tree, descending into inline
 /// modules. Tauri's expansion of `#[tauri::command]` emits exactly one
@@ -167,24 +365,36 @@ fn walk_use_tree(tree: &syn::UseTree, names: &mut std::collections::HashSet<Stri
     }
 }
 
-/// Check if an attribute is #[tauri::command] or #[command] (with or without arguments)
-fn is_tauri_command_attr(attr: &syn::Attribute) -> bool {
-    let path = match &attr.meta {
-        syn::Meta::Path(path) => path,
-        syn::Meta::List(list) => &list.path,
-        _ => return false,
-    };
+/// True for `#[cfg(test)]` and conjunctions that require it
+/// (`#[cfg(all(test, feature = "x"))]`). `cfg(not(test))` and
+/// `cfg(any(test, ...))` are not test-only and don't count.
+pub(super) fn is_cfg_test(attrs: &[syn::Attribute]) -> bool {
+    fn requires_test(meta: &syn::Meta) -> bool {
+        match meta {
+            syn::Meta::Path(path) => path.is_ident("test"),
+            syn::Meta::List(list) if list.path.is_ident("all") => list
+                .parse_args_with(
+                    syn::punctuated::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated,
+                )
+                .map(|inner| inner.iter().any(requires_test))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
 
-    let segments: Vec<_> = path.segments.iter().map(|s| s.ident.to_string()).collect();
-    // Check for #[tauri::command] or #[command]
-    (segments.len() == 2 && segments[0] == "tauri" && segments[1] == "command")
-        || (segments.len() == 1 && segments[0] == "command")
+    attrs.iter().any(|attr| {
+        attr.path().is_ident("cfg")
+            && attr
+                .parse_args::<syn::Meta>()
+                .map(|meta| requires_test(&meta))
+                .unwrap_or(false)
+    })
 }
 
 /// Extract rename_all value from #[tauri::command(rename_all = "...")]
-fn extract_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
+fn extract_rename_all(attrs: &[syn::Attribute], markers: &CommandMarkers) -> Option<String> {
     for attr in attrs {
-        if !is_tauri_command_attr(attr) {
+        if !markers.matches(attr) {
             continue;
         }
 
@@ -212,12 +422,13 @@ fn extract_rename_all(attrs: &[syn::Attribute]) -> Option<String> {
 fn parse_command_from_signature(
     sig: &syn::Signature,
     attrs: &[syn::Attribute],
+    markers: &CommandMarkers,
     source_file: &Path,
 ) -> TauriCommand {
     let name = sig.ident.to_string();
     let args = sig.inputs.iter().filter_map(parse_fn_arg).collect();
     let return_type = parse_return_type(&sig.output);
-    let rename_all = extract_rename_all(attrs);
+    let rename_all = extract_rename_all(attrs, markers);
 
     TauriCommand {
         name,
@@ -225,6 +436,7 @@ fn parse_command_from_signature(
         return_type,
         source_file: source_file.to_path_buf(),
         rename_all,
+        docs: extract_docs(attrs),
     }
 }
 
@@ -328,7 +540,7 @@ mod tests {
         assert!(commands[0].return_type.is_some());
 
         match &commands[0].return_type {
-            Some(RustType::Result(inner)) => match inner.as_ref() {
+            Some(RustType::Result { ok: inner, .. }) => match inner.as_ref() {
                 RustType::Custom { name, .. } => assert_eq!(name, "User"),
                 other => panic!("Expected Custom, got {:?}", other),
             },
@@ -336,6 +548,28 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_command_error_type() {
+        let code = r#"
+            #[tauri::command]
+            fn read_config() -> Result<Config, std::io::Error> {
+                unimplemented!()
+            }
+
+            #[tauri::command]
+            fn plain() -> Config {
+                unimplemented!()
+            }
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert_eq!(
+            commands[0].error_type(),
+            Some(&RustType::custom("std::io::Error"))
+        );
+        assert!(commands[1].error_type().is_none());
+    }
+
     #[test]
     fn test_parse_async_command() {
         let code = r#"
@@ -508,6 +742,109 @@ mod tests {
         assert_eq!(commands[0].rename_all, Some("camelCase".to_string()));
     }
 
+    #[test]
+    fn test_parse_command_through_use_alias() {
+        let code = r#"
+            use tauri::command as cmd;
+            use tauri::{command as api};
+
+            #[cmd(rename_all = "snake_case")]
+            fn get_user(user_id: i32) {}
+
+            #[api]
+            fn list_users() {}
+
+            #[other]
+            fn helper() {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        let names: Vec<_> = commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["get_user", "list_users"]);
+        assert_eq!(commands[0].rename_all, Some("snake_case".to_string()));
+    }
+
+    #[test]
+    fn test_use_alias_is_scoped_to_its_module() {
+        let code = r#"
+            mod api {
+                use tauri::command as cmd;
+
+                #[cmd]
+                fn in_api() {}
+
+                mod nested {
+                    use super::cmd;
+
+                    #[cmd]
+                    fn imported_from_parent() {}
+                }
+
+                mod globbed {
+                    use super::*;
+
+                    #[cmd]
+                    fn glob_from_parent() {}
+                }
+            }
+
+            mod jobs {
+                use tracing::instrument as cmd;
+
+                #[cmd]
+                fn traced() {}
+            }
+
+            #[cmd]
+            fn outside() {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        let names: Vec<_> = commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["in_api", "imported_from_parent", "glob_from_parent"]
+        );
+    }
+
+    #[test]
+    fn test_alias_of_unrelated_macro_is_not_a_command() {
+        let code = r#"
+            use tracing::instrument as cmd;
+
+            #[cmd]
+            fn traced() {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert!(commands.is_empty());
+    }
+
+    #[test]
+    fn test_parse_command_with_extra_attribute_paths() {
+        let code = r#"
+            use my_macros::api_command as endpoint;
+
+            #[tauri_plugin::command]
+            fn from_plugin() {}
+
+            #[endpoint]
+            fn from_alias() {}
+        "#;
+        let options = CommandParseOptions {
+            extra_attributes: vec![
+                "tauri_plugin::command".to_string(),
+                "my_macros::api_command".to_string(),
+            ],
+            ..Default::default()
+        };
+
+        assert!(parse_commands(code, &test_path()).unwrap().is_empty());
+        let commands = parse_commands_with(code, &test_path(), &options).unwrap();
+        let names: Vec<_> = commands.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["from_plugin", "from_alias"]);
+    }
+
     #[test]
     fn test_parse_no_rename_all() {
         let code = r#"
@@ -520,6 +857,23 @@ mod tests {
         assert_eq!(commands[0].rename_all, None);
     }
 
+    #[test]
+    fn test_parse_command_docs() {
+        let code = r#"
+            /// Load a user.
+            ///
+            /// # Examples
+            #[tauri::command]
+            fn get_user(id: i32) {}
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        assert_eq!(
+            commands[0].docs.as_deref(),
+            Some("Load a user.\n\n# Examples")
+        );
+    }
+
     #[test]
     fn test_parse_command_short_form_with_rename_all() {
         let code = r#"
@@ -532,6 +886,50 @@ mod tests {
         assert_eq!(commands[0].rename_all, Some("snake_case".to_string()));
     }
 
+    #[test]
+    fn test_cfg_test_commands_excluded_by_default() {
+        let code = r#"
+            #[tauri::command]
+            fn real_command() {}
+
+            #[cfg(test)]
+            mod tests {
+                #[tauri::command]
+                fn test_only_command() {}
+            }
+
+            #[cfg(all(test, feature = "mock"))]
+            #[tauri::command]
+            fn mock_command() {}
+
+            #[cfg(not(test))]
+            #[tauri::command]
+            fn release_command() {}
+        "#;
+
+        let names: Vec<_> = parse_commands(code, &test_path())
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(names, vec!["real_command", "release_command"]);
+
+        let names: Vec<_> = parse_commands_with_tests(code, &test_path())
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name)
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "real_command",
+                "test_only_command",
+                "mock_command",
+                "release_command"
+            ]
+        );
+    }
+
     // ---- Recursion / depth coverage for `parse_commands` -----------------
     //
     // Pre-2.0.3 the walker descended only one level into `Item::Mod` and
@@ -539,6 +937,31 @@ mod tests {
     // deeper mod nesting were silently dropped. These tests pin the
     // post-refactor contract: discovery is recursive in both directions.
 
+    #[test]
+    fn parse_commands_skips_generic_commands() {
+        let code = r#"
+            #[tauri::command]
+            fn get<T: serde::de::DeserializeOwned>(key: String) -> T {
+                unimplemented!()
+            }
+
+            #[tauri::command]
+            fn first<const N: usize>() -> Vec<u8> {
+                unimplemented!()
+            }
+
+            #[tauri::command]
+            fn label<'a>(name: &'a str) -> String {
+                unimplemented!()
+            }
+        "#;
+
+        let commands = parse_commands(code, &test_path()).unwrap();
+        let names: Vec<_> = commands.iter().map(|c| c.name.as_str()).collect();
+        // Lifetime-only generics still have a concrete signature
+        assert_eq!(names, vec!["label"]);
+    }
+
     #[test]
     fn parse_commands_finds_command_in_nested_mod() {
         let code = r#"
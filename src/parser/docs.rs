@@ -0,0 +1,74 @@
+//! Doc-comment capture. `///` and `/** */` comments reach the AST as
+//! `#[doc = "..."]` attributes, one per line.
+
+use syn::{Expr, Lit, Meta};
+
+/// Join every `#[doc = "..."]` on `attrs` into one string, one line per
+/// attribute, with the single space after `///` removed. `None` when
+/// there is no doc text.
+pub fn extract_docs(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        let Meta::NameValue(nv) = &attr.meta else {
+            continue;
+        };
+        if !nv.path.is_ident("doc") {
+            continue;
+        }
+        let Expr::Lit(expr) = &nv.value else {
+            continue;
+        };
+        let Lit::Str(text) = &expr.lit else {
+            continue;
+        };
+        for line in text.value().split('\n') {
+            lines.push(
+                line.strip_prefix(' ')
+                    .unwrap_or(line)
+                    .trim_end()
+                    .to_string(),
+            );
+        }
+    }
+
+    while lines.first().is_some_and(|l| l.is_empty()) {
+        lines.remove(0);
+    }
+    while lines.last().is_some_and(|l| l.is_empty()) {
+        lines.pop();
+    }
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn docs_of(code: &str) -> Option<String> {
+        let item: syn::ItemFn = syn::parse_str(code).unwrap();
+        extract_docs(&item.attrs)
+    }
+
+    #[test]
+    fn test_extract_line_docs() {
+        let docs = docs_of("/// First line.\n///\n///   indented\nfn f() {}");
+        assert_eq!(docs.as_deref(), Some("First line.\n\n  indented"));
+    }
+
+    #[test]
+    fn test_extract_block_and_attribute_docs() {
+        let docs = docs_of("#[doc = \" Attr.\"]\n/** Block\n line */\nfn f() {}");
+        assert_eq!(docs.as_deref(), Some("Attr.\nBlock\nline"));
+    }
+
+    #[test]
+    fn test_no_docs() {
+        assert_eq!(docs_of("#[inline]\nfn f() {}"), None);
+        assert_eq!(docs_of("///\nfn f() {}"), None);
+    }
+}
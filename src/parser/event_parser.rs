@@ -0,0 +1,170 @@
+use crate::models::{RustType, TauriEvent};
+use crate::utils::to_kebab_case;
+use anyhow::Result;
+use std::path::Path;
+use syn::{Attribute, Fields, Generics, Item};
+
+use super::command_parser::is_cfg_test;
+use super::docs::extract_docs;
+
+/// Parse a Rust source file and extract the event payload types.
+///
+/// A struct or enum marked `#[tauri_event(name = "user-updated")]` (or
+/// `#[tauri_ts_generator_derive::tauri_event]`) is emitted under that
+/// name; without `name` the event is the type name in kebab-case
+/// (`UserUpdated` → `user-updated`). Types inside inline `mod` blocks are
+/// found too; `#[cfg(test)]` ones and generic ones are skipped.
+pub fn parse_events(content: &str, source_file: &Path) -> Result<Vec<TauriEvent>> {
+    let syntax = syn::parse_file(content)?;
+    let mut events = Vec::new();
+    walk_for_events(&syntax.items, source_file, &mut events);
+    Ok(events)
+}
+
+fn walk_for_events(items: &[Item], source_file: &Path, out: &mut Vec<TauriEvent>) {
+    for item in items {
+        match item {
+            Item::Struct(s) => {
+                let payload = match s.fields {
+                    Fields::Unit => RustType::Unit,
+                    _ => RustType::custom(s.ident.to_string()),
+                };
+                push_event(&s.ident, &s.attrs, &s.generics, payload, source_file, out);
+            }
+            Item::Enum(e) => {
+                let payload = RustType::custom(e.ident.to_string());
+                push_event(&e.ident, &e.attrs, &e.generics, payload, source_file, out);
+            }
+            Item::Mod(module) if !is_cfg_test(&module.attrs) => {
+                if let Some((_, inner)) = &module.content {
+                    walk_for_events(inner, source_file, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn push_event(
+    ident: &syn::Ident,
+    attrs: &[Attribute],
+    generics: &Generics,
+    payload: RustType,
+    source_file: &Path,
+    out: &mut Vec<TauriEvent>,
+) {
+    let Some(marker) = attrs.iter().find(|attr| is_event_marker(attr)) else {
+        return;
+    };
+    if is_cfg_test(attrs) {
+        return;
+    }
+    if generics.type_params().next().is_some() || generics.const_params().next().is_some() {
+        crate::diagnostics::warn(format!(
+            "Skipping generic event payload '{}' in {}: its TypeScript type is unknown",
+            ident,
+            source_file.display()
+        ));
+        return;
+    }
+    out.push(TauriEvent {
+        name: event_name(marker).unwrap_or_else(|| to_kebab_case(&ident.to_string())),
+        payload,
+        source_file: source_file.to_path_buf(),
+        docs: extract_docs(attrs),
+    });
+}
+
+/// `#[tauri_event]`, under any path (`tauri_ts_generator_derive::tauri_event`)
+fn is_event_marker(attr: &Attribute) -> bool {
+    attr.path()
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "tauri_event")
+}
+
+/// `name` of `#[tauri_event(name = "...")]`
+fn event_name(attr: &Attribute) -> Option<String> {
+    if !matches!(attr.meta, syn::Meta::List(_)) {
+        return None;
+    }
+    let mut name = None;
+    let parsed = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("name") {
+            name = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+            Ok(())
+        } else {
+            Err(meta.error("unknown #[tauri_event] option; only `name` is supported"))
+        }
+    });
+    if let Err(err) = parsed {
+        crate::diagnostics::warn(format!("Ignoring malformed #[tauri_event]: {}", err));
+    }
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn events(content: &str) -> Vec<TauriEvent> {
+        parse_events(content, &PathBuf::from("events.rs")).unwrap()
+    }
+
+    #[test]
+    fn test_parse_events() {
+        let parsed = events(
+            r#"
+            /// Sent after a profile edit
+            #[derive(Serialize)]
+            #[tauri_event(name = "user-updated")]
+            pub struct UserUpdated { pub id: u32 }
+
+            #[derive(Serialize)]
+            #[tauri_ts_generator_derive::tauri_event]
+            pub struct AppReady;
+
+            mod sync {
+                #[derive(Serialize)]
+                #[tauri_event(name = "sync:state")]
+                pub enum SyncState { Idle, Running }
+            }
+
+            #[derive(Serialize)]
+            pub struct NotAnEvent { pub id: u32 }
+
+            #[cfg(test)]
+            mod tests {
+                #[tauri_event]
+                pub struct TestOnly;
+            }
+            "#,
+        );
+
+        let summary: Vec<(&str, &RustType)> = parsed
+            .iter()
+            .map(|e| (e.name.as_str(), &e.payload))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("user-updated", &RustType::custom("UserUpdated")),
+                ("app-ready", &RustType::Unit),
+                ("sync:state", &RustType::custom("SyncState")),
+            ]
+        );
+        assert_eq!(parsed[0].docs.as_deref(), Some("Sent after a profile edit"));
+    }
+
+    #[test]
+    fn test_generic_event_payloads_are_skipped() {
+        let parsed = events(
+            r#"
+            #[tauri_event(name = "page")]
+            pub struct Page<T> { pub items: Vec<T> }
+            "#,
+        );
+        assert!(parsed.is_empty());
+    }
+}
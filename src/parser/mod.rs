@@ -1,6 +1,12 @@
 pub mod command_parser;
+pub mod docs;
+pub mod event_parser;
 pub mod type_extractor;
 pub mod type_parser;
 
-pub use command_parser::{parse_commands, parse_expanded_commands};
+pub use command_parser::{
+    parse_commands, parse_commands_with, parse_commands_with_tests, parse_expanded_commands,
+    CommandParseOptions,
+};
+pub use event_parser::parse_events;
 pub use type_parser::{parse_types, ParseOptions, ParsedTypes};
@@ -1,16 +1,23 @@
 use crate::known_types::{
-    is_external_number_type, is_external_string_type, is_primitive_type, BYTES_TYPE,
-    JSON_VALUE_TYPE,
+    is_external_number_type, is_external_string_type, is_primitive_type, nonzero_integer,
+    BYTES_TYPE, JSON_MAP_TYPE, JSON_RAW_VALUE_TYPE, JSON_VALUE_TYPE, PHANTOM_DATA_TYPE,
 };
 use crate::models::RustType;
 use std::collections::HashSet;
-use syn::{GenericArgument, PathArguments, Type};
+use syn::{GenericArgument, PathArguments, Type, TypePath};
 
 /// Parse a Rust type into our RustType representation (without generic context)
 pub fn parse_type(ty: &Type) -> RustType {
     parse_type_with_context(ty, &HashSet::new())
 }
 
+/// Whether `ty` is `PhantomData<...>`. Struct fields of this type are
+/// markers for the type checker and are left out of the generated types.
+pub fn is_phantom_data(ty: &Type) -> bool {
+    matches!(ty, Type::Path(type_path)
+        if type_path.path.segments.last().is_some_and(|s| s.ident == PHANTOM_DATA_TYPE))
+}
+
 /// Parse a Rust type with known generic parameters from the parent struct/enum
 pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> RustType {
     match ty {
@@ -34,6 +41,11 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                     return RustType::Primitive(normalized);
                 }
 
+                // `NonZeroU32` is a `u32` on the wire
+                if let Some(int) = nonzero_integer(&name) {
+                    return RustType::Primitive(int.to_string());
+                }
+
                 // Check if it's a known external type that serializes to string
                 if is_external_string_type(&name) {
                     return RustType::Primitive(name);
@@ -45,12 +57,16 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                 }
 
                 // Special types
-                if name == JSON_VALUE_TYPE {
+                if name == JSON_VALUE_TYPE || name == JSON_RAW_VALUE_TYPE {
                     return RustType::Primitive(name);
                 }
                 if name == BYTES_TYPE {
                     return RustType::Primitive(name);
                 }
+                // Serializes as `null`; never a type to collect
+                if name == PHANTOM_DATA_TYPE {
+                    return RustType::Unit;
+                }
 
                 // Generic container types
                 match name.as_str() {
@@ -61,6 +77,15 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                             RustType::Unknown("Vec<?>".to_string())
                         }
                     }
+                    // Serde serializes sets as JSON arrays. `extract_first_type_arg`
+                    // ignores a trailing hasher parameter (`HashSet<T, S>`).
+                    "HashSet" | "BTreeSet" | "IndexSet" => {
+                        if let Some(inner) = extract_first_type_arg(&segment.arguments) {
+                            RustType::Vec(Box::new(parse_type_with_context(&inner, generic_params)))
+                        } else {
+                            RustType::Unknown(format!("{}<?>", name))
+                        }
+                    }
                     "Option" => {
                         if let Some(inner) = extract_single_generic(&segment.arguments) {
                             RustType::Option(Box::new(parse_type_with_context(
@@ -72,11 +97,18 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                         }
                     }
                     "Result" => {
-                        if let Some(inner) = extract_single_generic(&segment.arguments) {
-                            RustType::Result(Box::new(parse_type_with_context(
-                                &inner,
-                                generic_params,
-                            )))
+                        // `Result<T, E>`, or a one-argument alias such as
+                        // `anyhow::Result<T>` / `io::Result<T>` that hides E.
+                        if let Some((ok, err)) = extract_two_generics(&segment.arguments) {
+                            RustType::Result {
+                                ok: Box::new(parse_type_with_context(&ok, generic_params)),
+                                err: Some(Box::new(parse_type_with_context(&err, generic_params))),
+                            }
+                        } else if let Some(ok) = extract_single_generic(&segment.arguments) {
+                            RustType::Result {
+                                ok: Box::new(parse_type_with_context(&ok, generic_params)),
+                                err: None,
+                            }
                         } else {
                             RustType::Unknown("Result<?>".to_string())
                         }
@@ -92,7 +124,9 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                         }
                     }
 
-                    "HashMap" | "BTreeMap" => {
+                    // Ordered maps (indexmap, hashlink) serialize like any
+                    // other map; a trailing hasher parameter is ignored.
+                    "HashMap" | "BTreeMap" | "IndexMap" | "LinkedHashMap" => {
                         if let Some((key, value)) = extract_two_generics(&segment.arguments) {
                             RustType::HashMap {
                                 key: Box::new(parse_type_with_context(&key, generic_params)),
@@ -103,6 +137,28 @@ pub fn parse_type_with_context(ty: &Type, generic_params: &HashSet<String>) -> R
                         }
                     }
 
+                    // `serde_json::Map<String, Value>` is a JSON object. A bare
+                    // `Map` only counts when its values are `Value`, so a
+                    // user-defined `Map<K, V>` stays a custom type.
+                    JSON_MAP_TYPE if is_json_map(type_path) => {
+                        let value = extract_two_generics(&segment.arguments)
+                            .map(|(_, value)| parse_type_with_context(&value, generic_params))
+                            .unwrap_or_else(|| RustType::Primitive(JSON_VALUE_TYPE.to_string()));
+                        RustType::HashMap {
+                            key: Box::new(RustType::Primitive("String".to_string())),
+                            value: Box::new(value),
+                        }
+                    }
+
+                    // The generic spelling, `NonZero<u32>`
+                    "NonZero" => {
+                        if let Some(inner) = extract_single_generic(&segment.arguments) {
+                            parse_type_with_context(&inner, generic_params)
+                        } else {
+                            RustType::Unknown("NonZero<?>".to_string())
+                        }
+                    }
+
                     // Transparent smart-pointer / wrapper containers: serde (and Tauri's JSON
                     // bridge) serialize the inner type unchanged, so the TypeScript output
                     // should reflect the inner type too. Cow<'a, T> is handled by skipping
@@ -201,6 +257,22 @@ fn extract_single_generic(args: &PathArguments) -> Option<Type> {
     None
 }
 
+/// Whether a path ending in `Map` is `serde_json::Map`: spelled with
+/// the crate prefix, or with `Value` values
+fn is_json_map(type_path: &TypePath) -> bool {
+    let segments = &type_path.path.segments;
+    if segments.first().is_some_and(|s| s.ident == "serde_json") {
+        return true;
+    }
+    segments
+        .last()
+        .and_then(|s| extract_two_generics(&s.arguments))
+        .is_some_and(|(_, value)| {
+            matches!(&value, Type::Path(p)
+                if p.path.segments.last().is_some_and(|s| s.ident == JSON_VALUE_TYPE))
+        })
+}
+
 /// Extract the first type argument, skipping lifetimes and const generics.
 /// Needed for wrappers like Cow<'a, T> where the first arg is a lifetime.
 fn extract_first_type_arg(args: &PathArguments) -> Option<Type> {
@@ -214,7 +286,7 @@ fn extract_first_type_arg(args: &PathArguments) -> Option<Type> {
     None
 }
 
-/// Extract two generic type arguments (for HashMap<K, V>)
+/// Extract two generic type arguments (for HashMap<K, V>, Result<T, E>)
 fn extract_two_generics(args: &PathArguments) -> Option<(Type, Type)> {
     if let PathArguments::AngleBracketed(angle) = args {
         let mut iter = angle.args.iter();
@@ -76,6 +76,21 @@ fn test_parse_vec_type() {
     }
 }
 
+#[test]
+fn test_parse_sets_as_vec() {
+    for src in [
+        "HashSet<User>",
+        "std::collections::BTreeSet<User>",
+        "IndexSet<User, RandomState>",
+    ] {
+        assert_eq!(
+            parse_type(&parse_type_str(src)),
+            RustType::Vec(Box::new(RustType::custom("User"))),
+            "{src}"
+        );
+    }
+}
+
 #[test]
 fn test_parse_vec_nested() {
     let ty = parse_type_str("Vec<Vec<i32>>");
@@ -119,14 +134,32 @@ fn test_parse_option_custom_type() {
 fn test_parse_result_type() {
     let ty = parse_type_str("Result<User, String>");
     match parse_type(&ty) {
-        RustType::Result(ok) => match *ok {
-            RustType::Custom { name, .. } => assert_eq!(name, "User"),
-            other => panic!("Expected Custom inside Result, got {:?}", other),
-        },
+        RustType::Result { ok, err } => {
+            match *ok {
+                RustType::Custom { name, .. } => assert_eq!(name, "User"),
+                other => panic!("Expected Custom inside Result, got {:?}", other),
+            }
+            assert_eq!(
+                err.as_deref(),
+                Some(&RustType::Primitive("String".to_string()))
+            );
+        }
         other => panic!("Expected Result, got {:?}", other),
     }
 }
 
+#[test]
+fn test_parse_result_alias_hides_error() {
+    let ty = parse_type_str("anyhow::Result<User>");
+    assert_eq!(
+        parse_type(&ty),
+        RustType::Result {
+            ok: Box::new(RustType::custom("User")),
+            err: None,
+        }
+    );
+}
+
 #[test]
 fn test_parse_hashmap_type() {
     let ty = parse_type_str("HashMap<String, i32>");
@@ -163,6 +196,51 @@ fn test_parse_btreemap_type() {
     }
 }
 
+#[test]
+fn test_parse_ordered_map_types() {
+    for source in [
+        "IndexMap<String, User>",
+        "indexmap::IndexMap<String, User, ahash::RandomState>",
+        "LinkedHashMap<String, User>",
+    ] {
+        let ty = parse_type_str(source);
+        assert_eq!(
+            parse_type(&ty),
+            RustType::HashMap {
+                key: Box::new(RustType::Primitive("String".to_string())),
+                value: Box::new(RustType::custom("User")),
+            },
+            "{source}"
+        );
+    }
+}
+
+#[test]
+fn test_parse_serde_json_map() {
+    let json_object = RustType::HashMap {
+        key: Box::new(RustType::Primitive("String".to_string())),
+        value: Box::new(RustType::Primitive("Value".to_string())),
+    };
+    for source in [
+        "serde_json::Map<String, Value>",
+        "serde_json::Map<String, serde_json::Value>",
+        "Map<String, Value>",
+    ] {
+        let ty = parse_type_str(source);
+        assert_eq!(parse_type(&ty), json_object, "{source}");
+    }
+
+    // A user-defined `Map` is left to the resolver
+    let ty = parse_type_str("Map<String, User>");
+    assert!(matches!(parse_type(&ty), RustType::Custom { ref name, .. } if name == "Map"));
+}
+
+#[test]
+fn test_parse_boxed_raw_value() {
+    let ty = parse_type_str("Box<serde_json::value::RawValue>");
+    assert_eq!(parse_type(&ty), RustType::Primitive("RawValue".to_string()));
+}
+
 #[test]
 fn test_parse_tuple_type() {
     let ty = parse_type_str("(i32, String, bool)");
@@ -223,7 +301,7 @@ fn test_parse_nested_generics() {
 fn test_parse_result_with_vec() {
     let ty = parse_type_str("Result<Vec<Item>, String>");
     match parse_type(&ty) {
-        RustType::Result(ok) => match *ok {
+        RustType::Result { ok, .. } => match *ok {
             RustType::Vec(inner) => match *inner {
                 RustType::Custom { name, .. } => assert_eq!(name, "Item"),
                 other => panic!("Expected Custom, got {:?}", other),
@@ -267,6 +345,8 @@ fn test_parse_external_types() {
         "PathBuf",
         "Url",
         "IpAddr",
+        "SocketAddr",
+        "SocketAddrV6",
         "Duration",
     ] {
         let ty = parse_type_str(ext_type);
@@ -277,6 +357,25 @@ fn test_parse_external_types() {
     }
 }
 
+#[test]
+fn test_parse_nonzero_integers_as_their_integer() {
+    for (source, int) in [
+        ("NonZeroU32", "u32"),
+        ("std::num::NonZeroI64", "i64"),
+        ("NonZeroU128", "u128"),
+        ("NonZeroUsize", "usize"),
+        ("NonZero<u8>", "u8"),
+        ("core::num::NonZero<i16>", "i16"),
+    ] {
+        let ty = parse_type_str(source);
+        assert_eq!(
+            parse_type(&ty),
+            RustType::Primitive(int.to_string()),
+            "{source}"
+        );
+    }
+}
+
 #[test]
 fn test_parse_reference_type() {
     let ty = parse_type_str("&str");
@@ -349,6 +448,18 @@ fn test_parse_nested_smart_pointers() {
     }
 }
 
+#[test]
+fn test_parse_box_of_vec_unwraps_to_vec() {
+    let ty = parse_type_str("Box<Vec<User>>");
+    match parse_type(&ty) {
+        RustType::Vec(inner) => match *inner {
+            RustType::Custom { name, .. } => assert_eq!(name, "User"),
+            other => panic!("Expected Custom(User) inside Vec, got {:?}", other),
+        },
+        other => panic!("Expected Vec from Box<Vec<User>>, got {:?}", other),
+    }
+}
+
 #[test]
 fn test_parse_option_box_self_ref() {
     // `Option<Box<Node>>` — the pattern used in recursive data structures.
@@ -361,3 +472,21 @@ fn test_parse_option_box_self_ref() {
         other => panic!("Expected Option, got {:?}", other),
     }
 }
+
+#[test]
+fn test_parse_reference_to_slice_of_custom() {
+    let ty = parse_type_str("&[User]");
+    assert_eq!(
+        parse_type(&ty),
+        RustType::Vec(Box::new(RustType::custom("User")))
+    );
+}
+
+#[test]
+fn test_parse_boxed_slice_of_custom() {
+    let ty = parse_type_str("Box<[User]>");
+    assert_eq!(
+        parse_type(&ty),
+        RustType::Vec(Box::new(RustType::custom("User")))
+    );
+}
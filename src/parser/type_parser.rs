@@ -1,3 +1,4 @@
+mod cfg_attr;
 mod expanded;
 mod serde_attrs;
 
@@ -6,17 +7,21 @@ use std::collections::HashSet;
 use std::path::Path;
 use syn::{Fields, Item, ItemEnum, ItemStruct, Meta};
 
+use crate::known_types::DATE_TIME_TYPES;
 use crate::models::{
-    EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustTypeAlias, StructField, VariantData,
+    DerivedTypes, EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustType, RustTypeAlias,
+    StructField, VariantData,
 };
 
-use super::type_extractor::parse_type_with_context;
+use super::docs::extract_docs;
+use super::type_extractor::{is_phantom_data, parse_type_with_context};
 use crate::models::StructShape;
 use expanded::collect_serializable_types;
 use serde_attrs::{
-    apply_rename_all, get_serde_rename, has_serde_default, has_serde_flatten, has_serde_skip,
-    has_serde_transparent, has_skip_serializing_if_none, has_ts_optional,
-    parse_serde_container_attrs,
+    apply_rename_all, get_serde_rename, get_serde_with, has_serde_default, has_serde_flatten,
+    has_serde_skip, has_serde_skip_serializing, has_serde_transparent,
+    has_skip_serializing_if_none, has_ts_optional, parse_serde_container_attrs,
+    parse_ts_container_attrs,
 };
 
 /// Parsed types from a Rust file.
@@ -39,12 +44,15 @@ pub struct ParsedTypes {
 ///   pipeline collects everything it can reach so the resolver has a
 ///   complete view; tests usually want filtering on so they can
 ///   exercise it.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct ParseOptions {
     /// Input is `cargo expand` output rather than raw source.
     pub expanded: bool,
     /// Include every struct/enum/alias, not just ones with serde derives.
     pub include_all: bool,
+    /// Cargo features taken as enabled when expanding
+    /// `#[cfg_attr(feature = "...", ...)]`
+    pub active_features: Vec<String>,
 }
 
 impl ParseOptions {
@@ -53,6 +61,7 @@ impl ParseOptions {
     pub const SOURCE: Self = Self {
         expanded: false,
         include_all: false,
+        active_features: Vec::new(),
     };
     /// Source file, every struct/enum/alias regardless of serde attrs.
     /// Used by the reachable-type walker — if a command signature
@@ -60,17 +69,20 @@ impl ParseOptions {
     pub const SOURCE_ALL: Self = Self {
         expanded: false,
         include_all: true,
+        active_features: Vec::new(),
     };
     /// `cargo expand` output, only types with a serde impl.
     pub const EXPANDED: Self = Self {
         expanded: true,
         include_all: false,
+        active_features: Vec::new(),
     };
     /// `cargo expand` output, every type. Used by the pipeline to
     /// register macro-generated types that aren't visible in source.
     pub const EXPANDED_ALL: Self = Self {
         expanded: true,
         include_all: true,
+        active_features: Vec::new(),
     };
 }
 
@@ -87,7 +99,13 @@ pub fn parse_types(
     source_file: &Path,
     options: ParseOptions,
 ) -> Result<ParsedTypes> {
-    parse_types_internal(content, source_file, options.expanded, options.include_all)
+    parse_types_internal(
+        content,
+        source_file,
+        options.expanded,
+        options.include_all,
+        &options.active_features,
+    )
 }
 
 fn parse_types_internal(
@@ -95,8 +113,10 @@ fn parse_types_internal(
     source_file: &Path,
     expanded: bool,
     include_all: bool,
+    active_features: &[String],
 ) -> Result<ParsedTypes> {
-    let syntax = syn::parse_file(content)?;
+    let mut syntax = syn::parse_file(content)?;
+    cfg_attr::expand_cfg_attrs(&mut syntax.items, active_features);
     let mut parsed = ParsedTypes::default();
 
     // For expanded code, first collect all types that have Serialize/Deserialize impls
@@ -216,6 +236,20 @@ fn parse_alias(item_type: &syn::ItemType, source_file: &Path) -> Option<RustType
 /// Check if a type has Serialize or Deserialize derive attribute
 /// This indicates the type is meant for serialization and should be exported
 fn is_serializable(attrs: &[syn::Attribute]) -> bool {
+    derives_any(
+        attrs,
+        &[
+            "Serialize",
+            "Deserialize",
+            "Serialize_repr",
+            "Deserialize_repr",
+        ],
+    )
+}
+
+/// Check if `#[derive(...)]` lists any of `traits`, bare or path-qualified
+/// (`serde::Deserialize`).
+fn derives_any(attrs: &[syn::Attribute], traits: &[&str]) -> bool {
     for attr in attrs {
         if let Meta::List(meta_list) = &attr.meta {
             if meta_list.path.is_ident("derive") {
@@ -224,16 +258,10 @@ fn is_serializable(attrs: &[syn::Attribute]) -> bool {
                     syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
                 ) {
                     for path in nested {
-                        if let Some(ident) = path.get_ident() {
-                            let name = ident.to_string();
-                            if name == "Serialize" || name == "Deserialize" {
-                                return true;
-                            }
-                        }
-                        // Also check for fully qualified paths like serde::Serialize
+                        // The last segment covers both `Serialize` and
+                        // fully qualified paths like `serde::Serialize`
                         if let Some(last) = path.segments.last() {
-                            let name = last.ident.to_string();
-                            if name == "Serialize" || name == "Deserialize" {
+                            if traits.iter().any(|t| last.ident == t) {
                                 return true;
                             }
                         }
@@ -320,7 +348,16 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
     // Create a set for efficient lookup when parsing field types
     let generic_params: HashSet<String> = generics.iter().cloned().collect();
 
+    if container_attrs.rename_all_fields.is_some() {
+        crate::diagnostics::warn(format!(
+            "#[serde(rename_all_fields)] on struct '{}' is ignored; it only applies \
+            to enums. Use rename_all to rename struct fields",
+            name
+        ));
+    }
+
     let transparent = has_serde_transparent(&item.attrs);
+    let deserializable = derives_any(&item.attrs, &["Deserialize"]);
 
     let (fields, mut shape): (Vec<StructField>, StructShape) = match &item.fields {
         Fields::Named(named) => {
@@ -328,31 +365,45 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
                 .named
                 .iter()
                 .filter_map(|field| {
-                    // Skip fields with #[serde(skip)] or similar
-                    if has_serde_skip(&field.attrs) {
+                    // Skip fields with #[serde(skip)] or similar, and
+                    // PhantomData markers
+                    if has_serde_skip(&field.attrs) || is_phantom_data(&field.ty) {
+                        return None;
+                    }
+
+                    // #[serde(skip_serializing)] is never sent to the
+                    // frontend. If the struct is also deserialized it can
+                    // still be sent back, so keep it as an optional field.
+                    let skip_serializing = has_serde_skip_serializing(&field.attrs);
+                    if skip_serializing && !deserializable {
                         return None;
                     }
 
                     let field_name = field.ident.as_ref()?.to_string();
-                    let field_type = parse_type_with_context(&field.ty, &generic_params);
+                    let field_type = apply_serde_with(
+                        parse_type_with_context(&field.ty, &generic_params),
+                        &field.attrs,
+                    );
 
                     // Check for serde rename attribute
-                    let explicit_rename = get_serde_rename(&field.attrs);
-                    let final_name = explicit_rename
-                        .clone()
-                        .or_else(|| apply_rename_all(&field_name, &container_attrs.rename_all))
-                        .unwrap_or(field_name);
-                    let has_rename =
-                        explicit_rename.is_some() || container_attrs.rename_all.is_some();
-
-                    // #[ts(optional)] OR #[serde(default)] OR
-                    // #[serde(skip_serializing_if = "Option::is_none")] on
-                    // an Option<T> — each of these makes the field optional
-                    // in the JSON serde actually emits.
-                    let use_optional = has_ts_optional(&field.attrs, &field_type)
+                    let (final_name, has_rename) = serialized_field_name(
+                        field_name,
+                        &field.attrs,
+                        &container_attrs.rename_all,
+                    );
+
+                    // #[ts(optional)], or #[serde(skip_serializing_if =
+                    // "Option::is_none")] on an Option<T>, drops the field
+                    // instead of writing null. #[serde(default)] only lets
+                    // the field be omitted from the input, so an Option
+                    // keeps its null.
+                    let omits_null = skip_serializing
+                        || has_ts_optional(&field.attrs, &field_type)
                         || (matches!(field_type, crate::models::RustType::Option(_))
-                            && (has_serde_default(&field.attrs)
-                                || has_skip_serializing_if_none(&field.attrs)));
+                            && has_skip_serializing_if_none(&field.attrs));
+                    let has_default = has_serde_default(&field.attrs);
+                    let use_optional = omits_null || has_default;
+                    let keeps_null = has_default && !omits_null;
 
                     // Check for #[serde(flatten)] attribute
                     let is_flatten = has_serde_flatten(&field.attrs);
@@ -362,7 +413,9 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
                         ty: field_type,
                         has_explicit_rename: has_rename,
                         use_optional,
+                        keeps_null,
                         is_flatten,
+                        docs: extract_docs(&field.attrs),
                     })
                 })
                 .collect();
@@ -382,7 +435,9 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
                     ty: parse_type_with_context(&field.ty, &generic_params),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 })
                 .collect();
             let shape = if fields.len() == 1 {
@@ -401,15 +456,107 @@ fn parse_struct(item: &ItemStruct, source_file: &Path) -> Option<RustStruct> {
         shape = StructShape::Newtype;
     }
 
+    let derived = parse_derived_types(item, &name, &container_attrs.rename_all);
+
     Some(RustStruct {
         name,
         generics,
         fields,
         shape,
         source_file: source_file.to_path_buf(),
+        derived,
+        docs: extract_docs(&item.attrs),
     })
 }
 
+/// Name a named field takes in serde's JSON, plus whether a `rename` /
+/// `rename_all` was involved.
+fn serialized_field_name(
+    field_name: String,
+    attrs: &[syn::Attribute],
+    rename_all: &Option<String>,
+) -> (String, bool) {
+    let explicit_rename = get_serde_rename(attrs);
+    let has_rename = explicit_rename.is_some() || rename_all.is_some();
+    let final_name = explicit_rename
+        .or_else(|| apply_rename_all(&field_name, rename_all))
+        .unwrap_or(field_name);
+    (final_name, has_rename)
+}
+
+/// Read `#[ts(update)]` / `#[ts(create(omit(...)))]` off a struct. Omitted
+/// fields are written with their Rust names and translated here to the
+/// serialized names the TypeScript `Omit<...>` has to use.
+fn parse_derived_types(
+    item: &ItemStruct,
+    struct_name: &str,
+    rename_all: &Option<String>,
+) -> DerivedTypes {
+    let ts_attrs = parse_ts_container_attrs(&item.attrs);
+    let create = ts_attrs.create.map(|omitted| {
+        omitted
+            .into_iter()
+            .filter_map(|rust_name| {
+                let field = item
+                    .fields
+                    .iter()
+                    .find(|f| f.ident.as_ref().is_some_and(|i| *i == rust_name));
+                match field {
+                    Some(field) if !has_serde_skip(&field.attrs) => {
+                        Some(serialized_field_name(rust_name, &field.attrs, rename_all).0)
+                    }
+                    // Already absent from the interface; nothing to omit.
+                    Some(_) => None,
+                    None => {
+                        crate::diagnostics::warn(format!(
+                            "#[ts(create(omit(\"{}\")))] on '{}' names no such field, ignoring",
+                            rust_name, struct_name
+                        ));
+                        None
+                    }
+                }
+            })
+            .collect()
+    });
+
+    DerivedTypes {
+        update: ts_attrs.update,
+        create,
+    }
+}
+
+/// Honor `#[serde(with = "...")]` on date-time fields.
+///
+/// Date-time types map to `string` by default, which matches the RFC 3339
+/// / ISO 8601 helpers (`time::serde::rfc3339`, `...::iso8601`). The
+/// timestamp helpers (`time::serde::timestamp`, `chrono::serde::ts_seconds`
+/// and friends) serialize a Unix timestamp instead, so those fields become
+/// numbers. The `::option` variants of each helper keep the `Option`.
+fn apply_serde_with(ty: RustType, attrs: &[syn::Attribute]) -> RustType {
+    let Some(module) = get_serde_with(attrs) else {
+        return ty;
+    };
+    let is_timestamp = module
+        .split("::")
+        .any(|segment| segment == "timestamp" || segment.starts_with("ts_"));
+    if is_timestamp {
+        timestamp_type(ty)
+    } else {
+        ty
+    }
+}
+
+/// Swap a date-time type (or an `Option` of one) for its timestamp form.
+fn timestamp_type(ty: RustType) -> RustType {
+    match ty {
+        RustType::Primitive(name) if DATE_TIME_TYPES.contains(&name.as_str()) => {
+            RustType::Primitive("i64".to_string())
+        }
+        RustType::Option(inner) => RustType::Option(Box::new(timestamp_type(*inner))),
+        other => other,
+    }
+}
+
 /// Parse an enum into our RustEnum representation
 fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
     let name = item.ident.to_string();
@@ -434,7 +581,21 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
     // Parse container-level serde attributes (like rename_all)
     let container_attrs = parse_serde_container_attrs(&item.attrs);
 
-    let representation = if container_attrs.untagged {
+    // serde_repr writes a unit-only enum as its integer discriminant.
+    let numeric = derives_any(&item.attrs, &["Serialize_repr", "Deserialize_repr"])
+        && item
+            .variants
+            .iter()
+            .all(|v| matches!(v.fields, Fields::Unit));
+    let discriminants = if numeric {
+        discriminant_values(item)
+    } else {
+        vec![None; item.variants.len()]
+    };
+
+    let representation = if numeric {
+        EnumRepresentation::Numeric
+    } else if container_attrs.untagged {
         EnumRepresentation::Untagged
     } else if let Some(tag) = &container_attrs.tag {
         if let Some(content) = &container_attrs.content {
@@ -449,11 +610,36 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
         EnumRepresentation::External
     };
 
+    // rename_all renames the variants and rename_all_fields renames the
+    // fields inside struct-like variants; neither reaches into the other.
+    // A variant's own rename_all takes precedence over rename_all_fields.
+    if container_attrs.rename_all_fields.is_some()
+        && !item
+            .variants
+            .iter()
+            .any(|v| matches!(v.fields, Fields::Named(_)))
+    {
+        crate::diagnostics::warn(format!(
+            "#[serde(rename_all_fields)] on enum '{}' has no effect: it has no \
+            struct-like variants. Variant names are renamed by rename_all",
+            name
+        ));
+    }
+
     let variants = item
         .variants
         .iter()
-        .map(|variant| {
+        .zip(discriminants)
+        .map(|(variant, discriminant)| {
             let variant_name = variant.ident.to_string();
+            if numeric {
+                return EnumVariant {
+                    name: variant_name,
+                    data: VariantData::Unit,
+                    has_explicit_rename: false,
+                    discriminant,
+                };
+            }
 
             // Check for serde rename attribute on variant
             let explicit_rename = get_serde_rename(&variant.attrs);
@@ -475,27 +661,36 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
                     VariantData::Tuple(types)
                 }
                 Fields::Named(named) => {
+                    let field_rule = parse_serde_container_attrs(&variant.attrs)
+                        .rename_all
+                        .or_else(|| container_attrs.rename_all_fields.clone());
                     let fields = named
                         .named
                         .iter()
                         .filter_map(|field| {
-                            // Skip fields with #[serde(skip)] or similar
-                            if has_serde_skip(&field.attrs) {
+                            // Skip fields with #[serde(skip)] or similar, and
+                            // PhantomData markers
+                            if has_serde_skip(&field.attrs) || is_phantom_data(&field.ty) {
                                 return None;
                             }
 
                             let field_name = field.ident.as_ref()?.to_string();
-                            let field_type = parse_type_with_context(&field.ty, &generic_params);
-                            let explicit_rename = get_serde_rename(&field.attrs);
-                            let final_name = explicit_rename.clone().unwrap_or(field_name);
+                            let field_type = apply_serde_with(
+                                parse_type_with_context(&field.ty, &generic_params),
+                                &field.attrs,
+                            );
+                            let (final_name, has_rename) =
+                                serialized_field_name(field_name, &field.attrs, &field_rule);
                             let use_optional = has_ts_optional(&field.attrs, &field_type);
                             let is_flatten = has_serde_flatten(&field.attrs);
                             Some(StructField {
                                 name: final_name,
                                 ty: field_type,
-                                has_explicit_rename: explicit_rename.is_some(),
+                                has_explicit_rename: has_rename,
                                 use_optional,
+                                keeps_null: false,
                                 is_flatten,
+                                docs: extract_docs(&field.attrs),
                             })
                         })
                         .collect();
@@ -507,6 +702,7 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
                 name: final_name,
                 data,
                 has_explicit_rename,
+                discriminant: None,
             }
         })
         .collect();
@@ -517,8 +713,53 @@ fn parse_enum(item: &ItemEnum, source_file: &Path) -> Option<RustEnum> {
         variants,
         source_file: source_file.to_path_buf(),
         representation,
+        docs: extract_docs(&item.attrs),
     })
 }
 
+/// Each variant's discriminant, following Rust's rule that an implicit one
+/// is the previous value plus one, starting at zero. Only integer literals
+/// are evaluated; a variant whose value depends on anything else (a
+/// `const`, arithmetic) is `None`, and so is every implicit one after it.
+fn discriminant_values(item: &ItemEnum) -> Vec<Option<i64>> {
+    fn literal(expr: &syn::Expr) -> Option<i64> {
+        match expr {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int),
+                ..
+            }) => int.base10_parse().ok(),
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => literal(expr).map(|v: i64| -v),
+            syn::Expr::Paren(paren) => literal(&paren.expr),
+            syn::Expr::Group(group) => literal(&group.expr),
+            _ => None,
+        }
+    }
+
+    let mut next = Some(0i64);
+    let mut values = Vec::with_capacity(item.variants.len());
+    for variant in &item.variants {
+        let value = match &variant.discriminant {
+            Some((_, expr)) => {
+                let value = literal(expr);
+                if value.is_none() {
+                    crate::diagnostics::warn(format!(
+                        "Cannot evaluate the discriminant of {}::{}; it is typed as `number`",
+                        item.ident, variant.ident
+                    ));
+                }
+                value
+            }
+            None => next,
+        };
+        next = value.and_then(|v| v.checked_add(1));
+        values.push(value);
+    }
+    values
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,138 @@
+//! `#[cfg_attr(predicate, attr, ...)]` expansion.
+//!
+//! Before the struct/enum walk, every `cfg_attr` on a type, variant or
+//! field is replaced by the attributes it gates when its predicate holds
+//! for `[input] active_features`, and dropped otherwise. The serde
+//! helpers then see `#[cfg_attr(feature = "api", serde(skip))]` as a
+//! plain `#[serde(skip)]` (or not at all) without knowing about `cfg`.
+
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Fields, Item, Meta, Token};
+
+/// Expand the `cfg_attr`s on every type in `items`, inline modules
+/// included.
+pub(super) fn expand_cfg_attrs(items: &mut [Item], features: &[String]) {
+    for item in items {
+        match item {
+            Item::Struct(item_struct) => {
+                expand_attrs(&mut item_struct.attrs, features);
+                expand_fields(&mut item_struct.fields, features);
+            }
+            Item::Enum(item_enum) => {
+                expand_attrs(&mut item_enum.attrs, features);
+                for variant in &mut item_enum.variants {
+                    expand_attrs(&mut variant.attrs, features);
+                    expand_fields(&mut variant.fields, features);
+                }
+            }
+            Item::Type(item_type) => expand_attrs(&mut item_type.attrs, features),
+            Item::Mod(module) => {
+                if let Some((_, mod_items)) = &mut module.content {
+                    expand_cfg_attrs(mod_items, features);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn expand_fields(fields: &mut Fields, features: &[String]) {
+    for field in fields.iter_mut() {
+        expand_attrs(&mut field.attrs, features);
+    }
+}
+
+fn expand_attrs(attrs: &mut Vec<Attribute>, features: &[String]) {
+    if !attrs.iter().any(|attr| attr.path().is_ident("cfg_attr")) {
+        return;
+    }
+    let mut expanded = Vec::with_capacity(attrs.len());
+    for attr in attrs.drain(..) {
+        push_expanded(attr, features, &mut expanded);
+    }
+    *attrs = expanded;
+}
+
+/// Push `attr`, or the attributes it gates when it is an active
+/// `cfg_attr` (nested `cfg_attr`s included). An unparseable `cfg_attr`
+/// is kept as is.
+fn push_expanded(attr: Attribute, features: &[String], out: &mut Vec<Attribute>) {
+    let Meta::List(list) = &attr.meta else {
+        out.push(attr);
+        return;
+    };
+    if !list.path.is_ident("cfg_attr") {
+        out.push(attr);
+        return;
+    }
+    let Ok(args) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+        out.push(attr);
+        return;
+    };
+    let mut args = args.into_iter();
+    let Some(predicate) = args.next() else {
+        return;
+    };
+    if !cfg_holds(&predicate, features) {
+        return;
+    }
+    for meta in args {
+        let gated = Attribute {
+            meta,
+            ..attr.clone()
+        };
+        push_expanded(gated, features, out);
+    }
+}
+
+/// Evaluate a `cfg` predicate. Only `feature = "..."` is known; every
+/// other option (`test`, `target_os = ...`) is taken as unset.
+fn cfg_holds(predicate: &Meta, features: &[String]) -> bool {
+    match predicate {
+        Meta::NameValue(nv) if nv.path.is_ident("feature") => match &nv.value {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(name),
+                ..
+            }) => features.iter().any(|feature| *feature == name.value()),
+            _ => false,
+        },
+        Meta::List(list) => {
+            let Ok(inner) = list.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)
+            else {
+                return false;
+            };
+            if list.path.is_ident("all") {
+                inner.iter().all(|meta| cfg_holds(meta, features))
+            } else if list.path.is_ident("any") {
+                inner.iter().any(|meta| cfg_holds(meta, features))
+            } else if list.path.is_ident("not") {
+                inner.len() == 1 && !cfg_holds(&inner[0], features)
+            } else {
+                false
+            }
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holds(predicate: &str, features: &[&str]) -> bool {
+        let features: Vec<String> = features.iter().map(|f| f.to_string()).collect();
+        cfg_holds(&syn::parse_str(predicate).unwrap(), &features)
+    }
+
+    #[test]
+    fn test_cfg_predicates() {
+        assert!(holds(r#"feature = "api""#, &["api"]));
+        assert!(!holds(r#"feature = "api""#, &[]));
+        assert!(holds(r#"not(feature = "api")"#, &[]));
+        assert!(holds(r#"all(feature = "a", feature = "b")"#, &["a", "b"]));
+        assert!(!holds(r#"all(feature = "a", feature = "b")"#, &["a"]));
+        assert!(holds(r#"any(feature = "a", test)"#, &["a"]));
+        assert!(!holds("test", &["test"]));
+        assert!(holds("all()", &[]));
+    }
+}
@@ -19,6 +19,9 @@ use crate::utils::{
 pub(super) struct SerdeContainerAttrs {
     /// Value of rename_all attribute (e.g., "camelCase", "snake_case")
     pub rename_all: Option<String>,
+    /// Value of rename_all_fields; enum-only, applies to the fields of
+    /// struct-like variants and never to variant names
+    pub rename_all_fields: Option<String>,
     /// Value of tag attribute (e.g., "type")
     pub tag: Option<String>,
     /// Value of content attribute (e.g., "content")
@@ -84,6 +87,23 @@ pub(super) fn get_serde_rename(attrs: &[syn::Attribute]) -> Option<String> {
     found
 }
 
+/// Get the module path from `#[serde(with = "...")]` if present.
+pub(super) fn get_serde_with(attrs: &[syn::Attribute]) -> Option<String> {
+    let mut found = None;
+    for_each_meta_in(attrs, "serde", |meta| {
+        if let Meta::NameValue(nv) = meta {
+            if nv.path.is_ident("with") {
+                if let Some(value) = string_value(nv) {
+                    found = Some(value);
+                    return true;
+                }
+            }
+        }
+        false
+    });
+    found
+}
+
 /// Check if a field has `#[ts(optional)]` and validate it's on `Option<T>`.
 pub(super) fn has_ts_optional(attrs: &[syn::Attribute], ty: &crate::models::RustType) -> bool {
     let mut result = false;
@@ -105,15 +125,71 @@ pub(super) fn has_ts_optional(attrs: &[syn::Attribute], ty: &crate::models::Rust
     result
 }
 
+/// Struct-level `#[ts(...)]` markers requesting derived DTO types.
+#[derive(Debug, Default)]
+pub(super) struct TsContainerAttrs {
+    /// `#[ts(update)]`
+    pub update: bool,
+    /// `#[ts(create)]` / `#[ts(create(omit("id", ...)))]` — Rust field
+    /// names to leave out of the create type
+    pub create: Option<Vec<String>>,
+}
+
+/// Parse struct-level `#[ts(update)]` and `#[ts(create(omit(...)))]`.
+pub(super) fn parse_ts_container_attrs(attrs: &[syn::Attribute]) -> TsContainerAttrs {
+    let mut result = TsContainerAttrs::default();
+    for_each_meta_in(attrs, "ts", |meta| {
+        match meta {
+            Meta::Path(path) if path.is_ident("update") => result.update = true,
+            Meta::Path(path) if path.is_ident("create") => {
+                result.create.get_or_insert_with(Vec::new);
+            }
+            Meta::List(list) if list.path.is_ident("create") => {
+                let omitted = result.create.get_or_insert_with(Vec::new);
+                let inner = list.parse_args_with(
+                    syn::punctuated::Punctuated::<Meta, syn::Token![,]>::parse_terminated,
+                );
+                for meta in inner.iter().flatten() {
+                    match meta {
+                        Meta::List(omit) if omit.path.is_ident("omit") => {
+                            let names = omit.parse_args_with(
+                                syn::punctuated::Punctuated::<syn::LitStr, syn::Token![,]>::parse_terminated,
+                            );
+                            match names {
+                                Ok(names) => omitted.extend(names.iter().map(|n| n.value())),
+                                Err(_) => crate::diagnostics::warn(
+                                    "#[ts(create(omit(...)))] expects string literals, e.g. omit(\"id\"); ignoring",
+                                ),
+                            }
+                        }
+                        _ => crate::diagnostics::warn(
+                            "Unknown option inside #[ts(create(...))]; only omit(...) is supported",
+                        ),
+                    }
+                }
+            }
+            _ => {}
+        }
+        false
+    });
+    result
+}
+
 /// Check if a field has `#[serde(skip)]`.
 ///
-/// Note: we only check for plain `skip`, not `skip_serializing` or
-/// `skip_deserializing` — those are directional and a struct used for both
-/// input and output should still keep the other direction in TypeScript.
+/// Note: this is plain `skip` only. `skip_serializing` is directional, see
+/// [`has_serde_skip_serializing`]; `skip_deserializing` fields are still
+/// serialized, so they stay in the interface.
 pub(super) fn has_serde_skip(attrs: &[syn::Attribute]) -> bool {
     has_serde_path_flag(attrs, "skip")
 }
 
+/// Check if a field has `#[serde(skip_serializing)]`: it never appears in
+/// the JSON sent to the frontend, but may still be accepted as input.
+pub(super) fn has_serde_skip_serializing(attrs: &[syn::Attribute]) -> bool {
+    has_serde_path_flag(attrs, "skip_serializing")
+}
+
 /// Check if a field has `#[serde(flatten)]`.
 pub(super) fn has_serde_flatten(attrs: &[syn::Attribute]) -> bool {
     has_serde_path_flag(attrs, "flatten")
@@ -179,7 +255,8 @@ pub(super) fn has_skip_serializing_if_none(attrs: &[syn::Attribute]) -> bool {
     result
 }
 
-/// Parse serde container attributes (rename_all, tag, content, untagged).
+/// Parse serde container attributes (rename_all, rename_all_fields, tag,
+/// content, untagged).
 pub(super) fn parse_serde_container_attrs(attrs: &[syn::Attribute]) -> SerdeContainerAttrs {
     let mut result = SerdeContainerAttrs::default();
     for_each_meta_in(attrs, "serde", |meta| {
@@ -187,6 +264,8 @@ pub(super) fn parse_serde_container_attrs(attrs: &[syn::Attribute]) -> SerdeCont
             Meta::NameValue(nv) => {
                 if nv.path.is_ident("rename_all") {
                     result.rename_all = string_value(nv);
+                } else if nv.path.is_ident("rename_all_fields") {
+                    result.rename_all_fields = string_value(nv);
                 } else if nv.path.is_ident("tag") {
                     result.tag = string_value(nv);
                 } else if nv.path.is_ident("content") {
@@ -290,4 +369,18 @@ mod tests {
         assert!(parsed.untagged);
         assert_eq!(parsed.rename_all.as_deref(), Some("lowercase"));
     }
+
+    #[test]
+    fn rename_all_fields_is_kept_apart_from_rename_all() {
+        let attrs = attrs_of(
+            r#"
+            #[derive(Serialize)]
+            #[serde(rename_all = "snake_case", rename_all_fields = "camelCase")]
+            pub enum E { A { user_id: u32 } }
+            "#,
+        );
+        let parsed = parse_serde_container_attrs(&attrs);
+        assert_eq!(parsed.rename_all.as_deref(), Some("snake_case"));
+        assert_eq!(parsed.rename_all_fields.as_deref(), Some("camelCase"));
+    }
 }
@@ -61,6 +61,31 @@ fn test_parse_ts_optional_ignored_on_non_option() {
     );
 }
 
+#[test]
+fn test_parse_ts_update_and_create_markers() {
+    let code = r#"
+        #[derive(Serialize, TS)]
+        #[serde(rename_all = "camelCase")]
+        #[ts(update, create(omit("id", "created_at")))]
+        pub struct User {
+            pub id: i32,
+            pub name: String,
+            pub created_at: String,
+        }
+    "#;
+
+    let ParsedTypes { structs, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let derived = &structs[0].derived;
+
+    assert!(derived.update);
+    // Omitted names follow the struct's serde renaming.
+    assert_eq!(
+        derived.create.as_deref(),
+        Some(&["id".to_string(), "createdAt".to_string()][..])
+    );
+}
+
 #[test]
 fn test_parse_ts_optional_on_struct_variant() {
     let code = r#"
@@ -537,6 +562,36 @@ fn test_ignore_non_serializable() {
     assert_eq!(structs[0].name, "Exported");
 }
 
+#[test]
+fn test_cfg_attr_serde_skip_and_rename_follow_active_features() {
+    let code = r#"
+        #[cfg_attr(feature = "serde", derive(Serialize))]
+        pub struct Session {
+            pub id: u32,
+            #[cfg_attr(feature = "redact", serde(skip))]
+            pub token: String,
+            #[cfg_attr(feature = "serde", serde(rename = "expiresAt"))]
+            pub expires_at: u64,
+        }
+    "#;
+    let field_names = |features: &[&str]| {
+        let options = ParseOptions {
+            active_features: features.iter().map(|f| f.to_string()).collect(),
+            ..ParseOptions::SOURCE
+        };
+        let ParsedTypes { structs, .. } = parse_types(code, &test_path(), options).unwrap();
+        structs
+            .iter()
+            .flat_map(|s| s.fields.iter().map(|f| f.name.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(field_names(&["serde", "redact"]), ["id", "expiresAt"]);
+    assert_eq!(field_names(&["serde"]), ["id", "token", "expiresAt"]);
+    // Without the feature, the derive is gated off too
+    assert!(field_names(&[]).is_empty());
+}
+
 #[test]
 fn test_parse_types_in_mod() {
     let code = r#"
@@ -644,6 +699,58 @@ fn test_parse_expanded_code_with_serde_attrs() {
     assert_eq!(structs[0].name, "AuthResponse");
 }
 
+#[test]
+fn test_parse_expanded_enums_in_nested_modules() {
+    // cargo expand output: serde's impls sit in `const _: () = { ... }`
+    // blocks next to the enum, a few modules down
+    let code = r#"
+        pub mod api {
+            pub mod types {
+                pub enum Status {
+                    Active,
+                    Suspended { reason: ::std::string::String },
+                    Pending(u32),
+                }
+                #[doc(hidden)]
+                const _: () = {
+                    #[automatically_derived]
+                    impl _serde::Serialize for Status {
+                        fn serialize<__S>(&self, __serializer: __S) -> _serde::__private::Result<__S::Ok, __S::Error>
+                        where
+                            __S: _serde::Serializer,
+                        {
+                            unimplemented!()
+                        }
+                    }
+                };
+
+                pub enum Internal {
+                    Hidden,
+                }
+            }
+        }
+    "#;
+
+    let ParsedTypes { enums, .. } =
+        super::parse_types(code, &test_path(), ParseOptions::EXPANDED).unwrap();
+    assert_eq!(enums.len(), 1, "only the serializable enum: {enums:?}");
+    let status = &enums[0];
+    assert_eq!(status.name, "Status");
+    let variants: Vec<(&str, &VariantData)> = status
+        .variants
+        .iter()
+        .map(|v| (v.name.as_str(), &v.data))
+        .collect();
+    assert!(matches!(
+        variants[..],
+        [
+            ("Active", VariantData::Unit),
+            ("Suspended", VariantData::Struct(_)),
+            ("Pending", VariantData::Tuple(_)),
+        ]
+    ));
+}
+
 #[test]
 fn test_parse_expanded_without_derive_but_with_serde_field_attrs() {
     // This simulates cargo expand output where derive is already expanded
@@ -715,13 +822,50 @@ fn test_serde_skip_fields_are_excluded() {
     let ParsedTypes { structs, .. } =
         parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
     assert_eq!(structs.len(), 1);
-    // Only #[serde(skip)] should be excluded
-    // skip_serializing and skip_deserializing are directional and should be kept
-    assert_eq!(structs[0].fields.len(), 4);
-    assert_eq!(structs[0].fields[0].name, "id");
-    assert_eq!(structs[0].fields[1].name, "name");
-    assert_eq!(structs[0].fields[2].name, "password_hash");
-    assert_eq!(structs[0].fields[3].name, "computed_field");
+    // A Serialize-only struct never carries skip / skip_serializing fields;
+    // skip_deserializing fields are still serialized and stay
+    let names: Vec<_> = structs[0].fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["id", "name", "computed_field"]);
+}
+
+#[test]
+fn test_phantom_data_fields_are_excluded() {
+    let code = r#"
+        use std::marker::PhantomData;
+
+        #[derive(Serialize)]
+        pub struct Id<T> {
+            pub value: u64,
+            marker: std::marker::PhantomData<T>,
+            pub label: String,
+            _kind: PhantomData<fn() -> T>,
+        }
+    "#;
+
+    let ParsedTypes { structs, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let names: Vec<_> = structs[0].fields.iter().map(|f| f.name.as_str()).collect();
+    assert_eq!(names, vec!["value", "label"]);
+}
+
+#[test]
+fn test_skip_serializing_is_optional_on_deserializable_struct() {
+    let code = r#"
+        #[derive(Serialize, Deserialize)]
+        pub struct Login {
+            pub user: String,
+            #[serde(skip_serializing)]
+            pub password: String,
+        }
+    "#;
+
+    let ParsedTypes { structs, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    // Never sent to the frontend, but still accepted from it
+    let password = &structs[0].fields[1];
+    assert_eq!(password.name, "password");
+    assert!(password.use_optional);
+    assert!(!structs[0].fields[0].use_optional);
 }
 
 #[test]
@@ -846,3 +990,150 @@ fn test_parse_serde_flatten_with_other_attrs() {
 
     assert!(outer.fields[1].is_flatten);
 }
+
+#[test]
+fn test_serde_with_rfc3339_keeps_string_mapping() {
+    let code = r#"
+        #[derive(Serialize)]
+        pub struct Event {
+            #[serde(with = "time::serde::rfc3339")]
+            pub at: OffsetDateTime,
+            #[serde(with = "time::serde::rfc3339::option")]
+            pub ended: Option<OffsetDateTime>,
+        }
+    "#;
+
+    let ParsedTypes { structs, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let fields = &structs[0].fields;
+
+    assert_eq!(
+        fields[0].ty,
+        RustType::Primitive("OffsetDateTime".to_string())
+    );
+    assert_eq!(
+        fields[1].ty,
+        RustType::Option(Box::new(RustType::Primitive("OffsetDateTime".to_string())))
+    );
+}
+
+#[test]
+fn test_serde_with_timestamp_maps_to_number() {
+    let code = r#"
+        #[derive(Serialize)]
+        pub struct Event {
+            #[serde(with = "time::serde::timestamp")]
+            pub at: OffsetDateTime,
+            #[serde(with = "time::serde::timestamp::option")]
+            pub ended: Option<OffsetDateTime>,
+            #[serde(with = "time::serde::timestamp")]
+            pub label: String,
+        }
+    "#;
+
+    let ParsedTypes { structs, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let fields = &structs[0].fields;
+
+    assert_eq!(fields[0].ty, RustType::Primitive("i64".to_string()));
+    assert_eq!(
+        fields[1].ty,
+        RustType::Option(Box::new(RustType::Primitive("i64".to_string())))
+    );
+    // Only date-time types are affected.
+    assert_eq!(fields[2].ty, RustType::Primitive("String".to_string()));
+}
+
+#[test]
+fn test_parse_doc_comments() {
+    let code = r#"
+        /// A user account.
+        ///
+        /// Created on sign-up.
+        #[derive(Serialize)]
+        pub struct User {
+            /// Primary key.
+            pub id: i32,
+            pub name: String,
+        }
+
+        /// Access level.
+        #[derive(Serialize)]
+        pub enum Role {
+            Admin {
+                /// Granted by.
+                by: String,
+            },
+        }
+    "#;
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+
+    let user = &structs[0];
+    assert_eq!(
+        user.docs.as_deref(),
+        Some("A user account.\n\nCreated on sign-up.")
+    );
+    assert_eq!(user.fields[0].docs.as_deref(), Some("Primary key."));
+    assert_eq!(user.fields[1].docs, None);
+
+    assert_eq!(enums[0].docs.as_deref(), Some("Access level."));
+    match &enums[0].variants[0].data {
+        VariantData::Struct(fields) => assert_eq!(fields[0].docs.as_deref(), Some("Granted by.")),
+        other => panic!("expected struct variant, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_rename_all_and_rename_all_fields_apply_independently() {
+    let code = r#"
+        #[derive(Serialize)]
+        #[serde(tag = "type", rename_all = "snake_case", rename_all_fields = "camelCase")]
+        pub enum Event {
+            UserJoined { user_id: u32, display_name: String },
+            #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+            UserLeft { user_id: u32 },
+            Heartbeat,
+        }
+    "#;
+
+    let ParsedTypes { enums, .. } = parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let event = &enums[0];
+
+    let names: Vec<_> = event.variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(names, ["user_joined", "user_left", "heartbeat"]);
+
+    let field_names = |i: usize| match &event.variants[i].data {
+        VariantData::Struct(fields) => fields.iter().map(|f| f.name.clone()).collect::<Vec<_>>(),
+        other => panic!("Expected Struct variant, got {:?}", other),
+    };
+    assert_eq!(field_names(0), ["userId", "displayName"]);
+    // The variant's own rename_all wins over the container's rename_all_fields.
+    assert_eq!(field_names(1), ["USER_ID"]);
+}
+
+#[test]
+fn test_serde_repr_enum_captures_discriminants() {
+    let code = r#"
+        #[derive(Serialize_repr)]
+        #[repr(i32)]
+        pub enum Level {
+            Low = 1,
+            Next,
+            Negative = -(4),
+            #[serde(rename = "ignored")]
+            Computed = BASE + 1,
+            AfterComputed,
+        }
+    "#;
+
+    let ParsedTypes { enums, .. } = parse_types(code, &test_path(), ParseOptions::SOURCE).unwrap();
+    let level = &enums[0];
+
+    assert_eq!(level.representation, EnumRepresentation::Numeric);
+    let values: Vec<_> = level.variants.iter().map(|v| v.discriminant).collect();
+    assert_eq!(values, [Some(1), Some(2), Some(-4), None, None]);
+    // serde_repr ignores renames; the Rust name is kept
+    assert_eq!(level.variants[3].name, "Computed");
+}
@@ -3,86 +3,405 @@
 //! Flow: Scan -> Parse -> Resolve -> Collect -> Generate
 
 pub mod collect;
+mod explain;
+mod inline_aliases;
+mod since;
+mod watch;
 
 use anyhow::{Context, Result};
+use rayon::prelude::*;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use crate::cargo_expand::{find_cargo_manifest, run_cargo_expand};
 use crate::config::Config;
-use crate::diagnostics::Diagnostics;
+use crate::diagnostics::{error_line, AnnotationFormat, Diagnostics, Report};
 use crate::generator::{
-    commands_gen::{collect_channel_type_aliases, generate_commands_file},
-    types_gen::generate_types_file,
-    GeneratorContext,
+    backend::{self, Bindings},
+    commands_gen::command_function_name,
+    type_mapper, GeneratorContext,
 };
 use crate::known_types;
-use crate::models::{RustEnum, RustStruct, RustType, RustTypeAlias, TauriCommand};
+use crate::models::{RustType, TauriCommand, TauriEvent};
 use crate::parser::{
-    parse_commands, parse_expanded_commands, parse_types, ParseOptions, ParsedTypes,
+    parse_commands_with, parse_events, parse_expanded_commands, parse_types, CommandParseOptions,
+    ParseOptions, ParsedTypes,
 };
-use crate::resolver::ModuleResolver;
+use crate::resolver::{FileScope, ModuleResolver};
 use crate::scanner::Scanner;
+use crate::workspace::{path_dependencies, PathDependency};
 
 pub use collect::TypeCollectionResult;
 
+/// Which of the two output files a run writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputSelection {
+    /// Write both `types_file` and `commands_file`
+    #[default]
+    All,
+    /// Write `types_file` only; `commands_file` is left untouched
+    TypesOnly,
+    /// Write `commands_file` only; `types_file` is left untouched
+    CommandsOnly,
+}
+
+impl OutputSelection {
+    fn writes_types(self) -> bool {
+        self != OutputSelection::CommandsOnly
+    }
+
+    fn writes_commands(self) -> bool {
+        self != OutputSelection::TypesOnly
+    }
+}
+
+/// Output of steps 1-3: everything known about the project before any
+/// conflict is treated as fatal.
+struct Analysis {
+    commands: Vec<TauriCommand>,
+    events: Vec<TauriEvent>,
+    resolver: ModuleResolver,
+    type_collection: collect::TypeCollectionResult,
+}
+
+/// Output files rendered in memory by
+/// [`Pipeline::generate_strings`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedOutput {
+    /// Path and content of every file [`Pipeline::run`] would write,
+    /// in the order it writes them
+    pub files: Vec<(PathBuf, String)>,
+}
+
+/// Every file after a `// ==> path <==` line, the way `generate --stdout`
+/// prints them
+impl std::fmt::Display for GeneratedOutput {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, (path, content)) in self.files.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "// ==> {} <==", path.display())?;
+            f.write_str(content)?;
+        }
+        Ok(())
+    }
+}
+
+impl GeneratedOutput {
+    /// Content [`Pipeline::run`] would write to `path`, as configured in
+    /// `[output]`
+    pub fn file(&self, path: &Path) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|(file, _)| file == path)
+            .map(|(_, content)| content.as_str())
+    }
+}
+
+/// Rendered contents of the output files
+struct GeneratedFiles {
+    bindings: BindingFiles,
+    /// `command_map_file`, when configured
+    command_map: Option<String>,
+    /// `zod_file`, when configured
+    zod: Option<String>,
+    /// `events_file`, when configured
+    events: Option<String>,
+}
+
+/// Rendered types and commands
+enum BindingFiles {
+    /// `types_file` and `commands_file`
+    Split { types: String, commands: String },
+    /// `single_file`, holding both
+    Single(String),
+}
+
+/// One rendered output file and which parts of the bindings it holds
+struct OutputFile<'a> {
+    path: &'a Path,
+    content: &'a str,
+    holds: since::Affected,
+}
+
+impl GeneratedFiles {
+    fn files<'a>(&'a self, config: &'a Config) -> Vec<OutputFile<'a>> {
+        let mut files = match &self.bindings {
+            BindingFiles::Split { types, commands } => vec![
+                OutputFile {
+                    path: &config.output.types_file,
+                    content: types,
+                    holds: since::Affected {
+                        types: true,
+                        commands: false,
+                    },
+                },
+                OutputFile {
+                    path: &config.output.commands_file,
+                    content: commands,
+                    holds: since::Affected {
+                        types: false,
+                        commands: true,
+                    },
+                },
+            ],
+            BindingFiles::Single(content) => vec![OutputFile {
+                path: config.output.files()[0],
+                content,
+                holds: since::Affected::ALL,
+            }],
+        };
+        if let (Some(path), Some(content)) = (&config.output.command_map_file, &self.command_map) {
+            // Argument and result types both live in the map
+            files.push(OutputFile {
+                path,
+                content,
+                holds: since::Affected::ALL,
+            });
+        }
+        if let (Some(path), Some(content)) = (&config.output.zod_file, &self.zod) {
+            // Type schemas, and the args schemas command functions import
+            files.push(OutputFile {
+                path,
+                content,
+                holds: since::Affected::ALL,
+            });
+        }
+        if let (Some(path), Some(content)) = (&config.output.events_file, &self.events) {
+            // Payload types, but no command
+            files.push(OutputFile {
+                path,
+                content,
+                holds: since::Affected {
+                    types: true,
+                    commands: false,
+                },
+            });
+        }
+        files
+    }
+}
+
+/// Record the module path of every file defining a command, which
+/// function naming and grouping read
+fn register_command_modules(
+    ctx: &mut GeneratorContext,
+    resolver: &ModuleResolver,
+    commands: &[TauriCommand],
+) {
+    for cmd in commands {
+        if let Some(scope) = resolver.file_scope(&cmd.source_file) {
+            ctx.register_module(&cmd.source_file, &scope.module_path);
+        }
+    }
+}
+
+/// What the parallel pass of step 2 extracts from one source file
+struct ParsedFile {
+    scope: Result<FileScope>,
+    commands: Result<Vec<TauriCommand>>,
+    events: Result<Vec<TauriEvent>>,
+    /// Warnings raised while parsing, reported in the merge
+    diagnostics: Vec<Report>,
+}
+
+/// Output of step 2
+struct ParsedSources {
+    commands: Vec<TauriCommand>,
+    events: Vec<TauriEvent>,
+    resolver: ModuleResolver,
+    /// Types found in `cargo expand` output, registered in `resolver`
+    /// only where no source file defines them
+    expanded_types: Option<ParsedTypes>,
+}
+
 /// Main pipeline for code generation
 pub struct Pipeline {
     diag: Diagnostics,
+    selection: OutputSelection,
+    strict: bool,
 }
 
 impl Pipeline {
     pub fn new(verbose: bool) -> Self {
         Self {
             diag: Diagnostics::new(verbose),
+            selection: OutputSelection::All,
+            strict: false,
         }
     }
 
+    /// Fail when a command uses a type that can't be resolved, instead of
+    /// warning and typing it as `unknown`.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Write warnings and errors as `annotations`, e.g. GitHub Actions
+    /// workflow commands.
+    pub fn with_annotations(mut self, annotations: AnnotationFormat) -> Self {
+        self.diag = self.diag.with_annotations(annotations);
+        self
+    }
+
+    /// Write status lines ("Parsed N commands", ...) to stderr, leaving
+    /// stdout to the output of [`generate_strings`](Self::generate_strings).
+    pub fn with_status_on_stderr(mut self) -> Self {
+        self.diag = self.diag.with_status_on_stderr();
+        self
+    }
+
+    /// Restrict which output files are written. Everything upstream of
+    /// the write (scan, resolve, collect) still runs — the commands decide
+    /// which types are reachable even when only the types file is wanted.
+    pub fn with_output_selection(mut self, selection: OutputSelection) -> Self {
+        self.selection = selection;
+        self
+    }
+
     /// Run the full generation pipeline
     pub fn run(&self, config: &Config) -> Result<()> {
-        // Install the ambient Diagnostics sink for any helper that can't
-        // easily take a `&Diagnostics` parameter (the serde-attr walkers,
-        // the type-mapper's Unknown fallbacks, etc.).
-        crate::diagnostics::install(self.diag);
+        let generated = self.render(config)?;
+        self.write_output(config, &generated, since::Affected::ALL)?;
+
+        self.diag.info("Done!");
+
+        Ok(())
+    }
 
+    /// Run the generation pipeline and return the output files instead
+    /// of writing them, for build scripts and other tools embedding the
+    /// generator. Only the files selected by `OutputSelection` are
+    /// returned.
+    ///
+    /// ```
+    /// use tauri_ts_generator::config::Config;
+    /// use tauri_ts_generator::pipeline::Pipeline;
+    ///
+    /// # fn main() -> anyhow::Result<()> {
+    /// let dir = tempfile::tempdir()?;
+    /// std::fs::write(
+    ///     dir.path().join("lib.rs"),
+    ///     "#[tauri::command]\npub fn greet(name: String) -> String { name }\n",
+    /// )?;
+    ///
+    /// let mut config = Config::default_config();
+    /// config.input.source_dir = dir.path().to_path_buf();
+    ///
+    /// let output = Pipeline::new(false).generate_strings(&config)?;
+    /// let commands = output.file(&config.output.commands_file).unwrap();
+    /// assert!(commands.contains("export async function greet(name: string): Promise<string>"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn generate_strings(&self, config: &Config) -> Result<GeneratedOutput> {
+        let generated = self.render(config)?;
+        let files = generated
+            .files(config)
+            .into_iter()
+            .filter(|file| self.selected(file, since::Affected::ALL))
+            .map(|file| (file.path.to_path_buf(), file.content.to_string()))
+            .collect();
+        Ok(GeneratedOutput { files })
+    }
+
+    /// Like [`run`](Self::run), but only rewrite the output files that
+    /// `.rs` files changed since `git_ref` can affect. An output that does
+    /// not exist yet is always written.
+    pub fn run_since(&self, config: &Config, git_ref: &str) -> Result<()> {
+        let changes = since::changed_files(&config.input.source_dir, git_ref)?;
         self.diag.debug(format!(
-            "Scanning directory: {}",
-            config.input.source_dir.display()
+            "{} Rust file(s) changed since {}",
+            changes.len(),
+            git_ref
         ));
+        self.run_with_changes(config, &changes)
+    }
 
-        // Step 1: Scan for Rust files
-        let rust_files = self.scan_files(config)?;
+    fn run_with_changes(&self, config: &Config, changes: &[since::ChangedFile]) -> Result<()> {
+        let analysis = self.analyze(config)?;
+        let mut affected =
+            since::affected_outputs(changes, &analysis.commands, &analysis.type_collection);
 
-        self.diag
-            .debug(format!("Found {} Rust files", rust_files.len()));
+        let generated = self.render_analysis(config, analysis)?;
+        for file in generated.files(config) {
+            if !file.path.exists() {
+                affected.types |= file.holds.types;
+                affected.commands |= file.holds.commands;
+            }
+        }
+        self.write_output(config, &generated, affected)?;
 
-        // Step 1.5: Optionally run cargo expand for macro-generated types
-        let expanded_code = if config.input.use_cargo_expand {
-            self.run_cargo_expand(config)?
-        } else {
-            None
-        };
+        if !affected.types && !affected.commands {
+            self.diag
+                .info("No changed file affects the generated output; nothing written.");
+        }
+        self.diag.info("Done!");
 
-        // Step 2: Parse all files and build resolver
-        let (mut commands, resolver, expanded_types) =
-            self.parse_files(&rust_files, config, expanded_code.as_deref())?;
+        Ok(())
+    }
 
-        // Step 2.5: Filter out Tauri special types (State, Window, etc.) including aliases
-        self.filter_tauri_special_args(&mut commands, &resolver);
+    /// Run the pipeline without writing anything and fail if either
+    /// output file on disk differs from what `run` would produce.
+    pub fn check(&self, config: &Config) -> Result<()> {
+        let generated = self.render(config)?;
 
-        // Step 2.6: Detect two `#[tauri::command]` functions with the same
-        // name. JavaScript callers do `invoke("name", …)` — if two commands
-        // share a name the generated TS would define the function twice and
-        // the Rust backend only ever routes to one of them. Fail loudly.
-        self.check_duplicate_command_names(&commands)?;
+        let stale: Vec<_> = generated
+            .files(config)
+            .into_iter()
+            .filter_map(|file| staleness(file.path, file.content).map(|reason| (file.path, reason)))
+            .collect();
 
-        // Step 3: Collect and resolve types used in commands
-        let type_collection = collect::collect_reachable_types(
-            &commands,
-            &resolver,
-            expanded_types.as_ref(),
-            &self.diag,
+        if stale.is_empty() {
+            self.diag.info("Generated files are up to date.");
+            return Ok(());
+        }
+
+        self.diag.error("Generated files are out of date:");
+        for (path, reason) in &stale {
+            self.diag
+                .error_in(path, None, format!("  {}: {}", path.display(), reason));
+        }
+        anyhow::bail!(
+            "{} generated file(s) are stale. Run `tauri-ts-generator generate` to update them.",
+            stale.len()
         );
+    }
+
+    /// Run steps 1-3 and report how `type_name` was found: its candidate
+    /// definitions, how each use site resolved it, and whether it ends up
+    /// exported. Writes nothing.
+    pub fn explain(&self, config: &Config, type_name: &str) -> Result<()> {
+        let analysis = self.analyze(config)?;
+        for line in explain::explain_type(
+            type_name,
+            &analysis.commands,
+            &analysis.resolver,
+            &analysis.type_collection,
+        ) {
+            self.diag.info(line);
+        }
+        Ok(())
+    }
+
+    /// Steps 1-6: scan, parse, resolve and render both output files
+    /// in memory.
+    fn render(&self, config: &Config) -> Result<GeneratedFiles> {
+        let analysis = self.analyze(config)?;
+        self.render_analysis(config, analysis)
+    }
+
+    /// Steps 4-6 on an existing analysis.
+    fn render_analysis(&self, config: &Config, analysis: Analysis) -> Result<GeneratedFiles> {
+        let Analysis {
+            mut commands,
+            events,
+            resolver,
+            mut type_collection,
+        } = analysis;
 
         // Step 4: Check for conflicts
         if !type_collection.conflicts.is_empty() {
@@ -93,7 +412,8 @@ impl Pipeline {
                     type_name
                 ));
                 for file in files {
-                    self.diag.error(format!("    - {}", file.display()));
+                    self.diag
+                        .error_in(file, None, format!("    - {}", file.display()));
                 }
             }
             anyhow::bail!(
@@ -102,19 +422,40 @@ impl Pipeline {
             );
         }
 
-        // Step 4.5: Warn about unresolved types (likely macro-generated)
+        // Step 4.5: Report unresolved types (macro-generated, or from
+        // another crate). They are typed as `unknown` unless `--strict`
         if !type_collection.unresolved.is_empty() {
-            self.diag.warn(format!(
-                "{} type(s) could not be resolved and will not be generated:",
-                type_collection.unresolved.len()
-            ));
-            for (type_name, used_in) in &type_collection.unresolved {
+            let mut unresolved: Vec<_> = type_collection.unresolved.iter().collect();
+            unresolved.sort_by(|a, b| a.0.cmp(b.0));
+            let report = |file: &Path, msg: String| {
+                if self.strict {
+                    self.diag.error_in(file, None, msg);
+                } else {
+                    self.diag.warn_in(file, None, msg);
+                }
+            };
+            if self.strict {
+                self.diag.error(format!(
+                    "{} type(s) could not be resolved:",
+                    unresolved.len()
+                ));
+            } else {
                 self.diag.warn(format!(
-                    "  - '{}' (used in {})",
-                    type_name,
-                    used_in.display()
+                    "{} type(s) could not be resolved and will be typed as `unknown`:",
+                    unresolved.len()
                 ));
             }
+            for (type_name, usage) in &unresolved {
+                report(
+                    &usage.file,
+                    format!(
+                        "  - '{}' (used by {} in {})",
+                        type_name,
+                        usage.used_by,
+                        usage.file.display()
+                    ),
+                );
+            }
             if !config.input.use_cargo_expand {
                 self.diag.info("");
                 self.diag.info(
@@ -126,6 +467,12 @@ impl Pipeline {
                 self.diag.info("        use_cargo_expand = true");
                 self.diag.info("");
             }
+            if self.strict {
+                anyhow::bail!(
+                    "Found {} unresolved type(s). Define them in a scanned file, or drop --strict to type them as `unknown`.",
+                    unresolved.len()
+                );
+            }
         }
 
         // Summary
@@ -137,27 +484,187 @@ impl Pipeline {
             type_collection.aliases.len()
         ));
 
-        // Step 6: Generate TypeScript files
-        self.generate_output(
+        // Step 5: Substitute aliases at their use sites instead of
+        // exporting them
+        if config.generation.inline_type_aliases {
+            inline_aliases::inline_type_aliases(
+                &mut commands,
+                &mut type_collection.structs,
+                &mut type_collection.enums,
+                &type_collection.aliases,
+            );
+            type_collection.aliases.clear();
+        }
+
+        // Step 6: Generate the output files
+        Ok(self.generate_output(
             config,
-            &commands,
-            &type_collection.structs,
-            &type_collection.enums,
-            &type_collection.aliases,
+            &resolver,
+            Bindings {
+                structs: &type_collection.structs,
+                enums: &type_collection.enums,
+                aliases: &type_collection.aliases,
+                commands: &commands,
+                events: &events,
+            },
+        ))
+    }
+
+    /// Steps 1-3: scan, parse and collect every type reachable from a
+    /// command.
+    fn analyze(&self, config: &Config) -> Result<Analysis> {
+        // Install the ambient Diagnostics sink for any helper that can't
+        // easily take a `&Diagnostics` parameter (the serde-attr walkers,
+        // the type-mapper's Unknown fallbacks, etc.).
+        crate::diagnostics::install(self.diag);
+
+        self.diag.debug(format!(
+            "Scanning directory: {}",
+            config.input.source_dir.display()
+        ));
+
+        // Step 1: Scan for Rust files
+        let rust_files = self.scan_files(config)?;
+
+        self.diag
+            .debug(format!("Found {} Rust files", rust_files.len()));
+
+        // Step 1.2: Optionally scan path dependencies, whose types commands
+        // may use
+        let dependency_files = if config.input.path_dependencies {
+            self.scan_path_dependencies(config)?
+        } else {
+            Vec::new()
+        };
+
+        // Step 1.5: Optionally run cargo expand for macro-generated types
+        let expanded_code = if config.input.use_cargo_expand {
+            self.run_cargo_expand(config)?
+        } else {
+            None
+        };
+
+        // Step 2: Parse all files and build resolver
+        let ParsedSources {
+            mut commands,
+            events,
+            resolver,
+            expanded_types,
+        } = self.parse_files(
+            &rust_files,
+            &dependency_files,
+            config,
+            expanded_code.as_deref(),
         )?;
 
-        self.diag.info("Done!");
+        // Step 2.5: Filter out Tauri special types (State, Window, etc.) including aliases
+        self.filter_tauri_special_args(&mut commands, &resolver);
 
-        Ok(())
+        // Step 2.6: Detect two `#[tauri::command]` functions with the same
+        // name. JavaScript callers do `invoke("name", …)` — if two commands
+        // share a name the generated TS would define the function twice and
+        // the Rust backend only ever routes to one of them. Fail loudly.
+        self.check_duplicate_command_names(&commands)?;
+
+        // Step 2.7: Distinct Rust names can still land on the same TS
+        // function once camelCased (`get_user` and `getUser`).
+        self.check_function_name_collisions(&commands, &resolver, config)?;
+
+        // Step 3: Collect and resolve types used in commands, and in
+        // event payloads when `events_file` is configured
+        let events = if config.output.events_file.is_some() {
+            events
+        } else {
+            Vec::new()
+        };
+        let type_collection = collect::collect_reachable_types(
+            &commands,
+            &events,
+            &resolver,
+            expanded_types.as_ref(),
+            collect::CollectOptions {
+                include_error_types: config.generation.export_error_types,
+                external_prefixes: &config.input.external_crate_prefixes,
+                active_features: &config.input.active_features,
+            },
+            &self.diag,
+        );
+
+        Ok(Analysis {
+            commands,
+            events,
+            resolver,
+            type_collection,
+        })
     }
 
     /// Step 1: Scan for Rust files
     fn scan_files(&self, config: &Config) -> Result<Vec<PathBuf>> {
-        let scanner = Scanner::new(
+        self.scanner(config)?.scan()
+    }
+
+    /// The scanner for `config`'s source tree, with the output files
+    /// excluded.
+    fn scanner(&self, config: &Config) -> Result<Scanner> {
+        let mut scanner = Scanner::new(
             config.input.source_dir.clone(),
             config.input.exclude.clone(),
-        );
-        scanner.scan()
+        )
+        .include(&config.input.include)?
+        .follow_symlinks(config.input.follow_symlinks);
+        // Never scan our own output when it lives inside source_dir.
+        for file in config.output_files_in_source() {
+            self.diag.debug(format!(
+                "Excluding output file from scan: {}",
+                file.display()
+            ));
+            scanner = scanner.exclude_path(file);
+        }
+        Ok(scanner)
+    }
+
+    /// Step 1.2: Scan the `src` of every path dependency in the crate's
+    /// manifest
+    fn scan_path_dependencies(
+        &self,
+        config: &Config,
+    ) -> Result<Vec<(PathDependency, Vec<PathBuf>)>> {
+        let manifest = config
+            .input
+            .cargo_manifest
+            .clone()
+            .or_else(|| find_cargo_manifest(&config.input.source_dir));
+        let Some(manifest) = manifest else {
+            self.diag
+                .warn("Could not find Cargo.toml to read path dependencies from.");
+            return Ok(Vec::new());
+        };
+
+        let mut scanned = Vec::new();
+        for dependency in path_dependencies(&manifest)? {
+            if !dependency.source_dir.is_dir() {
+                self.diag.warn_in(
+                    &manifest,
+                    None,
+                    format!(
+                        "Path dependency '{}' has no source directory at {}",
+                        dependency.name,
+                        dependency.source_dir.display()
+                    ),
+                );
+                continue;
+            }
+            let files = Scanner::new(dependency.source_dir.clone(), config.input.exclude.clone())
+                .follow_symlinks(config.input.follow_symlinks)
+                .scan()?;
+            self.diag.debug(format!(
+                "Found {} Rust files in path dependency '{}'",
+                files.len(),
+                dependency.name
+            ));
+            scanned.push((dependency, files));
+        }
+        Ok(scanned)
     }
 
     /// Step 1.5: Run cargo expand if enabled
@@ -208,12 +715,31 @@ impl Pipeline {
     fn parse_files(
         &self,
         rust_files: &[PathBuf],
+        dependency_files: &[(PathDependency, Vec<PathBuf>)],
         config: &Config,
         expanded_code: Option<&str>,
-    ) -> Result<(Vec<TauriCommand>, ModuleResolver, Option<ParsedTypes>)> {
+    ) -> Result<ParsedSources> {
         let mut resolver = ModuleResolver::new();
         let base_path = config.input.source_dir.clone();
         let mut commands: Vec<TauriCommand> = Vec::new();
+        let mut events: Vec<TauriEvent> = Vec::new();
+
+        // Path dependencies only contribute types: register their files
+        // under the dependency's name, and skip their commands
+        for (dependency, files) in dependency_files {
+            for file_path in files {
+                let content = fs::read_to_string(file_path)
+                    .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+                match ModuleResolver::scan_file(file_path, &content, &dependency.source_dir) {
+                    Ok(scope) => resolver.add_file(file_path, scope.in_crate(&dependency.name)),
+                    Err(e) => self.diag.warn_in(
+                        file_path,
+                        error_line(&e),
+                        format!("Failed to parse {}: {}", file_path.display(), e),
+                    ),
+                }
+            }
+        }
 
         // Store expanded types temporarily - we'll register them AFTER parsing source files
         let mut expanded_types: Option<ParsedTypes> = None;
@@ -242,22 +768,60 @@ impl Pipeline {
             }
         }
 
-        // First, parse all source files - this registers types in the resolver
-        for file_path in rust_files {
-            let content = fs::read_to_string(file_path)
-                .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+        let command_options = CommandParseOptions {
+            include_tests: config.input.include_test_commands,
+            extra_attributes: config.input.command_attributes.clone(),
+        };
+
+        // First, read and parse every source file. Files are independent,
+        // so this runs in parallel; `collect` keeps the (sorted) scan
+        // order, and the merge below is sequential, so the result does not
+        // depend on scheduling. Warnings are buffered per file and
+        // reported in the merge for the same reason.
+        let diag = self.diag;
+        let parsed: Vec<Result<ParsedFile>> = rust_files
+            .par_iter()
+            .map(|file_path| {
+                crate::diagnostics::install(diag);
+                let content = fs::read_to_string(file_path)
+                    .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+                let ((scope, commands, events), diagnostics) = crate::diagnostics::buffered(|| {
+                    (
+                        ModuleResolver::scan_file(file_path, &content, &base_path),
+                        // `#[cfg(test)]` commands only when asked for
+                        parse_commands_with(&content, file_path, &command_options),
+                        parse_events(&content, file_path),
+                    )
+                });
+                Ok(ParsedFile {
+                    scope,
+                    commands,
+                    events,
+                    diagnostics,
+                })
+            })
+            .collect();
+
+        // Register the scopes in the resolver and gather the commands
+        for (file_path, parsed) in rust_files.iter().zip(parsed) {
+            let ParsedFile {
+                scope,
+                commands: parsed_commands,
+                events: parsed_events,
+                diagnostics,
+            } = parsed?;
+            self.diag.replay(diagnostics);
 
-            // Build resolver scope for this file
-            if let Err(e) = resolver.parse_file(file_path, &content, &base_path) {
-                self.diag.debug(format!(
+            match scope {
+                Ok(scope) => resolver.add_file(file_path, scope),
+                Err(e) => self.diag.debug(format!(
                     "Failed to parse imports in {}: {}",
                     file_path.display(),
                     e
-                ));
+                )),
             }
 
-            // Parse commands
-            match parse_commands(&content, file_path) {
+            match parsed_commands {
                 Ok(file_commands) => {
                     if !file_commands.is_empty() {
                         self.diag.debug(format!(
@@ -269,13 +833,16 @@ impl Pipeline {
                     commands.extend(file_commands);
                 }
                 Err(e) => {
-                    self.diag.warn(format!(
-                        "Failed to parse commands in {}: {}",
-                        file_path.display(),
-                        e
-                    ));
+                    self.diag.warn_in(
+                        file_path,
+                        error_line(&e),
+                        format!("Failed to parse commands in {}: {}", file_path.display(), e),
+                    );
                 }
             }
+
+            // A file that fails to parse was already reported above
+            events.extend(parsed_events.unwrap_or_default());
         }
 
         // Pick up macro-generated #[tauri::command] functions from
@@ -342,79 +909,90 @@ impl Pipeline {
             }
         }
 
-        Ok((commands, resolver, expanded_types))
+        Ok(ParsedSources {
+            commands,
+            events,
+            resolver,
+            expanded_types,
+        })
     }
 
-    /// Step 6: Generate TypeScript output files
+    /// Step 6: Generate the output files with the `output.target` backend
     fn generate_output(
         &self,
         config: &Config,
-        commands: &[TauriCommand],
-        filtered_structs: &[RustStruct],
-        filtered_enums: &[RustEnum],
-        aliases: &[RustTypeAlias],
-    ) -> Result<()> {
-        // Ensure output directories exist before writing files
-        config.ensure_output_directories()?;
-
+        resolver: &ModuleResolver,
+        bindings: Bindings,
+    ) -> GeneratedFiles {
         // Create generator context
-        let mut ctx = GeneratorContext::new(config.naming.clone());
+        let mut ctx =
+            GeneratorContext::with_generation(config.naming.clone(), config.generation.clone())
+                .with_style(config.output.style.clone())
+                .with_banner(config.output.banner(SystemTime::now()))
+                .with_types_namespace(config.output.types_namespace.clone())
+                .with_args_schemas(config.output.zod_file.clone());
 
-        for s in filtered_structs {
+        for s in bindings.structs {
             ctx.register_type(&s.name);
         }
-        for e in filtered_enums {
+        for e in bindings.enums {
             ctx.register_enum(&e.name);
         }
-        for alias in aliases {
+        for alias in bindings.aliases {
             ctx.register_type(&alias.name);
         }
+        ctx.register_key_types(bindings.structs, bindings.enums, bindings.aliases);
+        type_mapper::warn_unkeyable_map_keys(&bindings, &ctx);
+        register_command_modules(&mut ctx, resolver, bindings.commands);
+        let backend = backend::for_target(&config.output, ctx);
+        let files = if config.output.single_file.is_some() {
+            BindingFiles::Single(backend.single_file(&bindings))
+        } else {
+            BindingFiles::Split {
+                types: backend.types_file(&bindings),
+                commands: backend.commands_file(&bindings),
+            }
+        };
 
-        let channel_aliases = collect_channel_type_aliases(commands, &ctx);
-
-        // Generate types.ts — append channel type aliases at the end
-        let mut types_content =
-            generate_types_file(filtered_structs, filtered_enums, aliases, &ctx);
-        for (alias_name, inner_ts) in &channel_aliases {
-            types_content.push_str(&format!("export type {} = {};\n", alias_name, inner_ts));
-        }
-        if !channel_aliases.is_empty() {
-            types_content.push('\n');
+        GeneratedFiles {
+            bindings: files,
+            command_map: backend.command_map_file(&bindings),
+            zod: backend.zod_file(&bindings),
+            events: backend.events_file(&bindings),
         }
+    }
 
-        fs::write(&config.output.types_file, &types_content).with_context(|| {
-            format!(
-                "Failed to write types file: {}",
-                config.output.types_file.display()
-            )
-        })?;
-
-        self.diag
-            .info(format!("Generated: {}", config.output.types_file.display()));
-
-        // Generate commands.ts
-        let commands_content = generate_commands_file(
-            commands,
-            &config.output.types_file,
-            &config.output.commands_file,
-            &ctx,
-        );
+    /// Step 7: Write the rendered files selected by `OutputSelection`
+    fn write_output(
+        &self,
+        config: &Config,
+        generated: &GeneratedFiles,
+        affected: since::Affected,
+    ) -> Result<()> {
+        // Ensure output directories exist before writing files
+        config.ensure_output_directories()?;
 
-        fs::write(&config.output.commands_file, &commands_content).with_context(|| {
-            format!(
-                "Failed to write commands file: {}",
-                config.output.commands_file.display()
-            )
-        })?;
+        for file in generated.files(config) {
+            if !self.selected(&file, affected) {
+                continue;
+            }
+            fs::write(file.path, file.content)
+                .with_context(|| format!("Failed to write output file: {}", file.path.display()))?;
 
-        self.diag.info(format!(
-            "Generated: {}",
-            config.output.commands_file.display()
-        ));
+            self.diag
+                .info(format!("Generated: {}", file.path.display()));
+        }
 
         Ok(())
     }
 
+    /// Whether `file` is written under `OutputSelection`, given which parts
+    /// of the bindings are `affected`
+    fn selected(&self, file: &OutputFile, affected: since::Affected) -> bool {
+        (file.holds.types && self.selection.writes_types() && affected.types)
+            || (file.holds.commands && self.selection.writes_commands() && affected.commands)
+    }
+
     /// Step 2.6: detect duplicate `#[tauri::command]` names across the
     /// project. Reports every colliding source file pair-wise so the user
     /// can find them without re-scanning the tree by hand.
@@ -447,7 +1025,8 @@ impl Pipeline {
             self.diag
                 .error(format!("  '{}' is defined in {} files:", name, files.len()));
             for file in *files {
-                self.diag.error(format!("    - {}", file.display()));
+                self.diag
+                    .error_in(file, None, format!("    - {}", file.display()));
             }
         }
         anyhow::bail!(
@@ -456,6 +1035,56 @@ impl Pipeline {
         );
     }
 
+    /// Step 2.7: detect commands whose invoke names differ but whose
+    /// generated TypeScript function names are identical, which would
+    /// produce two declarations of the same function in `commands.ts`.
+    fn check_function_name_collisions(
+        &self,
+        commands: &[TauriCommand],
+        resolver: &ModuleResolver,
+        config: &Config,
+    ) -> Result<()> {
+        use std::collections::BTreeMap;
+
+        let mut ctx =
+            GeneratorContext::with_generation(config.naming.clone(), config.generation.clone());
+        register_command_modules(&mut ctx, resolver, commands);
+        let mut by_fn_name: BTreeMap<String, Vec<&TauriCommand>> = BTreeMap::new();
+        for cmd in commands {
+            by_fn_name
+                .entry(command_function_name(cmd, &ctx))
+                .or_default()
+                .push(cmd);
+        }
+
+        let collisions: Vec<_> = by_fn_name
+            .iter()
+            .filter(|(_, cmds)| cmds.len() > 1)
+            .collect();
+
+        if collisions.is_empty() {
+            return Ok(());
+        }
+
+        self.diag
+            .error("Commands collide after conversion to TypeScript function names:");
+        for (fn_name, cmds) in &collisions {
+            self.diag
+                .error(format!("  '{}' would be generated by:", fn_name));
+            for cmd in *cmds {
+                self.diag.error_in(
+                    &cmd.source_file,
+                    None,
+                    format!("    - '{}' in {}", cmd.name, cmd.source_file.display()),
+                );
+            }
+        }
+        anyhow::bail!(
+            "Found {} TypeScript function name collision(s). Rename one of the Rust functions so their camelCase names differ.",
+            collisions.len()
+        );
+    }
+
     /// Step 2.5: Filter out Tauri special types from command arguments
     /// This handles both direct uses (State<T>) and type aliases (type MyState = State<T>)
     fn filter_tauri_special_args(
@@ -477,6 +1106,21 @@ impl Pipeline {
                     return false;
                 }
 
+                // Check if it's a renamed import of a Tauri special type
+                // (`use tauri::WebviewWindow as MainWindow;`)
+                let renamed_import = resolver
+                    .file_scope(&cmd.source_file)
+                    .and_then(|scope| scope.imports.get(&type_name))
+                    .and_then(|imported| match imported.path.as_slice() {
+                        [krate, .., original] if krate == "tauri" => Some(original),
+                        _ => None,
+                    });
+                if renamed_import
+                    .is_some_and(|original| known_types::is_tauri_special_type(original))
+                {
+                    return false;
+                }
+
                 // Check if it's an alias to a Tauri special type
                 if let Some(target) = resolver.resolve_alias_target(&type_name, &cmd.source_file) {
                     if known_types::is_tauri_special_type(&target) {
@@ -490,5 +1134,34 @@ impl Pipeline {
     }
 }
 
+/// Why the file at `path` doesn't match `expected`, or `None` if it does.
+/// Points at the first differing line so CI logs show what drifted.
+fn staleness(path: &Path, expected: &str) -> Option<String> {
+    let Ok(actual) = fs::read_to_string(path) else {
+        return Some("missing".to_string());
+    };
+    if actual == expected {
+        return None;
+    }
+
+    let mut actual_lines = actual.lines();
+    let mut expected_lines = expected.lines();
+    let mut line = 1;
+    loop {
+        match (actual_lines.next(), expected_lines.next()) {
+            (Some(a), Some(e)) if a == e => line += 1,
+            (None, None) => return Some("differs in line endings".to_string()),
+            (a, e) => {
+                return Some(format!(
+                    "differs at line {}\n    - {}\n    + {}",
+                    line,
+                    a.unwrap_or("<end of file>"),
+                    e.unwrap_or("<end of file>")
+                ));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
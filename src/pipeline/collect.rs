@@ -12,10 +12,10 @@ use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::diagnostics::Diagnostics;
+use crate::diagnostics::{error_line, Diagnostics};
 use crate::models::{
     walk_custom_type_names, RustEnum, RustStruct, RustType, RustTypeAlias, TauriCommand,
-    VariantData,
+    TauriEvent, VariantData,
 };
 use crate::parser::{parse_types, ParseOptions, ParsedTypes};
 use crate::resolver::{ModuleResolver, ResolutionResult};
@@ -31,20 +31,46 @@ pub struct TypeCollectionResult {
     pub aliases: Vec<RustTypeAlias>,
     /// Conflicts: type name -> list of conflicting source files
     pub conflicts: HashMap<String, Vec<PathBuf>>,
-    /// Unresolved types: type name -> file where it was used
-    pub unresolved: HashMap<String, PathBuf>,
+    /// Unresolved types: type name -> where it was first used
+    pub unresolved: HashMap<String, UnresolvedUse>,
 }
 
-/// Entry point: walk the command graph, return everything reachable.
+/// Where an unresolved type is referenced from
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedUse {
+    /// File the reference is in
+    pub file: PathBuf,
+    /// What references it: `command 'get_user'`, `event 'user-updated'`
+    /// or `type 'User'`
+    pub used_by: String,
+}
+
+/// Settings of the walk, from the `[input]` and `[generation]` config
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CollectOptions<'a> {
+    /// Follow the error types of `Result` returns
+    /// (`[generation] export_error_types`)
+    pub include_error_types: bool,
+    /// `[input] external_crate_prefixes`
+    pub external_prefixes: &'a [String],
+    /// `[input] active_features`
+    pub active_features: &'a [String],
+}
+
+/// Entry point: walk the command graph (and the event payloads), return
+/// everything reachable.
 pub fn collect_reachable_types(
     commands: &[TauriCommand],
+    events: &[TauriEvent],
     resolver: &ModuleResolver,
     expanded_types: Option<&ParsedTypes>,
+    options: CollectOptions<'_>,
     diag: &Diagnostics,
 ) -> TypeCollectionResult {
-    let mut state = CollectState::new(resolver, diag);
+    let mut state = CollectState::new(resolver, options, diag);
     state.seed_expanded_types(expanded_types);
-    state.seed_from_commands(commands);
+    state.seed_from_commands(commands, options.include_error_types);
+    state.seed_from_events(events);
     state.drain();
     state.finalize_reexport_aliases();
     state.into_result()
@@ -85,12 +111,13 @@ fn custom_types_from_variant(data: &VariantData) -> Vec<String> {
 struct CollectState<'a> {
     resolver: &'a ModuleResolver,
     diag: &'a Diagnostics,
+    options: CollectOptions<'a>,
 
     structs: Vec<RustStruct>,
     enums: Vec<RustEnum>,
     aliases: Vec<RustTypeAlias>,
     conflicts: HashMap<String, Vec<PathBuf>>,
-    unresolved: HashMap<String, PathBuf>,
+    unresolved: HashMap<String, UnresolvedUse>,
 
     resolved_types: HashMap<String, PathBuf>,
     // Stored behind `Arc` so the drain loop can hand out a cheap handle
@@ -107,10 +134,15 @@ struct CollectState<'a> {
 }
 
 impl<'a> CollectState<'a> {
-    fn new(resolver: &'a ModuleResolver, diag: &'a Diagnostics) -> Self {
+    fn new(
+        resolver: &'a ModuleResolver,
+        options: CollectOptions<'a>,
+        diag: &'a Diagnostics,
+    ) -> Self {
         Self {
             resolver,
             diag,
+            options,
             structs: Vec::new(),
             enums: Vec::new(),
             aliases: Vec::new(),
@@ -161,7 +193,7 @@ impl<'a> CollectState<'a> {
         true
     }
 
-    fn resolve_and_enqueue(&mut self, type_name: &str, from_file: &Path) {
+    fn resolve_and_enqueue(&mut self, type_name: &str, from_file: &Path, used_by: &str) {
         let name = simple_name(type_name).to_string();
 
         match self.resolver.resolve_type(type_name, from_file) {
@@ -184,27 +216,80 @@ impl<'a> CollectState<'a> {
                     self.add_conflict_path(&name, &path);
                 }
             }
+            ResolutionResult::NotFound if self.is_external(type_name, from_file) => {
+                self.diag.debug(format!(
+                    "Typing external type '{}' (used by {}) as unknown",
+                    type_name, used_by
+                ));
+            }
             ResolutionResult::NotFound => {
                 self.unresolved
                     .entry(name)
-                    .or_insert_with(|| from_file.to_path_buf());
+                    .or_insert_with(|| UnresolvedUse {
+                        file: from_file.to_path_buf(),
+                        used_by: used_by.to_string(),
+                    });
             }
         }
     }
 
-    fn seed_from_commands(&mut self, commands: &[TauriCommand]) {
+    /// Whether `type_name` comes from one of the `external_prefixes`,
+    /// matched on whole path segments
+    fn is_external(&self, type_name: &str, from_file: &Path) -> bool {
+        let path = self.resolver.imported_path(type_name, from_file);
+        self.options.external_prefixes.iter().any(|prefix| {
+            let prefix: Vec<&str> = prefix.split("::").filter(|s| !s.is_empty()).collect();
+            !prefix.is_empty()
+                && prefix.len() < path.len()
+                && prefix.iter().zip(&path).all(|(a, b)| a == b)
+        })
+    }
+
+    /// Seed the walk from command signatures. Error types of `Result`
+    /// returns are only followed under `include_error_types`
+    /// (`[generation] export_error_types`).
+    fn seed_from_commands(&mut self, commands: &[TauriCommand], include_error_types: bool) {
         for cmd in commands {
             let cmd_file = cmd.source_file.clone();
+            let used_by = format!("command '{}'", cmd.name);
             for arg in &cmd.args {
                 for t in custom_types(&arg.ty) {
-                    self.resolve_and_enqueue(&t, &cmd_file);
+                    self.resolve_and_enqueue(&t, &cmd_file, &used_by);
                 }
             }
             if let Some(ret_type) = &cmd.return_type {
                 for t in custom_types(ret_type) {
-                    self.resolve_and_enqueue(&t, &cmd_file);
+                    self.resolve_and_enqueue(&t, &cmd_file, &used_by);
                 }
             }
+            if let Some(err_type) = cmd.error_type().filter(|_| include_error_types) {
+                for t in custom_types(err_type) {
+                    self.enqueue_if_resolvable(&t, &cmd_file, &used_by);
+                }
+            }
+        }
+    }
+
+    fn seed_from_events(&mut self, events: &[TauriEvent]) {
+        for event in events {
+            let used_by = format!("event '{}'", event.name);
+            for t in custom_types(&event.payload) {
+                self.resolve_and_enqueue(&t, &event.source_file, &used_by);
+            }
+        }
+    }
+
+    /// Best-effort variant of `resolve_and_enqueue` for command error
+    /// types. Errors are commonly foreign (`std::io::Error`,
+    /// `anyhow::Error`) and only feed the `@throws` annotation, so a type
+    /// that doesn't resolve is silently dropped instead of being reported
+    /// as unresolved.
+    fn enqueue_if_resolvable(&mut self, type_name: &str, from_file: &Path, used_by: &str) {
+        match self.resolver.resolve_type(type_name, from_file) {
+            ResolutionResult::Found(_) | ResolutionResult::FoundWithAlias(..) => {
+                self.resolve_and_enqueue(type_name, from_file, used_by);
+            }
+            ResolutionResult::Ambiguous(_) | ResolutionResult::NotFound => {}
         }
     }
 
@@ -217,26 +302,34 @@ impl<'a> CollectState<'a> {
         let content = match fs::read_to_string(type_file) {
             Ok(c) => c,
             Err(e) => {
-                self.diag.warn(format!(
-                    "Failed to read file for types {}: {}",
-                    type_file.display(),
-                    e
-                ));
+                self.diag.warn_in(
+                    type_file,
+                    None,
+                    format!(
+                        "Failed to read file for types {}: {}",
+                        type_file.display(),
+                        e
+                    ),
+                );
                 return false;
             }
         };
-        match parse_types(&content, type_file, ParseOptions::SOURCE_ALL) {
+        let options = ParseOptions {
+            active_features: self.options.active_features.to_vec(),
+            ..ParseOptions::SOURCE_ALL
+        };
+        match parse_types(&content, type_file, options) {
             Ok(parsed) => {
                 self.parsed_files
                     .insert(type_file.to_path_buf(), Arc::new(parsed));
                 true
             }
             Err(e) => {
-                self.diag.warn(format!(
-                    "Failed to parse types in {}: {}",
-                    type_file.display(),
-                    e
-                ));
+                self.diag.warn_in(
+                    type_file,
+                    error_line(&e),
+                    format!("Failed to parse types in {}: {}", type_file.display(), e),
+                );
                 false
             }
         }
@@ -270,9 +363,10 @@ impl<'a> CollectState<'a> {
                 {
                     self.structs.push(s.clone());
                 }
+                let used_by = format!("type '{}'", s.name);
                 for field in &s.fields {
                     for t in custom_types(&field.ty) {
-                        self.resolve_and_enqueue(&t, &type_file);
+                        self.resolve_and_enqueue(&t, &type_file, &used_by);
                     }
                 }
                 continue;
@@ -282,9 +376,10 @@ impl<'a> CollectState<'a> {
                 if self.seen_enums.insert((e.name.clone(), type_file.clone())) {
                     self.enums.push(e.clone());
                 }
+                let used_by = format!("type '{}'", e.name);
                 for variant in &e.variants {
                     for t in custom_types_from_variant(&variant.data) {
-                        self.resolve_and_enqueue(&t, &type_file);
+                        self.resolve_and_enqueue(&t, &type_file, &used_by);
                     }
                 }
                 continue;
@@ -295,8 +390,9 @@ impl<'a> CollectState<'a> {
                     self.aliases.push(alias.clone());
                 }
                 let alias_source = alias.source_file.clone();
+                let used_by = format!("type '{}'", alias.name);
                 for t in custom_types(&alias.target) {
-                    self.resolve_and_enqueue(&t, &alias_source);
+                    self.resolve_and_enqueue(&t, &alias_source, &used_by);
                 }
             }
         }
@@ -328,12 +424,15 @@ impl<'a> CollectState<'a> {
                 })
                 .unwrap_or_default();
 
+            // `pub use Page as Listing` keeps Page's parameters:
+            // `Listing<T> = Page<T>`.
+            let args = generics.iter().cloned().map(RustType::Generic).collect();
             self.aliases.push(RustTypeAlias {
                 name: alias_name,
                 generics,
                 target: RustType::Custom {
                     name: original_name,
-                    args: Vec::new(),
+                    args,
                 },
                 source_file,
             });
@@ -0,0 +1,146 @@
+//! `--explain <type>`: a human-readable account of how one type name was
+//! resolved. Lists where the name is defined, how each use site found (or
+//! failed to find) it, and whether it ends up in the generated types file.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use super::collect::TypeCollectionResult;
+use crate::models::{walk_custom_type_names, RustType, TauriCommand, VariantData};
+use crate::resolver::{ModuleResolver, ResolutionResult};
+use crate::utils::simple_name;
+
+/// Build the explanation for `type_name` (a simple name such as `User`).
+pub fn explain_type(
+    type_name: &str,
+    commands: &[TauriCommand],
+    resolver: &ModuleResolver,
+    collection: &TypeCollectionResult,
+) -> Vec<String> {
+    let mut lines = vec![format!("Type '{}':", type_name)];
+
+    lines.push("  Candidate definitions:".to_string());
+    match resolver.type_definitions_for(type_name) {
+        Some(files) if !files.is_empty() => {
+            for file in files {
+                lines.push(format!("    - {}", file.display()));
+            }
+        }
+        _ => lines.push("    (none)".to_string()),
+    }
+
+    let uses = use_sites(type_name, commands, collection);
+    lines.push("  Resolution:".to_string());
+    if uses.is_empty() {
+        lines.push("    (not referenced by any command or exported type)".to_string());
+    }
+    for (written, file, context) in &uses {
+        let (result, method) = resolver.resolve_type_explained(written, file);
+        lines.push(format!(
+            "    - '{}' in {} ({}): {} via {}",
+            written,
+            file.display(),
+            context,
+            describe(&result),
+            method
+        ));
+    }
+
+    lines.push(format!(
+        "  Exported: {}",
+        export_status(type_name, collection)
+    ));
+    lines
+}
+
+/// Every `(as written, file, context)` where a command signature or a
+/// collected type refers to `type_name`, deduplicated and sorted.
+fn use_sites(
+    type_name: &str,
+    commands: &[TauriCommand],
+    collection: &TypeCollectionResult,
+) -> BTreeSet<(String, PathBuf, String)> {
+    let mut sites = BTreeSet::new();
+    let mut visit = |ty: &RustType, file: &Path, context: String| {
+        walk_custom_type_names(ty, &mut |name| {
+            if simple_name(name) == type_name {
+                sites.insert((name.to_string(), file.to_path_buf(), context.clone()));
+            }
+        });
+    };
+
+    for cmd in commands {
+        let context = format!("command '{}'", cmd.name);
+        for arg in &cmd.args {
+            visit(&arg.ty, &cmd.source_file, context.clone());
+        }
+        if let Some(ret) = &cmd.return_type {
+            visit(ret, &cmd.source_file, context.clone());
+        }
+    }
+    for s in &collection.structs {
+        for field in &s.fields {
+            visit(&field.ty, &s.source_file, format!("struct '{}'", s.name));
+        }
+    }
+    for e in &collection.enums {
+        for variant in &e.variants {
+            let context = format!("enum '{}'", e.name);
+            match &variant.data {
+                VariantData::Unit => {}
+                VariantData::Tuple(types) => {
+                    for ty in types {
+                        visit(ty, &e.source_file, context.clone());
+                    }
+                }
+                VariantData::Struct(fields) => {
+                    for field in fields {
+                        visit(&field.ty, &e.source_file, context.clone());
+                    }
+                }
+            }
+        }
+    }
+    for a in &collection.aliases {
+        visit(&a.target, &a.source_file, format!("alias '{}'", a.name));
+    }
+
+    sites
+}
+
+fn describe(result: &ResolutionResult) -> String {
+    match result {
+        ResolutionResult::Found(file) => format!("found in {}", file.display()),
+        ResolutionResult::FoundWithAlias(file, original) => {
+            format!("found in {} as '{}'", file.display(), original)
+        }
+        ResolutionResult::NotFound => "not found".to_string(),
+        ResolutionResult::Ambiguous(files) => {
+            let files: Vec<_> = files.iter().map(|f| f.display().to_string()).collect();
+            format!("ambiguous between {}", files.join(", "))
+        }
+    }
+}
+
+fn export_status(type_name: &str, collection: &TypeCollectionResult) -> String {
+    if let Some(s) = collection.structs.iter().find(|s| s.name == type_name) {
+        return format!("yes, as a struct from {}", s.source_file.display());
+    }
+    if let Some(e) = collection.enums.iter().find(|e| e.name == type_name) {
+        return format!("yes, as an enum from {}", e.source_file.display());
+    }
+    if let Some(a) = collection.aliases.iter().find(|a| a.name == type_name) {
+        return format!("yes, as a type alias from {}", a.source_file.display());
+    }
+    if collection.conflicts.contains_key(type_name) {
+        return "no, the name is defined in more than one source (conflict)".to_string();
+    }
+    if let Some((_, unresolved)) = collection
+        .unresolved
+        .iter()
+        .find(|(name, _)| simple_name(name) == type_name)
+    {
+        return format!("no, unresolved (used in {})", unresolved.file.display());
+    }
+    "no, not reachable from any command".to_string()
+}
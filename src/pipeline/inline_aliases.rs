@@ -0,0 +1,137 @@
+//! Replaces references to type aliases with the aliased type under
+//! `[generation] inline_type_aliases`, so `id: UserId` is emitted as
+//! `id: number` and no `export type UserId` is needed.
+//!
+//! Generic aliases are instantiated at each use site: with
+//! `type Page<T> = Vec<T>`, a `Page<User>` reference becomes `Vec<User>`.
+
+use std::collections::HashMap;
+
+use crate::models::{RustEnum, RustStruct, RustType, RustTypeAlias, TauriCommand, VariantData};
+use crate::utils::simple_name;
+
+/// Alias chains (`type A = B; type B = i32;`) are followed at most this
+/// deep; Rust rejects cyclic aliases, so this only guards against a
+/// malformed parse.
+const MAX_ALIAS_DEPTH: usize = 32;
+
+/// Rewrite every type reachable from `commands`, `structs` and `enums`,
+/// replacing each reference to one of `aliases` with its target.
+pub fn inline_type_aliases(
+    commands: &mut [TauriCommand],
+    structs: &mut [RustStruct],
+    enums: &mut [RustEnum],
+    aliases: &[RustTypeAlias],
+) {
+    let by_name: HashMap<&str, &RustTypeAlias> =
+        aliases.iter().map(|a| (a.name.as_str(), a)).collect();
+    let inline = |ty: &mut RustType| substitute(ty, &by_name, 0);
+
+    for cmd in commands {
+        for arg in &mut cmd.args {
+            inline(&mut arg.ty);
+        }
+        if let Some(ty) = &mut cmd.return_type {
+            inline(ty);
+        }
+    }
+    for s in structs {
+        for field in &mut s.fields {
+            inline(&mut field.ty);
+        }
+    }
+    for e in enums {
+        for variant in &mut e.variants {
+            match &mut variant.data {
+                VariantData::Unit => {}
+                VariantData::Tuple(types) => types.iter_mut().for_each(inline),
+                VariantData::Struct(fields) => {
+                    for field in fields {
+                        inline(&mut field.ty);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn substitute(ty: &mut RustType, aliases: &HashMap<&str, &RustTypeAlias>, depth: usize) {
+    match ty {
+        RustType::Custom { name, args } => {
+            for arg in args.iter_mut() {
+                substitute(arg, aliases, depth);
+            }
+            let Some(alias) = aliases.get(simple_name(name)) else {
+                return;
+            };
+            if depth >= MAX_ALIAS_DEPTH {
+                return;
+            }
+            let bindings: HashMap<&str, &RustType> = alias
+                .generics
+                .iter()
+                .map(String::as_str)
+                .zip(args.iter())
+                .collect();
+            let mut target = alias.target.clone();
+            bind_generics(&mut target, &bindings);
+            substitute(&mut target, aliases, depth + 1);
+            *ty = target;
+        }
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Channel(inner) => {
+            substitute(inner, aliases, depth);
+        }
+        RustType::Result { ok, err } => {
+            substitute(ok, aliases, depth);
+            if let Some(err) = err {
+                substitute(err, aliases, depth);
+            }
+        }
+        RustType::HashMap { key, value } => {
+            substitute(key, aliases, depth);
+            substitute(value, aliases, depth);
+        }
+        RustType::Tuple(types) => {
+            for t in types {
+                substitute(t, aliases, depth);
+            }
+        }
+        RustType::Primitive(_) | RustType::Generic(_) | RustType::Unit | RustType::Unknown(_) => {}
+    }
+}
+
+/// Replace the alias's own type parameters with the arguments of the
+/// reference being inlined.
+fn bind_generics(ty: &mut RustType, bindings: &HashMap<&str, &RustType>) {
+    match ty {
+        RustType::Generic(param) => {
+            if let Some(bound) = bindings.get(param.as_str()) {
+                *ty = (*bound).clone();
+            }
+        }
+        RustType::Custom { args, .. } => {
+            for arg in args {
+                bind_generics(arg, bindings);
+            }
+        }
+        RustType::Vec(inner) | RustType::Option(inner) | RustType::Channel(inner) => {
+            bind_generics(inner, bindings);
+        }
+        RustType::Result { ok, err } => {
+            bind_generics(ok, bindings);
+            if let Some(err) = err {
+                bind_generics(err, bindings);
+            }
+        }
+        RustType::HashMap { key, value } => {
+            bind_generics(key, bindings);
+            bind_generics(value, bindings);
+        }
+        RustType::Tuple(types) => {
+            for t in types {
+                bind_generics(t, bindings);
+            }
+        }
+        RustType::Primitive(_) | RustType::Unit | RustType::Unknown(_) => {}
+    }
+}
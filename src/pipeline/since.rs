@@ -0,0 +1,160 @@
+//! `generate --since <ref>`: find the `.rs` files that changed since a git
+//! ref and work out which of the two output files they can affect, so a
+//! run only rewrites those.
+//!
+//! The whole project is still analysed — which types are exported depends
+//! on every command — but an output none of the changed files contributes
+//! to is left untouched on disk.
+
+use anyhow::{Context, Result};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::collect::TypeCollectionResult;
+use crate::models::{walk_custom_type_names, TauriCommand};
+use crate::parser::{parse_commands, parse_types, ParseOptions, ParsedTypes};
+use crate::utils::simple_name;
+
+/// A `.rs` file that differs from the ref.
+#[derive(Debug, Clone)]
+pub(super) struct ChangedFile {
+    /// Where the file lives (or lived) in the working tree
+    pub path: PathBuf,
+    /// Its content at the ref; `None` when the file is new
+    pub previous: Option<String>,
+}
+
+/// Which output files a set of changes can affect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct Affected {
+    pub types: bool,
+    pub commands: bool,
+}
+
+impl Affected {
+    pub const ALL: Affected = Affected {
+        types: true,
+        commands: true,
+    };
+}
+
+/// Every `.rs` file under `source_dir` that is modified, added, deleted or
+/// untracked relative to `git_ref`, with its content at the ref.
+pub(super) fn changed_files(source_dir: &Path, git_ref: &str) -> Result<Vec<ChangedFile>> {
+    let toplevel = PathBuf::from(git(source_dir, &["rev-parse", "--show-toplevel"])?.trim());
+    // Validate the ref up front so a typo is an error, not "nothing changed".
+    git(
+        &toplevel,
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", git_ref)],
+    )
+    .with_context(|| format!("Unknown git ref: {}", git_ref))?;
+
+    let diffed = git(&toplevel, &["diff", "--name-only", git_ref, "--"])?;
+    let untracked = git(&toplevel, &["ls-files", "--others", "--exclude-standard"])?;
+    let source_dir = canonical(source_dir);
+
+    let mut seen = HashSet::new();
+    let mut changed = Vec::new();
+    for relative in diffed.lines().chain(untracked.lines()) {
+        let path = toplevel.join(relative);
+        if !relative.ends_with(".rs")
+            || !path.starts_with(&source_dir)
+            || !seen.insert(path.clone())
+        {
+            continue;
+        }
+        let previous = git(&toplevel, &["show", &format!("{}:{}", git_ref, relative)]).ok();
+        changed.push(ChangedFile { path, previous });
+    }
+    Ok(changed)
+}
+
+/// Which outputs `changes` can affect, judged by what each changed file
+/// contributes now and what it contained at the ref:
+///
+/// * the types file, if the file defines an exported type now, or defined
+///   any serializable type before;
+/// * the commands file, if the file defines a command (now or before) or
+///   an exported type a command signature names.
+pub(super) fn affected_outputs(
+    changes: &[ChangedFile],
+    commands: &[TauriCommand],
+    collection: &TypeCollectionResult,
+) -> Affected {
+    let changed: HashSet<PathBuf> = changes.iter().map(|c| canonical(&c.path)).collect();
+    let is_changed = |path: &Path| changed.contains(&canonical(path));
+
+    let mut command_types: HashSet<String> = HashSet::new();
+    for cmd in commands {
+        for ty in cmd.args.iter().map(|a| &a.ty).chain(&cmd.return_type) {
+            walk_custom_type_names(ty, &mut |name| {
+                command_types.insert(simple_name(name).to_string());
+            });
+        }
+    }
+
+    let exported = collection
+        .structs
+        .iter()
+        .map(|s| (&s.name, &s.source_file))
+        .chain(collection.enums.iter().map(|e| (&e.name, &e.source_file)))
+        .chain(collection.aliases.iter().map(|a| (&a.name, &a.source_file)));
+
+    let mut affected = Affected {
+        types: false,
+        commands: commands.iter().any(|c| is_changed(&c.source_file)),
+    };
+    for (name, file) in exported {
+        if is_changed(file) {
+            affected.types = true;
+            affected.commands |= command_types.contains(name.as_str());
+        }
+    }
+
+    for change in changes {
+        let Some(previous) = &change.previous else {
+            continue;
+        };
+        if !affected.commands {
+            affected.commands = parse_commands(previous, &change.path)
+                .map(|old| !old.is_empty())
+                .unwrap_or(true);
+        }
+        if !affected.types {
+            affected.types = match parse_types(previous, &change.path, ParseOptions::SOURCE) {
+                Ok(ParsedTypes {
+                    structs,
+                    enums,
+                    aliases,
+                }) => !(structs.is_empty() && enums.is_empty() && aliases.is_empty()),
+                Err(_) => true,
+            };
+        }
+    }
+    affected
+}
+
+/// Run `git <args>` in `dir` and return its stdout.
+fn git(dir: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(dir)
+        .args(args)
+        .output()
+        .context("Failed to run git; --since needs git on PATH")?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "git {} failed: {}",
+            args.join(" "),
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// `path` made absolute, or as given when it no longer exists.
+fn canonical(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
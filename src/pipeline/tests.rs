@@ -41,12 +41,15 @@ fn test_collect_reachable_types_from_commands() {
         return_type: Some(RustType::custom("Response")),
         source_file: types_path.clone(),
         rename_all: None,
+        docs: None,
     }];
 
     let result = collect::collect_reachable_types(
         &commands,
+        &[],
         &resolver,
         None,
+        collect::CollectOptions::default(),
         &crate::diagnostics::Diagnostics::new(false),
     );
 
@@ -81,12 +84,15 @@ fn test_collect_reachable_types_includes_aliases() {
         return_type: None,
         source_file: types_path.clone(),
         rename_all: None,
+        docs: None,
     }];
 
     let result = collect::collect_reachable_types(
         &commands,
+        &[],
         &resolver,
         None,
+        collect::CollectOptions::default(),
         &crate::diagnostics::Diagnostics::new(false),
     );
 
@@ -121,12 +127,15 @@ fn test_collect_reachable_types_detects_conflicts() {
         return_type: Some(RustType::custom("User")),
         source_file: cmd_path,
         rename_all: None,
+        docs: None,
     }];
 
     let result = collect::collect_reachable_types(
         &commands,
+        &[],
         &resolver,
         None,
+        collect::CollectOptions::default(),
         &crate::diagnostics::Diagnostics::new(false),
     );
 
@@ -164,6 +173,7 @@ fn test_filter_tauri_special_types() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     }];
 
     pipeline.filter_tauri_special_args(&mut commands, &resolver);
@@ -193,6 +203,7 @@ fn test_filter_tauri_app_handle() {
         return_type: None,
         source_file: test_path(),
         rename_all: None,
+        docs: None,
     }];
 
     pipeline.filter_tauri_special_args(&mut commands, &resolver);
@@ -229,6 +240,7 @@ fn test_filter_tauri_special_types_via_alias() {
         return_type: None,
         source_file: path.clone(),
         rename_all: None,
+        docs: None,
     }];
 
     pipeline.filter_tauri_special_args(&mut commands, &resolver);
@@ -265,12 +277,15 @@ fn test_collect_reachable_types_handles_self_referential_struct() {
         return_type: Some(RustType::custom("Node")),
         source_file: types_path.clone(),
         rename_all: None,
+        docs: None,
     }];
 
     let result = collect::collect_reachable_types(
         &commands,
+        &[],
         &resolver,
         None,
+        collect::CollectOptions::default(),
         &crate::diagnostics::Diagnostics::new(false),
     );
 
@@ -305,12 +320,15 @@ fn test_collect_reachable_types_handles_mutually_recursive_structs() {
         return_type: Some(RustType::custom("A")),
         source_file: types_path.clone(),
         rename_all: None,
+        docs: None,
     }];
 
     let result = collect::collect_reachable_types(
         &commands,
+        &[],
         &resolver,
         None,
+        collect::CollectOptions::default(),
         &crate::diagnostics::Diagnostics::new(false),
     );
 
@@ -364,8 +382,8 @@ fn parse_files_picks_up_macro_generated_commands_from_expanded_code() {
     config.input.source_dir = src_dir.clone();
 
     let pipeline = Pipeline::new(false);
-    let (commands, _resolver, _expanded_types) = pipeline
-        .parse_files(&[raw_path], &config, Some(expanded_code))
+    let ParsedSources { commands, .. } = pipeline
+        .parse_files(&[raw_path], &[], &config, Some(expanded_code))
         .expect("parse_files should succeed");
 
     let names: Vec<&str> = commands.iter().map(|c| c.name.as_str()).collect();
@@ -420,9 +438,10 @@ fn parse_files_keeps_source_path_when_command_exists_in_both() {
     config.input.source_dir = src_dir.clone();
 
     let pipeline = Pipeline::new(false);
-    let (commands, _resolver, _expanded_types) = pipeline
+    let ParsedSources { commands, .. } = pipeline
         .parse_files(
             std::slice::from_ref(&raw_path),
+            &[],
             &config,
             Some(expanded_code),
         )
@@ -440,3 +459,220 @@ fn parse_files_keeps_source_path_when_command_exists_in_both() {
         "raw-source entry must win over the cargo-expand duplicate"
     );
 }
+
+/// A project with a command file, a model file whose `User` the command
+/// names, and an `Address` only `User` refers to. Returns the config with
+/// both outputs already generated.
+fn since_project(root: &std::path::Path) -> crate::config::Config {
+    let src_dir = root.join("src");
+    write_file(
+        &src_dir.join("commands.rs"),
+        r#"
+        use crate::models::User;
+        #[tauri::command]
+        pub fn get_user() -> User { unimplemented!() }
+        "#,
+    );
+    write_file(
+        &src_dir.join("models.rs"),
+        r#"
+        use crate::address::Address;
+        #[derive(Serialize)]
+        pub struct User { pub address: Address }
+        "#,
+    );
+    write_file(
+        &src_dir.join("address.rs"),
+        "#[derive(Serialize)]\npub struct Address { pub city: String }\n",
+    );
+    write_file(&src_dir.join("util.rs"), "pub fn helper() {}\n");
+
+    let mut config = crate::config::Config::default_config();
+    config.input.source_dir = src_dir;
+    config.output.types_file = root.join("out").join("types.ts");
+    config.output.commands_file = root.join("out").join("commands.ts");
+    Pipeline::new(false).run(&config).unwrap();
+    config
+}
+
+fn mark_outputs_stale(config: &crate::config::Config) {
+    std::fs::write(&config.output.types_file, "stale").unwrap();
+    std::fs::write(&config.output.commands_file, "stale").unwrap();
+}
+
+fn rewritten(path: &std::path::Path) -> bool {
+    std::fs::read_to_string(path).unwrap() != "stale"
+}
+
+#[test]
+fn run_since_only_rewrites_affected_outputs() {
+    let temp = tempfile::tempdir().unwrap();
+    let config = since_project(temp.path());
+    let src_dir = config.input.source_dir.clone();
+    let changed = |name: &str, previous: &str| since::ChangedFile {
+        path: src_dir.join(name),
+        previous: Some(previous.to_string()),
+    };
+    let pipeline = Pipeline::new(false);
+
+    // A type only other types reach touches the types file alone.
+    mark_outputs_stale(&config);
+    let changes = [changed("address.rs", "pub struct Address {}")];
+    pipeline.run_with_changes(&config, &changes).unwrap();
+    assert!(rewritten(&config.output.types_file));
+    assert!(!rewritten(&config.output.commands_file));
+
+    // A type a command names shows up in both files.
+    mark_outputs_stale(&config);
+    let changes = [changed("models.rs", "pub struct User {}")];
+    pipeline.run_with_changes(&config, &changes).unwrap();
+    assert!(rewritten(&config.output.types_file));
+    assert!(rewritten(&config.output.commands_file));
+
+    // A file without commands or types affects nothing...
+    mark_outputs_stale(&config);
+    let changes = [changed("util.rs", "")];
+    pipeline.run_with_changes(&config, &changes).unwrap();
+    assert!(!rewritten(&config.output.types_file));
+    assert!(!rewritten(&config.output.commands_file));
+
+    // ...unless it held a command at the ref.
+    let changes = [changed(
+        "util.rs",
+        "#[tauri::command]\npub fn removed() {}\n",
+    )];
+    pipeline.run_with_changes(&config, &changes).unwrap();
+    assert!(!rewritten(&config.output.types_file));
+    assert!(rewritten(&config.output.commands_file));
+}
+
+#[test]
+fn run_since_rewrites_zod_file_when_a_command_gains_an_argument() {
+    let temp = tempfile::tempdir().unwrap();
+    let mut config = since_project(temp.path());
+    let zod_file = temp.path().join("out").join("schemas.ts");
+    config.output.zod_file = Some(zod_file.clone());
+    let pipeline = Pipeline::new(false);
+    pipeline.run(&config).unwrap();
+
+    let commands_rs = config.input.source_dir.join("commands.rs");
+    let previous = std::fs::read_to_string(&commands_rs).unwrap();
+    write_file(
+        &commands_rs,
+        r#"
+        use crate::models::User;
+        #[tauri::command]
+        pub fn get_user(id: u32) -> User { unimplemented!() }
+        "#,
+    );
+    let changes = [since::ChangedFile {
+        path: commands_rs,
+        previous: Some(previous),
+    }];
+    pipeline.run_with_changes(&config, &changes).unwrap();
+
+    let commands = std::fs::read_to_string(&config.output.commands_file).unwrap();
+    assert!(
+        commands.contains("import { GetUserArgsSchema } from \"./schemas\";"),
+        "got:\n{commands}"
+    );
+    let schemas = std::fs::read_to_string(&zod_file).unwrap();
+    assert!(
+        schemas.contains("export const GetUserArgsSchema = z.object({"),
+        "got:\n{schemas}"
+    );
+}
+
+#[test]
+fn changed_files_lists_rust_files_changed_since_ref() {
+    let temp = tempfile::tempdir().unwrap();
+    let root = temp.path();
+    let git = |args: &[&str]| {
+        let status = std::process::Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["-c", "user.name=test", "-c", "user.email=test@example.com"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    write_file(&root.join("src/kept.rs"), "pub struct Kept;\n");
+    write_file(&root.join("src/edited.rs"), "pub struct Before;\n");
+    write_file(&root.join("src/gone.rs"), "pub struct Gone;\n");
+    git(&["init", "-q"]);
+    git(&["add", "-A"]);
+    git(&["commit", "-qm", "base"]);
+
+    write_file(&root.join("src/edited.rs"), "pub struct After;\n");
+    std::fs::remove_file(root.join("src/gone.rs")).unwrap();
+    write_file(&root.join("src/new.rs"), "pub struct New;\n");
+    write_file(&root.join("src/notes.md"), "not rust\n");
+
+    let mut changes = since::changed_files(&root.join("src"), "HEAD").unwrap();
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let names: Vec<_> = changes
+        .iter()
+        .map(|c| c.path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    assert_eq!(names, ["edited.rs", "gone.rs", "new.rs"]);
+    assert_eq!(changes[0].previous.as_deref(), Some("pub struct Before;\n"));
+    assert_eq!(changes[2].previous, None);
+
+    assert!(since::changed_files(&root.join("src"), "no-such-ref").is_err());
+}
+
+#[test]
+fn parallel_parsing_matches_sequential_output() {
+    // A few hundred files: one command and one model per module, every
+    // model pointing at the next so resolution crosses files.
+    const MODULES: usize = 300;
+    let temp_dir = tempfile::tempdir().unwrap();
+    let src_dir = temp_dir.path().join("src");
+    for i in 0..MODULES {
+        write_file(
+            &src_dir.join(format!("module_{:03}.rs", i)),
+            &format!(
+                r#"
+                use crate::module_{next:03}::Model{next};
+                #[derive(Serialize)]
+                pub struct Model{i} {{ pub id: u32, pub next: Option<Box<Model{next}>> }}
+                #[tauri::command]
+                pub fn get_model_{i}(id: u32) -> Model{i} {{ unimplemented!() }}
+                "#,
+                i = i,
+                next = (i + 1) % MODULES,
+            ),
+        );
+    }
+
+    let mut config = crate::config::Config::default_config();
+    config.input.source_dir = src_dir;
+
+    let render = |config: &crate::config::Config| match Pipeline::new(false)
+        .render(config)
+        .unwrap()
+        .bindings
+    {
+        BindingFiles::Split { types, commands } => (types, commands),
+        BindingFiles::Single(_) => unreachable!("no single_file configured"),
+    };
+    let sequential = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap()
+        .install(|| render(&config));
+    let parallel = rayon::ThreadPoolBuilder::new()
+        .num_threads(8)
+        .build()
+        .unwrap()
+        .install(|| render(&config));
+
+    assert!(sequential.0.contains("export interface Model299 {"));
+    assert!(sequential
+        .1
+        .contains("export async function getModel0(id: number): Promise<Model0>"));
+    assert_eq!(sequential, parallel);
+}
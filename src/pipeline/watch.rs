@@ -0,0 +1,91 @@
+//! `generate --watch`: generate once, then regenerate whenever a `.rs`
+//! file the scanner would pick up changes. A failing pass is reported and
+//! the watcher keeps running, so a half-typed edit doesn't end the session.
+
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+
+use super::Pipeline;
+use crate::config::Config;
+use crate::scanner::Scanner;
+
+/// Editors often save in several steps (write temp, rename, touch); wait
+/// this long after the last event before regenerating.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+impl Pipeline {
+    /// Run the pipeline now and again after every relevant change under
+    /// `source_dir`. Only returns if the watcher itself fails.
+    pub fn watch(&self, config: &Config) -> Result<()> {
+        let scanner = self.scanner(config)?;
+        let (tx, rx) = mpsc::channel();
+        let mut watcher =
+            notify::recommended_watcher(tx).context("Failed to start file watcher")?;
+        watcher
+            .watch(&config.input.source_dir, RecursiveMode::Recursive)
+            .with_context(|| format!("Failed to watch {}", config.input.source_dir.display()))?;
+
+        self.regenerate(config);
+        self.diag.info(format!(
+            "Watching {} for changes (Ctrl+C to stop)",
+            config.input.source_dir.display()
+        ));
+
+        loop {
+            let event = rx.recv().context("File watcher stopped")?;
+            if !is_relevant(&event, &scanner) {
+                continue;
+            }
+
+            // Swallow the rest of the burst.
+            loop {
+                match rx.recv_timeout(DEBOUNCE) {
+                    Ok(_) => continue,
+                    Err(RecvTimeoutError::Timeout) => break,
+                    Err(RecvTimeoutError::Disconnected) => {
+                        anyhow::bail!("File watcher stopped")
+                    }
+                }
+            }
+
+            self.regenerate(config);
+        }
+    }
+
+    /// One watch pass: report the outcome instead of propagating it.
+    fn regenerate(&self, config: &Config) {
+        match self.run(config) {
+            Ok(()) => self.diag.info(format!("[{}] Regenerated", timestamp())),
+            Err(err) => self
+                .diag
+                .error(format!("[{}] Generation failed: {:#}", timestamp(), err)),
+        }
+    }
+}
+
+/// A create/modify/remove of at least one file the scanner would read.
+fn is_relevant(event: &notify::Result<Event>, scanner: &Scanner) -> bool {
+    match event {
+        Ok(event) => {
+            !matches!(event.kind, EventKind::Access(_))
+                && event.paths.iter().any(|path| scanner.is_watched(path))
+        }
+        Err(err) => {
+            crate::diagnostics::warn(format!("File watcher error: {}", err));
+            false
+        }
+    }
+}
+
+/// Wall-clock `HH:MM:SS` (UTC).
+fn timestamp() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+        % 86_400;
+    format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60)
+}
@@ -4,16 +4,17 @@
 //! - Local type definitions
 //! - Explicit imports (use foo::Bar)
 //! - Wildcard imports (use foo::*)
+//! - Re-export chains (pub use inner::Thing as Alias, followed to the definition)
 //! - Relative paths (super::Bar, crate::foo::Bar)
 //! - Ambiguity detection
 
 mod helpers;
 mod imports;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
-use helpers::are_siblings;
+use helpers::{are_siblings, crate_root};
 
 /// Maps alias names to their original type names
 type AliasMap = HashMap<String, String>;
@@ -35,6 +36,46 @@ pub enum ResolutionResult {
     Ambiguous(Vec<PathBuf>),
 }
 
+/// Which rule of [`ModuleResolver::resolve_type`] decided the outcome;
+/// reported by `--explain`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolutionMethod {
+    /// Defined in the file that references it
+    Local,
+    /// Brought in by `use <path>`
+    Import(String),
+    /// Found in the module of a `use <module>::*`
+    Wildcard(String),
+    /// Written as a path (`crate::types::User`, `super::User`)
+    QualifiedPath,
+    /// No scope-based rule matched; looked up by name across the project
+    NameLookup,
+    /// Same as `NameLookup`, narrowed to a sibling module among several
+    /// definitions
+    SiblingPreference,
+    /// The referencing file has no scope (e.g. cargo-expand output)
+    GlobalDefinitions,
+}
+
+impl std::fmt::Display for ResolutionMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolutionMethod::Local => write!(f, "local definition"),
+            ResolutionMethod::Import(path) => write!(f, "import `use {}`", path),
+            ResolutionMethod::Wildcard(module) => write!(f, "wildcard import `use {}::*`", module),
+            ResolutionMethod::QualifiedPath => write!(f, "qualified path"),
+            ResolutionMethod::NameLookup => write!(f, "project-wide lookup by name"),
+            ResolutionMethod::SiblingPreference => {
+                write!(
+                    f,
+                    "project-wide lookup by name, preferring a sibling module"
+                )
+            }
+            ResolutionMethod::GlobalDefinitions => write!(f, "global definitions (no file scope)"),
+        }
+    }
+}
+
 /// Represents a parsed file with its imports and local types
 #[derive(Debug, Default)]
 pub struct FileScope {
@@ -48,6 +89,28 @@ pub struct FileScope {
     pub wildcard_imports: Vec<ModulePath>,
     /// Type aliases: alias name -> base type name (e.g., "AppStateMutexed" -> "State")
     pub type_aliases: AliasMap,
+    /// Inline `mod foo { ... }` blocks, relative to `module_path` (e.g.
+    /// ["foo"], ["foo", "bar"]). Their items count as the file's own
+    pub inline_modules: Vec<ModulePath>,
+}
+
+impl FileScope {
+    /// Move a scope scanned as part of the main crate under the crate
+    /// `name`, for files of a dependency: `crate::models` becomes
+    /// `name::models` in the module path and in every import.
+    pub fn in_crate(mut self, name: &str) -> Self {
+        let rename = |path: &mut ModulePath| {
+            if path.first().map(String::as_str) == Some("crate") {
+                path[0] = name.to_string();
+            }
+        };
+        rename(&mut self.module_path);
+        for imported in self.imports.values_mut() {
+            rename(&mut imported.path);
+        }
+        self.wildcard_imports.iter_mut().for_each(rename);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -96,8 +159,39 @@ impl ModuleResolver {
         self.files.get(path)
     }
 
+    /// The full path `type_path` names in `from_file`, following the
+    /// file's imports but not resolving anything: `Tz` after
+    /// `use chrono_tz::Tz` is `chrono_tz::Tz`, `s3::Object` after
+    /// `use aws_sdk_s3 as s3` is `aws_sdk_s3::Object`.
+    pub fn imported_path(&self, type_path: &str, from_file: &Path) -> Vec<String> {
+        let segments: Vec<&str> = type_path.split("::").filter(|s| !s.is_empty()).collect();
+        let imported = segments.first().and_then(|first| {
+            self.files
+                .get(from_file)
+                .and_then(|scope| scope.imports.get(*first))
+        });
+        match imported {
+            Some(imported) => {
+                let mut path = imported.path.clone();
+                path.extend(segments[1..].iter().map(|s| s.to_string()));
+                path
+            }
+            None => segments.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
     /// Resolve a type name in the context of a specific file
     pub fn resolve_type(&self, type_path: &str, from_file: &Path) -> ResolutionResult {
+        self.resolve_type_explained(type_path, from_file).0
+    }
+
+    /// Like [`ModuleResolver::resolve_type`], also reporting which rule
+    /// produced the result.
+    pub fn resolve_type_explained(
+        &self,
+        type_path: &str,
+        from_file: &Path,
+    ) -> (ResolutionResult, ResolutionMethod) {
         let segments: Vec<&str> = type_path.split("::").filter(|s| !s.is_empty()).collect();
         let type_name = segments.last().copied().unwrap_or("");
 
@@ -106,7 +200,10 @@ impl ModuleResolver {
             None => {
                 // No scope for this file (e.g., <cargo-expand>)
                 // Try to resolve from global type_definitions
-                return self.try_resolve_from_definitions(type_name);
+                return (
+                    self.try_resolve_from_definitions(type_name),
+                    ResolutionMethod::GlobalDefinitions,
+                );
             }
         };
 
@@ -117,7 +214,10 @@ impl ModuleResolver {
         }
 
         // Handle path (foo::Bar, super::Bar, crate::foo::Bar)
-        self.resolve_path(&segments, scope)
+        (
+            self.resolve_path(&segments, scope),
+            ResolutionMethod::QualifiedPath,
+        )
     }
 
     fn resolve_simple_name(
@@ -125,31 +225,42 @@ impl ModuleResolver {
         name: &str,
         scope: &FileScope,
         from_file: &Path,
-    ) -> ResolutionResult {
+    ) -> (ResolutionResult, ResolutionMethod) {
         // 1. Check local definition
         if scope.local_types.contains_key(name) {
-            return ResolutionResult::Found(from_file.to_path_buf());
+            return (
+                ResolutionResult::Found(from_file.to_path_buf()),
+                ResolutionMethod::Local,
+            );
         }
 
         // 2. Check explicit imports
         if let Some(imported) = scope.imports.get(name) {
             let result = self.resolve_module_path(&imported.path);
-            return self.wrap_alias_if_needed(result, name, &imported.path);
+            return (
+                self.wrap_alias_if_needed(result, name, &imported.path),
+                ResolutionMethod::Import(imported.path.join("::")),
+            );
         }
 
         // 3. Check wildcard imports
         for wildcard_path in &scope.wildcard_imports {
             // Normalize relative path to absolute path
-            let full_path = self.normalize_relative_path(wildcard_path, &scope.module_path);
-            if let Some(file) = self.find_type_in_module(name, &full_path) {
-                return ResolutionResult::Found(file);
+            let mut full_path = self.normalize_relative_path(wildcard_path, &scope.module_path);
+            full_path.push(name.to_string());
+            match self.find_reexported(&full_path, &mut HashSet::new()) {
+                ResolutionResult::NotFound => {}
+                found => return (found, ResolutionMethod::Wildcard(wildcard_path.join("::"))),
             }
         }
 
         // 4. Fallback: Lookup by name in entire workspace (Ambiguity Check)
         if let Some(locations) = self.type_definitions.get(name) {
             if locations.len() == 1 {
-                return ResolutionResult::Found(locations[0].clone());
+                return (
+                    ResolutionResult::Found(locations[0].clone()),
+                    ResolutionMethod::NameLookup,
+                );
             }
             // If multiple found, try to filter by proximity or return ambiguous
             let from_module = &scope.module_path;
@@ -167,13 +278,19 @@ impl ModuleResolver {
                 .collect();
 
             if siblings.len() == 1 {
-                return ResolutionResult::Found(siblings[0].clone());
+                return (
+                    ResolutionResult::Found(siblings[0].clone()),
+                    ResolutionMethod::SiblingPreference,
+                );
             }
 
-            return ResolutionResult::Ambiguous(locations.clone());
+            return (
+                ResolutionResult::Ambiguous(locations.clone()),
+                ResolutionMethod::NameLookup,
+            );
         }
 
-        ResolutionResult::NotFound
+        (ResolutionResult::NotFound, ResolutionMethod::NameLookup)
     }
 
     /// Wrap resolution result with alias information if the import used a rename
@@ -199,39 +316,41 @@ impl ModuleResolver {
     }
 
     fn resolve_path(&self, segments: &[&str], scope: &FileScope) -> ResolutionResult {
-        let first = segments[0];
+        match self.absolute_path(segments, scope) {
+            Some(path) => self.resolve_module_path(&path),
+            None => ResolutionResult::NotFound,
+        }
+    }
 
+    /// The absolute module path `segments` names from `scope`.
+    fn absolute_path(&self, segments: &[&str], scope: &FileScope) -> Option<Vec<String>> {
         // 1. Check if the first segment is an imported alias/module
-        if let Some(imported) = scope.imports.get(first) {
+        if let Some(imported) = scope.imports.get(segments[0]) {
             // e.g. use crate::utils::wrapper; AND path is wrapper::MyType
             // imported.path = ["crate", "utils", "wrapper"]
             // result path = ["crate", "utils", "wrapper", "MyType"]
             let mut full_path = imported.path.clone();
             full_path.extend(segments[1..].iter().map(|s| s.to_string()));
-            return self.resolve_module_path(&full_path);
+            return Some(full_path);
         }
 
         // 2. Standard canonical path resolution
-        let path_result = self.resolve_canonical_path(segments, scope);
-        match path_result {
-            Some(path) => self.resolve_module_path(&path),
-            None => ResolutionResult::NotFound,
-        }
+        self.resolve_canonical_path(segments, scope)
     }
 
     // Resolve any path tokens to an absolute module path ["crate", "foo", "Type"]
     fn resolve_canonical_path(&self, segments: &[&str], scope: &FileScope) -> Option<Vec<String>> {
-        let mut current_path = if segments[0] == "crate" {
-            vec!["crate".to_string()]
-        } else if segments[0] == "super" || segments[0] == "self" {
-            scope.module_path.clone()
+        let (mut current_path, iter_start) = if segments[0] == "crate" {
+            (vec![crate_root(&scope.module_path)], 1)
+        } else if self.is_crate_root(segments[0]) {
+            // `shared::models::User`, a scanned path dependency
+            (vec![segments[0].to_string()], 1)
         } else {
-            // Implicit relative path: `submod::Type` -> start from current module
-            scope.module_path.clone()
+            // `super::Type`, `self::Type` or an implicit relative path
+            // (`submod::Type`): start from the current module
+            (scope.module_path.clone(), 0)
         };
 
-        let iter_start = if segments[0] == "crate" { 1 } else { 0 };
-
         for segment in &segments[iter_start..] {
             match *segment {
                 "super" => {
@@ -255,7 +374,28 @@ impl ModuleResolver {
 
     /// Resolve an absolute path (["crate", "mod", "Type"]) to a file
     fn resolve_module_path(&self, module_path: &[String]) -> ResolutionResult {
-        if module_path.len() < 2 {
+        match self.find_reexported(module_path, &mut HashSet::new()) {
+            // Fallback: check type_definitions for types from cargo expand.
+            // This handles cases where the type is generated by a macro and
+            // registered globally
+            ResolutionResult::NotFound => match module_path.last() {
+                Some(type_name) => self.try_resolve_from_definitions(type_name),
+                None => ResolutionResult::NotFound,
+            },
+            found => found,
+        }
+    }
+
+    /// Find the file defining the type at an absolute path, following `pub
+    /// use` re-exports (explicit, renamed or wildcard) through as many
+    /// modules as it takes. `visited` holds the paths already tried, so a
+    /// re-export cycle ends in `NotFound`.
+    fn find_reexported(
+        &self,
+        module_path: &[String],
+        visited: &mut HashSet<ModulePath>,
+    ) -> ResolutionResult {
+        if module_path.len() < 2 || !visited.insert(module_path.to_vec()) {
             return ResolutionResult::NotFound;
         }
 
@@ -264,35 +404,49 @@ impl ModuleResolver {
         let type_name = &module_path[module_path.len() - 1];
         let mod_path = &module_path[..module_path.len() - 1];
 
-        if let Some(file_path) = self.module_to_file.get(mod_path) {
-            if let Some(scope) = self.files.get(file_path) {
-                // 1. Check local definition
-                if scope.local_types.contains_key(type_name) {
-                    return ResolutionResult::Found(file_path.clone());
-                }
+        let Some(file_path) = self.module_to_file.get(mod_path) else {
+            return ResolutionResult::NotFound;
+        };
+        let Some(scope) = self.files.get(file_path) else {
+            return ResolutionResult::NotFound;
+        };
 
-                // 2. Check re-exports (imports via pub use or use)
-                if let Some(imported) = scope.imports.get(type_name) {
-                    // Recursively resolve the imported path relative to THIS module
-                    let segments: Vec<&str> = imported.path.iter().map(|s| s.as_str()).collect();
-                    let result = self.resolve_path(&segments, scope);
-                    return self.wrap_alias_if_needed(result, type_name, &imported.path);
-                }
+        // 1. Check local definition
+        if scope.local_types.contains_key(type_name) {
+            return ResolutionResult::Found(file_path.clone());
+        }
 
-                // 3. Check wildcard re-exports (pub use submod::*)
-                for wildcard_path in &scope.wildcard_imports {
-                    // Normalize relative path to absolute path
-                    let full_path = self.normalize_relative_path(wildcard_path, &scope.module_path);
-                    if let Some(found_file) = self.find_type_in_module(type_name, &full_path) {
-                        return ResolutionResult::Found(found_file);
-                    }
-                }
+        // 2. Check re-exports (imports via pub use or use), resolving the
+        // imported path relative to THIS module
+        if let Some(imported) = scope.imports.get(type_name) {
+            let segments: Vec<&str> = imported.path.iter().map(|s| s.as_str()).collect();
+            let result = match self.absolute_path(&segments, scope) {
+                Some(path) => self.find_reexported(&path, visited),
+                None => ResolutionResult::NotFound,
+            };
+            return self.wrap_alias_if_needed(result, type_name, &imported.path);
+        }
+
+        // 3. Check wildcard re-exports (pub use submod::*)
+        for wildcard_path in &scope.wildcard_imports {
+            let mut full_path = self.normalize_relative_path(wildcard_path, &scope.module_path);
+            full_path.push(type_name.clone());
+            match self.find_reexported(&full_path, visited) {
+                ResolutionResult::NotFound => {}
+                found => return found,
             }
         }
 
-        // 4. Fallback: check type_definitions for types from cargo expand
-        // This handles cases where the type is generated by a macro and registered globally
-        self.try_resolve_from_definitions(type_name)
+        ResolutionResult::NotFound
+    }
+
+    /// Whether `name` is the root of a scanned crate other than the main
+    /// one (a path dependency)
+    fn is_crate_root(&self, name: &str) -> bool {
+        name != "crate"
+            && self
+                .module_to_file
+                .contains_key([name.to_string()].as_slice())
     }
 
     /// Try to resolve a type from global type_definitions (cargo expand types)
@@ -318,9 +472,16 @@ impl ModuleResolver {
             return from_module.to_vec();
         }
 
-        // If path starts with "crate", it's already absolute
-        if relative_path.first().map(|s| s.as_str()) == Some("crate") {
-            return relative_path.to_vec();
+        // If path starts with "crate" or a dependency's name, it's
+        // already absolute
+        match relative_path[0].as_str() {
+            "crate" => {
+                let mut path = relative_path.to_vec();
+                path[0] = crate_root(from_module);
+                return path;
+            }
+            first if self.is_crate_root(first) => return relative_path.to_vec(),
+            _ => {}
         }
 
         // Handle super:: and self:: in relative paths
@@ -343,18 +504,6 @@ impl ModuleResolver {
         result
     }
 
-    /// Find type in module (for wildcard imports)
-    fn find_type_in_module(&self, type_name: &str, module_path: &[String]) -> Option<PathBuf> {
-        if let Some(file_path) = self.module_to_file.get(module_path) {
-            if let Some(scope) = self.files.get(file_path) {
-                if scope.local_types.contains_key(type_name) {
-                    return Some(file_path.clone());
-                }
-            }
-        }
-        None
-    }
-
     /// Resolve a type alias to its final target base type name (follows alias chains)
     /// For example, given "AliasedState" where:
     ///   `type MyState<'a> = State<'a, AppState>;`
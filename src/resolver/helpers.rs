@@ -2,6 +2,15 @@
 //! type-name extraction. Kept separate so `resolver.rs` stays focused on the
 //! `ModuleResolver` impl.
 
+/// First segment of a module path: `crate` in the main crate, the
+/// dependency's name in a path dependency
+pub(super) fn crate_root(module_path: &[String]) -> String {
+    module_path
+        .first()
+        .cloned()
+        .unwrap_or_else(|| "crate".to_string())
+}
+
 pub(super) fn are_siblings(path_a: &[String], path_b: &[String]) -> bool {
     // Empty paths or single-element paths can't be siblings
     if path_a.len() < 2 || path_b.len() < 2 {
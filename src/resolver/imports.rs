@@ -26,47 +26,90 @@ impl ModuleResolver {
         }
     }
 
-    /// Parse a file and extract its scope (imports, local types, submodules)
+    /// Parse a file and extract its scope (imports, local types, submodules).
+    ///
+    /// Re-parsing a path that was seen before replaces everything it
+    /// contributed, so a type that moved out of the file no longer
+    /// resolves to it.
     pub fn parse_file(&mut self, path: &Path, content: &str, base_path: &Path) -> Result<()> {
+        let scope = Self::scan_file(path, content, base_path)?;
+        self.add_file(path, scope);
+        Ok(())
+    }
+
+    /// The scope of one file, without touching any resolver. Files can be
+    /// scanned in parallel and then registered one by one with
+    /// [`ModuleResolver::add_file`].
+    pub fn scan_file(path: &Path, content: &str, base_path: &Path) -> Result<FileScope> {
         let syntax = syn::parse_file(content)?;
 
         let mut scope = FileScope {
-            module_path: self.path_to_module(path, base_path),
+            module_path: Self::path_to_module(path, base_path),
             ..Default::default()
         };
 
         // Process items, including nested modules
-        self.parse_items(&syntax.items, path, &mut scope);
+        Self::parse_items(&syntax.items, &mut scope, &mut Vec::new());
+        Ok(scope)
+    }
+
+    /// Register a scanned file, replacing whatever `path` contributed
+    /// before.
+    pub fn add_file(&mut self, path: &Path, scope: FileScope) {
+        self.forget_file(path);
 
+        for name in scope.local_types.keys() {
+            self.register_type_definition(name, path);
+        }
         self.module_to_file
             .insert(scope.module_path.clone(), path.to_path_buf());
+        for inline in &scope.inline_modules {
+            let mut module_path = scope.module_path.clone();
+            module_path.extend(inline.iter().cloned());
+            self.module_to_file.insert(module_path, path.to_path_buf());
+        }
         self.files.insert(path.to_path_buf(), scope);
+    }
 
-        Ok(())
+    /// Drop every type location and module mapping recorded for `path`,
+    /// e.g. after the file was deleted or before it is parsed again.
+    pub fn forget_file(&mut self, path: &Path) {
+        if self.files.remove(path).is_none() {
+            return;
+        }
+        self.type_definitions.retain(|_, locations| {
+            locations.retain(|location| location != path);
+            !locations.is_empty()
+        });
+        self.module_to_file.retain(|_, file| file != path);
     }
 
-    /// Parse items recursively (handles nested modules)
-    pub(super) fn parse_items(&mut self, items: &[Item], path: &Path, scope: &mut FileScope) {
+    /// Parse items recursively (handles nested modules). `inline_path` is
+    /// the inline module the items are in, relative to the file.
+    pub(super) fn parse_items(
+        items: &[Item],
+        scope: &mut FileScope,
+        inline_path: &mut Vec<String>,
+    ) {
         for item in items {
             match item {
                 Item::Use(item_use) => {
-                    self.parse_use_tree(&item_use.tree, scope, &mut Vec::new());
+                    Self::parse_use_tree(&item_use.tree, scope, &mut Vec::new());
                 }
                 Item::Struct(s) => {
-                    let name = s.ident.to_string();
-                    scope.local_types.insert(name.clone(), TypeKind::Struct);
-                    self.register_type_definition(&name, path);
+                    scope
+                        .local_types
+                        .insert(s.ident.to_string(), TypeKind::Struct);
                 }
                 Item::Enum(e) => {
-                    let name = e.ident.to_string();
-                    scope.local_types.insert(name.clone(), TypeKind::Enum);
-                    self.register_type_definition(&name, path);
+                    scope
+                        .local_types
+                        .insert(e.ident.to_string(), TypeKind::Enum);
                 }
                 Item::Type(t) => {
                     // Handle type aliases: type Foo = Bar;
                     let name = t.ident.to_string();
                     scope.local_types.insert(name.clone(), TypeKind::Struct); // Treat as struct-like
-                    self.register_type_definition(&name, path);
 
                     // Extract the base type name (e.g., "State" from "State<'a, T>")
                     if let Some(base_type) = extract_base_type_name(&t.ty) {
@@ -74,9 +117,13 @@ impl ModuleResolver {
                     }
                 }
                 Item::Mod(m) => {
-                    // Recursively parse types inside inline modules
+                    // Recursively parse types inside inline modules, which
+                    // are then reachable as `file_module::m::Type` too
                     if let Some((_, mod_items)) = &m.content {
-                        self.parse_items(mod_items, path, scope);
+                        inline_path.push(m.ident.to_string());
+                        scope.inline_modules.push(inline_path.clone());
+                        Self::parse_items(mod_items, scope, inline_path);
+                        inline_path.pop();
                     }
                 }
                 _ => {}
@@ -94,16 +141,11 @@ impl ModuleResolver {
     }
 
     /// Parse use tree recursively
-    pub(super) fn parse_use_tree(
-        &self,
-        tree: &UseTree,
-        scope: &mut FileScope,
-        prefix: &mut Vec<String>,
-    ) {
+    pub(super) fn parse_use_tree(tree: &UseTree, scope: &mut FileScope, prefix: &mut Vec<String>) {
         match tree {
             UseTree::Path(path) => {
                 prefix.push(path.ident.to_string());
-                self.parse_use_tree(&path.tree, scope, prefix);
+                Self::parse_use_tree(&path.tree, scope, prefix);
                 prefix.pop();
             }
             UseTree::Name(name) => {
@@ -134,14 +176,14 @@ impl ModuleResolver {
             }
             UseTree::Group(group) => {
                 for item in &group.items {
-                    self.parse_use_tree(item, scope, prefix);
+                    Self::parse_use_tree(item, scope, prefix);
                 }
             }
         }
     }
 
     /// Convert file path to module path
-    pub(super) fn path_to_module(&self, path: &Path, base_path: &Path) -> Vec<String> {
+    pub(super) fn path_to_module(path: &Path, base_path: &Path) -> Vec<String> {
         let relative = path.strip_prefix(base_path).unwrap_or(path);
         let mut parts: Vec<String> = vec!["crate".to_string()];
 
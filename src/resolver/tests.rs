@@ -426,6 +426,38 @@ fn test_parse_nested_module() {
     assert!(resolver.type_definitions.contains_key("OuterType"));
 }
 
+#[test]
+fn test_resolve_type_in_inline_module_by_path() {
+    let mut resolver = ModuleResolver::new();
+
+    // Two `Bar`s: the name alone is ambiguous, the path is not
+    let lib_path = PathBuf::from("src/lib.rs");
+    resolver
+        .parse_file(
+            &lib_path,
+            "mod models { pub mod nested { pub struct Bar; } }",
+            &base_path(),
+        )
+        .unwrap();
+    let other_path = PathBuf::from("src/other.rs");
+    resolver
+        .parse_file(&other_path, "pub struct Bar;", &base_path())
+        .unwrap();
+    let cmd_path = PathBuf::from("src/commands.rs");
+    resolver
+        .parse_file(&cmd_path, "use crate::models::nested::Bar;", &base_path())
+        .unwrap();
+
+    assert_eq!(
+        resolver.resolve_type("Bar", &cmd_path),
+        ResolutionResult::Found(lib_path.clone())
+    );
+    assert_eq!(
+        resolver.resolve_type("crate::models::nested::Bar", &other_path),
+        ResolutionResult::Found(lib_path)
+    );
+}
+
 #[test]
 fn test_parse_type_alias() {
     let mut resolver = ModuleResolver::new();
@@ -789,3 +821,212 @@ fn test_macro_generated_types_can_be_resolved() {
         res => panic!("Expected Found for macro-generated type, got {:?}", res),
     }
 }
+
+#[test]
+fn test_reparse_after_type_moves_between_files() {
+    let mut resolver = ModuleResolver::new();
+    let a_path = PathBuf::from("src/a.rs");
+    let b_path = PathBuf::from("src/b.rs");
+    let cmd_path = PathBuf::from("src/commands.rs");
+
+    // First run: `User` lives in a.rs
+    resolver
+        .parse_file(&a_path, "pub struct User;", &base_path())
+        .unwrap();
+    resolver.parse_file(&b_path, "", &base_path()).unwrap();
+    resolver.parse_file(&cmd_path, "", &base_path()).unwrap();
+    assert_eq!(
+        resolver.resolve_type("User", &cmd_path),
+        ResolutionResult::Found(a_path.clone())
+    );
+
+    // Second run: the definition moved to b.rs. The stale a.rs location
+    // must not linger and make the lookup ambiguous.
+    resolver.parse_file(&a_path, "", &base_path()).unwrap();
+    resolver
+        .parse_file(&b_path, "pub struct User;", &base_path())
+        .unwrap();
+    assert_eq!(
+        resolver.resolve_type("User", &cmd_path),
+        ResolutionResult::Found(b_path.clone())
+    );
+    assert_eq!(resolver.type_definitions_for("User"), Some(&[b_path][..]));
+}
+
+#[test]
+fn test_forget_deleted_file() {
+    let mut resolver = ModuleResolver::new();
+    let types_path = PathBuf::from("src/types.rs");
+    let cmd_path = PathBuf::from("src/commands.rs");
+
+    resolver
+        .parse_file(&types_path, "pub struct User;", &base_path())
+        .unwrap();
+    resolver.parse_file(&cmd_path, "", &base_path()).unwrap();
+
+    resolver.forget_file(&types_path);
+
+    assert_eq!(
+        resolver.resolve_type("User", &cmd_path),
+        ResolutionResult::NotFound
+    );
+    assert!(resolver.type_definitions_for("User").is_none());
+    assert!(resolver.file_scope(&types_path).is_none());
+}
+
+#[test]
+fn test_resolve_type_alias_across_files() {
+    let mut resolver = ModuleResolver::new();
+    let ids_path = PathBuf::from("src/ids.rs");
+    let cmd_path = PathBuf::from("src/commands.rs");
+
+    resolver
+        .parse_file(&ids_path, "pub type UserId = i32;", &base_path())
+        .unwrap();
+    resolver
+        .parse_file(&cmd_path, "use crate::ids::UserId;", &base_path())
+        .unwrap();
+
+    assert_eq!(
+        resolver.resolve_type("UserId", &cmd_path),
+        ResolutionResult::Found(ids_path)
+    );
+}
+
+#[test]
+fn test_resolve_type_explained_reports_method() {
+    let mut resolver = ModuleResolver::new();
+    let types_path = PathBuf::from("src/types.rs");
+    let cmd_path = PathBuf::from("src/commands.rs");
+    let glob_path = PathBuf::from("src/glob.rs");
+
+    resolver
+        .parse_file(
+            &types_path,
+            "pub struct User; pub struct Role;",
+            &base_path(),
+        )
+        .unwrap();
+    resolver
+        .parse_file(
+            &cmd_path,
+            "use crate::types::User; struct Local;",
+            &base_path(),
+        )
+        .unwrap();
+    resolver
+        .parse_file(&glob_path, "use crate::types::*;", &base_path())
+        .unwrap();
+
+    assert_eq!(
+        resolver.resolve_type_explained("User", &cmd_path),
+        (
+            ResolutionResult::Found(types_path.clone()),
+            ResolutionMethod::Import("crate::types::User".to_string())
+        )
+    );
+    assert_eq!(
+        resolver.resolve_type_explained("Local", &cmd_path),
+        (
+            ResolutionResult::Found(cmd_path.clone()),
+            ResolutionMethod::Local
+        )
+    );
+    assert_eq!(
+        resolver.resolve_type_explained("Role", &glob_path),
+        (
+            ResolutionResult::Found(types_path.clone()),
+            ResolutionMethod::Wildcard("crate::types".to_string())
+        )
+    );
+    assert_eq!(
+        resolver.resolve_type_explained("Role", &cmd_path),
+        (
+            ResolutionResult::Found(types_path),
+            ResolutionMethod::NameLookup
+        )
+    );
+}
+
+#[test]
+fn test_resolve_two_level_reexport_alias_chain() {
+    let mut resolver = ModuleResolver::new();
+
+    // src/inner/deeper.rs -> struct Thing
+    let deeper_path = PathBuf::from("src/inner/deeper.rs");
+    resolver
+        .parse_file(&deeper_path, "pub struct Thing;", &base_path())
+        .unwrap();
+
+    // src/inner/mod.rs -> pub use deeper::Thing as ThingAlias;
+    let inner_path = PathBuf::from("src/inner/mod.rs");
+    resolver
+        .parse_file(
+            &inner_path,
+            "pub mod deeper; pub use deeper::Thing as ThingAlias;",
+            &base_path(),
+        )
+        .unwrap();
+
+    // src/lib.rs -> pub use inner::ThingAlias; pub use inner::ThingAlias as Renamed;
+    let lib_path = PathBuf::from("src/lib.rs");
+    resolver
+        .parse_file(
+            &lib_path,
+            "pub mod inner; pub use inner::ThingAlias; pub use inner::ThingAlias as Renamed;",
+            &base_path(),
+        )
+        .unwrap();
+
+    // src/cmd.rs imports the chain's ends; src/glob.rs reaches it through a wildcard
+    let cmd_path = PathBuf::from("src/cmd.rs");
+    resolver
+        .parse_file(
+            &cmd_path,
+            "use crate::ThingAlias; use crate::Renamed;",
+            &base_path(),
+        )
+        .unwrap();
+    let glob_path = PathBuf::from("src/glob.rs");
+    resolver
+        .parse_file(&glob_path, "use crate::inner::*;", &base_path())
+        .unwrap();
+
+    let expected = ResolutionResult::FoundWithAlias(deeper_path, "Thing".to_string());
+    assert_eq!(resolver.resolve_type("ThingAlias", &cmd_path), expected);
+    assert_eq!(resolver.resolve_type("Renamed", &cmd_path), expected);
+    assert_eq!(
+        resolver.resolve_type("crate::ThingAlias", &cmd_path),
+        expected
+    );
+    assert_eq!(resolver.resolve_type("ThingAlias", &glob_path), expected);
+}
+
+#[test]
+fn test_reexport_cycle_terminates() {
+    let mut resolver = ModuleResolver::new();
+
+    // src/a.rs and src/b.rs re-export `Ghost` from each other; nobody defines it
+    let a_path = PathBuf::from("src/a.rs");
+    let b_path = PathBuf::from("src/b.rs");
+    resolver
+        .parse_file(&a_path, "pub use crate::b::Ghost;", &base_path())
+        .unwrap();
+    resolver
+        .parse_file(
+            &b_path,
+            "pub use crate::a::Ghost; pub use crate::a::*;",
+            &base_path(),
+        )
+        .unwrap();
+
+    let cmd_path = PathBuf::from("src/cmd.rs");
+    resolver
+        .parse_file(&cmd_path, "use crate::a::Ghost;", &base_path())
+        .unwrap();
+
+    assert_eq!(
+        resolver.resolve_type("Ghost", &cmd_path),
+        ResolutionResult::NotFound
+    );
+}
@@ -1,4 +1,5 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
 use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
@@ -8,6 +9,16 @@ pub struct Scanner {
     source_dir: PathBuf,
     /// Patterns to exclude
     exclude_patterns: Vec<String>,
+    /// Glob patterns a file must match (relative to `source_dir`) to be
+    /// scanned; `None` scans every file
+    include: Option<GlobSet>,
+    /// `source_dir` made absolute, for matching the absolute paths the
+    /// file watcher reports against `include`
+    canonical_source_dir: Option<PathBuf>,
+    /// Files, or whole directories, to skip, matched by path prefix
+    excluded_paths: Vec<PathBuf>,
+    /// Descend into symlinked directories
+    follow_symlinks: bool,
 }
 
 impl Scanner {
@@ -16,22 +27,73 @@ impl Scanner {
         Scanner {
             source_dir,
             exclude_patterns,
+            include: None,
+            canonical_source_dir: None,
+            excluded_paths: Vec::new(),
+            follow_symlinks: false,
         }
     }
 
+    /// Descend into symlinked directories. Off by default; when on, a
+    /// symlink pointing back at one of its ancestors is reported and
+    /// skipped rather than followed forever.
+    pub fn follow_symlinks(mut self, follow: bool) -> Self {
+        self.follow_symlinks = follow;
+        self
+    }
+
+    /// Only scan files matching at least one of the glob `patterns`,
+    /// written relative to `source_dir` (`**/commands/*.rs`). `*` stops at
+    /// `/`; use `**` to cross directories. Exclude patterns still win.
+    /// An empty list keeps the default of scanning every file.
+    pub fn include(mut self, patterns: &[String]) -> Result<Self> {
+        if patterns.is_empty() {
+            return Ok(self);
+        }
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("Invalid include pattern: {}", pattern))?;
+            builder.add(glob);
+        }
+        self.include = Some(builder.build()?);
+        self.canonical_source_dir = self.source_dir.canonicalize().ok();
+        Ok(self)
+    }
+
+    /// Skip `path`: one file, or everything under a directory. Unlike the
+    /// name-based exclude patterns, this matches one concrete path — used
+    /// to keep the generator's own output files out of the scan.
+    pub fn exclude_path(mut self, path: PathBuf) -> Self {
+        self.excluded_paths.push(path);
+        self
+    }
+
     /// Scan for all Rust source files
     pub fn scan(&self) -> Result<Vec<PathBuf>> {
         let mut rust_files = Vec::new();
 
         for entry in WalkDir::new(&self.source_dir)
-            .follow_links(true)
+            .follow_links(self.follow_symlinks)
             .into_iter()
             .filter_entry(|e| !self.is_excluded(e.path()))
         {
-            let entry = entry?;
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(err) if err.loop_ancestor().is_some() => {
+                    crate::diagnostics::warn(format!(
+                        "Skipping symlink cycle at {}",
+                        err.path().unwrap_or(Path::new("?")).display()
+                    ));
+                    continue;
+                }
+                Err(err) => return Err(err.into()),
+            };
             let path = entry.path();
 
-            if path.is_file() && self.is_rust_file(path) {
+            if path.is_file() && self.is_rust_file(path) && self.is_included(path) {
                 rust_files.push(path.to_path_buf());
             }
         }
@@ -39,14 +101,45 @@ impl Scanner {
         Ok(rust_files)
     }
 
+    /// Whether a change to `path` can affect a scan: an included `.rs`
+    /// file outside every excluded directory. The path need not exist, so
+    /// deletions count too.
+    pub fn is_watched(&self, path: &Path) -> bool {
+        self.is_rust_file(path) && !self.is_excluded(path) && self.is_included(path)
+    }
+
     /// Check if a path is a Rust source file
     fn is_rust_file(&self, path: &Path) -> bool {
         path.extension().map(|ext| ext == "rs").unwrap_or(false)
     }
 
+    /// Check if a file matches the include globs (always true without any)
+    fn is_included(&self, path: &Path) -> bool {
+        let Some(include) = &self.include else {
+            return true;
+        };
+        let relative = path
+            .strip_prefix(&self.source_dir)
+            .ok()
+            .or_else(|| {
+                let canonical = self.canonical_source_dir.as_ref()?;
+                path.strip_prefix(canonical).ok()
+            })
+            .unwrap_or(path);
+        include.is_match(relative)
+    }
+
     /// Check if a path should be excluded
     /// Uses component-based matching for more precise exclusion
     fn is_excluded(&self, path: &Path) -> bool {
+        if self
+            .excluded_paths
+            .iter()
+            .any(|excluded| path.starts_with(excluded))
+        {
+            return true;
+        }
+
         for pattern in &self.exclude_patterns {
             // Check each path component for an exact match
             for component in path.components() {
@@ -113,6 +206,54 @@ mod tests {
         assert!(scanner.is_excluded(Path::new("foo/tests/bar.rs")));
     }
 
+    #[test]
+    fn test_is_watched() {
+        let scanner = Scanner::new(PathBuf::from("."), vec!["target".to_string()]);
+
+        assert!(scanner.is_watched(Path::new("src/deleted.rs")));
+        assert!(!scanner.is_watched(Path::new("target/debug/build.rs")));
+        assert!(!scanner.is_watched(Path::new("src/bindings/types.ts")));
+    }
+
+    #[test]
+    fn test_include_restricts_scan_to_matching_subdirectory() {
+        let dir = tempdir().unwrap();
+        let commands = dir.path().join("api").join("commands");
+        fs::create_dir_all(&commands).unwrap();
+        fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+        fs::write(commands.join("user.rs"), "pub fn a() {}").unwrap();
+        fs::write(dir.path().join("api").join("models.rs"), "").unwrap();
+
+        let scanner = Scanner::new(dir.path().to_path_buf(), vec![])
+            .include(&["**/commands/*.rs".to_string()])
+            .unwrap();
+        let files = scanner.scan().unwrap();
+
+        assert_eq!(files, vec![commands.join("user.rs")]);
+    }
+
+    #[test]
+    fn test_exclude_overrides_include() {
+        let dir = tempdir().unwrap();
+        let generated = dir.path().join("commands").join("generated");
+        fs::create_dir_all(&generated).unwrap();
+        fs::write(dir.path().join("commands").join("user.rs"), "").unwrap();
+        fs::write(generated.join("auto.rs"), "").unwrap();
+
+        let scanner = Scanner::new(dir.path().to_path_buf(), vec!["generated".to_string()])
+            .include(&["commands/**/*.rs".to_string()])
+            .unwrap();
+        let files = scanner.scan().unwrap();
+
+        assert_eq!(files, vec![dir.path().join("commands").join("user.rs")]);
+    }
+
+    #[test]
+    fn test_invalid_include_pattern_is_an_error() {
+        let result = Scanner::new(PathBuf::from("."), vec![]).include(&["src/[".to_string()]);
+        assert!(result.is_err());
+    }
+
     #[test]
     fn test_is_excluded_empty_patterns() {
         let scanner = Scanner::new(PathBuf::from("."), vec![]);
@@ -226,6 +367,43 @@ mod tests {
         assert!(files[0].file_name().unwrap() == "main.rs");
     }
 
+    #[test]
+    fn test_scan_skips_excluded_dir() {
+        let dir = tempdir().unwrap();
+
+        fs::write(dir.path().join("main.rs"), "fn main() {}").unwrap();
+
+        let generated = dir.path().join("generated");
+        fs::create_dir_all(&generated).unwrap();
+        fs::write(generated.join("bindings.rs"), "// generated").unwrap();
+
+        // A sibling that merely shares a name prefix is still scanned.
+        let generated_more = dir.path().join("generated_more");
+        fs::create_dir_all(&generated_more).unwrap();
+        fs::write(generated_more.join("kept.rs"), "// kept").unwrap();
+
+        let scanner = Scanner::new(dir.path().to_path_buf(), vec![]).exclude_path(generated);
+        let mut files = scanner.scan().unwrap();
+        files.sort();
+
+        assert_eq!(files.len(), 2);
+        assert!(files.iter().any(|p| p.file_name().unwrap() == "main.rs"));
+        assert!(files.iter().any(|p| p.file_name().unwrap() == "kept.rs"));
+    }
+
+    #[test]
+    fn test_scan_skips_excluded_file_but_not_its_siblings() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("bindings.rs"), "// generated").unwrap();
+        fs::write(dir.path().join("user.rs"), "pub fn a() {}").unwrap();
+
+        let scanner = Scanner::new(dir.path().to_path_buf(), vec![])
+            .exclude_path(dir.path().join("bindings.rs"));
+        let files = scanner.scan().unwrap();
+
+        assert_eq!(files, vec![dir.path().join("user.rs")]);
+    }
+
     #[test]
     fn test_scanner_new() {
         let scanner = Scanner::new(
@@ -236,4 +414,28 @@ mod tests {
         assert_eq!(scanner.source_dir, PathBuf::from("/some/path"));
         assert_eq!(scanner.exclude_patterns.len(), 2);
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_symlink_cycle_terminates() {
+        let dir = tempdir().unwrap();
+        let sub = dir.path().join("sub");
+        fs::create_dir_all(&sub).unwrap();
+        fs::write(sub.join("lib.rs"), "pub fn hello() {}").unwrap();
+        // sub/loop -> .. : following it would recurse forever.
+        std::os::unix::fs::symlink(dir.path(), sub.join("loop")).unwrap();
+
+        // Default: symlinked directories are not entered at all.
+        let files = Scanner::new(dir.path().to_path_buf(), vec![])
+            .scan()
+            .unwrap();
+        assert_eq!(files, vec![sub.join("lib.rs")]);
+
+        // Following links: the cycle is detected and skipped.
+        let files = Scanner::new(dir.path().to_path_buf(), vec![])
+            .follow_symlinks(true)
+            .scan()
+            .unwrap();
+        assert_eq!(files, vec![sub.join("lib.rs")]);
+    }
 }
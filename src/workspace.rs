@@ -0,0 +1,149 @@
+//! Path dependencies of the scanned crate, read from its `Cargo.toml`.
+//!
+//! Commands often name types from a sibling crate of the same workspace
+//! (`shared::User`). With `[input] path_dependencies` on, the pipeline
+//! scans each such crate's `src` as well, so those types resolve.
+
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use toml::Table;
+
+/// A crate the scanned one depends on through `path = "..."`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathDependency {
+    /// Name the dependency goes by in code (`shared-types` → `shared_types`)
+    pub name: String,
+    /// The dependency's `src` directory
+    pub source_dir: PathBuf,
+}
+
+/// The `[dependencies]` of `manifest` that point at a local crate, either
+/// directly (`shared = { path = "../shared" }`) or through the
+/// workspace (`shared = { workspace = true }` with a `path` in
+/// `[workspace.dependencies]`). Dependencies of those crates are not
+/// followed.
+pub fn path_dependencies(manifest: &Path) -> Result<Vec<PathDependency>> {
+    let table = read_manifest(manifest)?;
+    let crate_dir = manifest.parent().unwrap_or(Path::new("."));
+    let Some(dependencies) = table.get("dependencies").and_then(|d| d.as_table()) else {
+        return Ok(Vec::new());
+    };
+
+    let mut workspace: Option<(PathBuf, Table)> = None;
+    let mut found = Vec::new();
+    for (key, spec) in dependencies {
+        let Some(spec) = spec.as_table() else {
+            continue;
+        };
+        let dir = if let Some(path) = spec.get("path").and_then(|p| p.as_str()) {
+            crate_dir.join(path)
+        } else if spec.get("workspace").and_then(|w| w.as_bool()) == Some(true) {
+            if workspace.is_none() {
+                workspace = find_workspace_manifest(crate_dir)?;
+            }
+            let Some((root, table)) = &workspace else {
+                continue;
+            };
+            let path = table
+                .get("workspace")
+                .and_then(|w| w.get("dependencies"))
+                .and_then(|d| d.get(key))
+                .and_then(|d| d.get("path"))
+                .and_then(|p| p.as_str());
+            match path {
+                Some(path) => root.join(path),
+                None => continue,
+            }
+        } else {
+            continue;
+        };
+        found.push(PathDependency {
+            name: key.replace('-', "_"),
+            source_dir: dir.join("src"),
+        });
+    }
+    Ok(found)
+}
+
+fn read_manifest(manifest: &Path) -> Result<Table> {
+    let content = fs::read_to_string(manifest)
+        .with_context(|| format!("Failed to read {}", manifest.display()))?;
+    content
+        .parse::<Table>()
+        .with_context(|| format!("Failed to parse {}", manifest.display()))
+}
+
+/// The closest `Cargo.toml` above `crate_dir` with a `[workspace]`
+/// section, with its directory
+fn find_workspace_manifest(crate_dir: &Path) -> Result<Option<(PathBuf, Table)>> {
+    for dir in crate_dir.ancestors().skip(1) {
+        let manifest = dir.join("Cargo.toml");
+        if !manifest.exists() {
+            continue;
+        }
+        let table = read_manifest(&manifest)?;
+        if table.contains_key("workspace") {
+            return Ok(Some((dir.to_path_buf(), table)));
+        }
+    }
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_dependencies() {
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["app", "crates/*"]
+
+[workspace.dependencies]
+shared-models = { path = "crates/shared-models" }
+serde = "1"
+"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.join("app")).unwrap();
+        let manifest = root.join("app").join("Cargo.toml");
+        fs::write(
+            &manifest,
+            r#"
+[package]
+name = "app"
+
+[dependencies]
+serde = { workspace = true }
+shared-models = { workspace = true }
+api = { path = "../crates/api", package = "app-api" }
+tauri = { version = "2", features = ["tray-icon"] }
+
+[dev-dependencies]
+fixtures = { path = "../crates/fixtures" }
+"#,
+        )
+        .unwrap();
+
+        let mut deps = path_dependencies(&manifest).unwrap();
+        deps.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(
+            deps,
+            vec![
+                PathDependency {
+                    name: "api".to_string(),
+                    source_dir: root.join("app").join("../crates/api").join("src"),
+                },
+                PathDependency {
+                    name: "shared_models".to_string(),
+                    source_dir: root.join("crates/shared-models").join("src"),
+                },
+            ]
+        );
+    }
+}
@@ -6,6 +6,9 @@
 //! The derive macro itself is a no-op - it doesn't generate any runtime code.
 //! Its sole purpose is to make the Rust compiler accept `#[ts(...)]` attributes
 //! which are then parsed at code generation time by `tauri-ts-generator`.
+//!
+//! `#[tauri_event]` likewise only marks a type as an event payload for the
+//! generator and leaves the item untouched.
 
 use proc_macro::TokenStream;
 use quote::quote;
@@ -39,3 +42,26 @@ pub fn derive_ts(input: TokenStream) -> TokenStream {
     // Return empty token stream - this is a no-op derive
     TokenStream::from(quote! {})
 }
+
+/// Marks a struct or enum as the payload of a Tauri event.
+///
+/// This attribute is a no-op at compile time: it returns the item unchanged.
+/// `tauri-ts-generator` reads it to write a typed listener for the event
+/// into `[output] events_file`. Without `name`, the event is the type name
+/// in kebab-case (`UserUpdated` → `user-updated`).
+///
+/// # Example
+///
+/// ```rust
+/// use tauri_ts_generator_derive::tauri_event;
+///
+/// // Listened to with `onUserUpdated((payload) => ...)`
+/// #[tauri_event(name = "user-updated")]
+/// pub struct UserUpdated {
+///     pub id: u32,
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn tauri_event(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    item
+}
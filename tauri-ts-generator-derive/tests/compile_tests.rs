@@ -123,3 +123,19 @@ fn test_derive_with_serde_like_usage() {
     assert!(request.volume_normalized.is_some());
     assert!(request.volume_absolute.is_none());
 }
+
+/// Test: #[tauri_event] leaves the item as it was
+#[tauri_ts_generator_derive::tauri_event(name = "user-updated")]
+#[derive(Debug, PartialEq)]
+struct UserUpdated {
+    id: u32,
+}
+
+#[tauri_ts_generator_derive::tauri_event]
+struct AppReady;
+
+#[test]
+fn test_tauri_event_keeps_the_item() {
+    let _ = AppReady;
+    assert_eq!(UserUpdated { id: 1 }, UserUpdated { id: 1 });
+}
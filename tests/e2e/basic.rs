@@ -44,6 +44,9 @@ fn simple_struct_and_command() {
             "{TYPES_HEADER}\n\
             import {{ invoke }} from \"@tauri-apps/api/core\";\n\
             import type {{ User }} from \"./types\";\n\n\
+            /**\n\
+            \x20* @throws {{string}}\n\
+            \x20*/\n\
             export async function getUser(id: number): Promise<User> {{\n\
             \x20\x20return invoke<User>(\"get_user\", {{ id }});\n\
             }}\n"
@@ -145,6 +148,34 @@ fn types_only_reachable_from_commands_are_emitted() {
     );
 }
 
+#[test]
+fn slice_reference_arg_becomes_array_and_exports_element() {
+    // `&[User]` is a borrowed slice: serde deserializes it from a JSON
+    // array, and the element type must still be reached by the collector.
+    let project = Project::with_source(
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User { pub id: i32 }
+
+        #[tauri::command]
+        fn save_all(items: &[User]) -> Result<(), String> { todo!() }
+        "#,
+    );
+
+    run_generate_ok(&project);
+    let types = std::fs::read_to_string(&project.types_out).unwrap();
+    assert!(types.contains("export interface User"), "got:\n{types}");
+
+    let commands = std::fs::read_to_string(&project.commands_out).unwrap();
+    assert!(
+        commands.contains("export async function saveAll(items: User[]): Promise<void>"),
+        "got:\n{commands}"
+    );
+    assert!(commands.contains("import type { User } from \"./types\";"));
+}
+
 #[test]
 fn unsupported_type_produces_warning_with_readable_label() {
     // A trait object in a command arg isn't representable in TypeScript;
@@ -314,3 +345,40 @@ fn vec_option_hashmap_map_to_expected_ts() {
         "HashMap<String,i32>:\n{types}"
     );
 }
+
+#[test]
+fn set_fields_become_arrays_and_export_element_type() {
+    // Serde writes sets as JSON arrays; the element type is only reachable
+    // through the set, so the collector has to descend into it.
+    let project = Project::with_source(
+        r#"
+        use serde::{Deserialize, Serialize};
+        use std::collections::{BTreeSet, HashSet};
+
+        #[derive(Serialize, Deserialize, PartialEq, Eq, Hash)]
+        pub struct User { pub id: i32 }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Team {
+            pub members: HashSet<User>,
+            pub labels: BTreeSet<String>,
+        }
+
+        #[tauri::command]
+        fn get_team() -> Team { todo!() }
+        "#,
+    );
+
+    run_generate_ok(&project);
+    let types = std::fs::read_to_string(&project.types_out).unwrap();
+    assert!(
+        types.contains("members: User[];"),
+        "HashSet<User>:\n{types}"
+    );
+    assert!(
+        types.contains("labels: string[];"),
+        "BTreeSet<String>:\n{types}"
+    );
+    assert!(types.contains("export interface User {"), "got:\n{types}");
+    assert!(!types.contains("HashSet"), "got:\n{types}");
+}
@@ -0,0 +1,64 @@
+//! `check` renders the bindings in memory and fails when the files on disk
+//! don't match, without writing anything.
+
+use std::fs;
+
+use crate::helpers::{assert_contains, run_check, run_generate_ok, Project};
+
+const SOURCE: &str = r#"
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    pub struct User { pub id: i32 }
+
+    #[tauri::command]
+    fn get_user() -> User { todo!() }
+"#;
+
+#[test]
+fn check_passes_right_after_generate() {
+    let project = Project::with_source(SOURCE);
+    run_generate_ok(&project);
+
+    let output = run_check(&project);
+    assert!(
+        output.status.success(),
+        "fresh output should pass; stderr:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn check_fails_on_edited_file_and_leaves_it_alone() {
+    let project = Project::with_source(SOURCE);
+    run_generate_ok(&project);
+
+    let types = fs::read_to_string(&project.types_out).unwrap();
+    let edited = types.replace("id: number", "id: string");
+    fs::write(&project.types_out, &edited).unwrap();
+
+    let output = run_check(&project);
+    assert!(!output.status.success(), "stale types.ts must fail");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(&stderr, "types.ts: differs at line");
+    assert_contains(&stderr, "-   id: string;");
+    assert_contains(&stderr, "+   id: number;");
+    assert!(!stderr.contains("commands.ts:"), "got:\n{stderr}");
+
+    assert_eq!(fs::read_to_string(&project.types_out).unwrap(), edited);
+}
+
+#[test]
+fn check_reports_missing_files_without_creating_them() {
+    let project = Project::with_source(SOURCE);
+    let generated_dir = project.types_out.parent().unwrap();
+    fs::remove_dir_all(generated_dir).unwrap();
+
+    let output = run_check(&project);
+    assert!(!output.status.success(), "missing output must fail");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(&stderr, "types.ts: missing");
+    assert_contains(&stderr, "commands.ts: missing");
+
+    assert!(!generated_dir.exists(), "check must not create directories");
+}
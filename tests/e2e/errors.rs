@@ -1,9 +1,11 @@
 //! CLI should fail loudly on user errors (bad config, conflicts) and warn
-//! without failing on unresolvable types.
+//! without failing on unresolvable types, unless `--strict`.
 
 use std::fs;
 
-use crate::helpers::{assert_contains, binary_path, run_generate_err, Project};
+use crate::helpers::{
+    assert_contains, binary_path, run_generate_err, run_generate_with_args, Project,
+};
 
 #[test]
 fn missing_config_fails_with_actionable_error() {
@@ -107,7 +109,293 @@ fn unresolved_type_warns_but_does_not_fail() {
             || combined.to_lowercase().contains("could not be resolved"),
         "expected a mention of the unresolved type:\n{combined}"
     );
-    // Generator should still have emitted files.
+    // Generator should still have emitted files, with the type as
+    // `unknown` rather than a name nothing declares.
     assert!(fs::metadata(&project.types_out).is_ok());
-    assert!(fs::metadata(&project.commands_out).is_ok());
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "export async function x(): Promise<unknown> {");
+    assert!(!commands.contains("Mystery"), "got:\n{commands}");
+}
+
+#[test]
+fn strict_fails_on_unresolved_types() {
+    let project = Project::with_source(
+        r#"
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        pub struct Page { pub items: Vec<external::Item> }
+
+        #[tauri::command]
+        fn x() -> Result<crate::unknown::Mystery, String> { todo!() }
+
+        #[tauri::command]
+        fn page() -> Page { todo!() }
+        "#,
+    );
+
+    let output = crate::helpers::run_generate_with_args(&project, &["--strict"]);
+    assert!(
+        !output.status.success(),
+        "--strict must fail on unresolved types. stdout:\n{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(
+        &stderr,
+        "'Mystery' (used by command 'x' in src-tauri/src/lib.rs)",
+    );
+    assert_contains(
+        &stderr,
+        "'Item' (used by type 'Page' in src-tauri/src/lib.rs)",
+    );
+    assert!(
+        fs::metadata(&project.commands_out).is_err(),
+        "nothing should be written"
+    );
+}
+
+#[test]
+fn external_crate_prefixes_are_typed_unknown_silently() {
+    let project = Project::with_source(
+        r#"
+        use chrono_tz::Tz;
+        use aws_sdk_s3 as s3;
+
+        #[tauri::command]
+        fn zone() -> Tz { todo!() }
+
+        #[tauri::command]
+        fn object() -> s3::types::Object { todo!() }
+        "#,
+    );
+    project.write_config("external_crate_prefixes = [\"chrono_tz\", \"aws_sdk_s3::types\"]\n");
+
+    let output = crate::helpers::run_generate_with_args(&project, &["--strict"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr:\n{stderr}");
+    assert!(
+        !stderr.contains("could not be resolved"),
+        "external types must not be reported:\n{stderr}"
+    );
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(
+        &commands,
+        "export async function zone(): Promise<unknown> {",
+    );
+    assert_contains(
+        &commands,
+        "export async function object(): Promise<unknown> {",
+    );
+
+    // A prefix only matches whole path segments
+    project.write_config("external_crate_prefixes = [\"chrono\", \"aws_sdk_s3::types\"]\n");
+    let output = crate::helpers::run_generate_ok(&project);
+    assert_contains(
+        &String::from_utf8_lossy(&output.stderr),
+        "'Tz' (used by command 'zone' in src-tauri/src/lib.rs)",
+    );
+}
+
+#[test]
+fn non_serializable_error_type_throws_plain_error() {
+    // `std::io::Error` has no TypeScript counterpart: the `@throws` tag
+    // must fall back to `Error`, and the error type must neither be
+    // reported as unresolved nor leak into the imports.
+    let project = Project::with_source(
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User { pub id: i32 }
+
+        #[tauri::command]
+        fn load_user() -> Result<User, std::io::Error> { todo!() }
+        "#,
+    );
+
+    let output = crate::helpers::run_generate_ok(&project);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        !stderr.contains("could not be resolved"),
+        "error type must not be reported as unresolved:\n{stderr}"
+    );
+
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(
+        &commands,
+        "/**\n * @throws {Error}\n */\nexport async function loadUser(): Promise<User> {",
+    );
+    assert_contains(&commands, "import type { User } from \"./types\";");
+    assert!(!commands.contains("io::Error"), "got:\n{commands}");
+}
+
+#[test]
+fn camel_case_function_name_collision_errors() {
+    // `get_user` and `getUser` are distinct invoke names, so the raw
+    // duplicate check passes, but both become `getUser` in TypeScript.
+    let project = Project::with_source(
+        r#"
+        #[tauri::command]
+        fn get_user() -> Result<(), String> { todo!() }
+        "#,
+    );
+    project.add_source(
+        "other.rs",
+        r#"
+        #[tauri::command]
+        #[allow(non_snake_case)]
+        fn getUser() -> Result<(), String> { todo!() }
+        "#,
+    );
+
+    let output = run_generate_err(&project);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(&stderr, "collide");
+    assert_contains(&stderr, "'getUser' would be generated by:");
+    assert_contains(&stderr, "'get_user' in");
+    assert_contains(&stderr, "'getUser' in");
+}
+
+#[test]
+fn generic_command_is_skipped_with_warning() {
+    // `T` is chosen by the Rust caller; there is no TypeScript type to
+    // put in `Promise<T>`, so the command is left out and the user told.
+    let project = Project::with_source(
+        r#"
+        #[tauri::command]
+        fn get<T: serde::de::DeserializeOwned>(key: String) -> T { todo!() }
+
+        #[tauri::command]
+        fn ping() -> String { todo!() }
+        "#,
+    );
+
+    let output = crate::helpers::run_generate_ok(&project);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(&stderr, "Skipping generic command 'get'");
+    assert_contains(&stderr, "type parameter(s) T");
+
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "export async function ping()");
+    assert!(!commands.contains("function get("), "got:\n{commands}");
+}
+
+#[test]
+fn command_shadowing_js_global_warns() {
+    let project = Project::with_source(
+        r#"
+        #[tauri::command]
+        fn fetch(url: String) -> String { todo!() }
+
+        #[tauri::command]
+        fn fetch_user() -> String { todo!() }
+        "#,
+    );
+
+    let output = crate::helpers::run_generate_ok(&project);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(
+        &stderr,
+        "Command 'fetch' in src-tauri/src/lib.rs generates a function 'fetch' that shadows",
+    );
+    assert!(!stderr.contains("'fetchUser'"), "got:\n{stderr}");
+
+    // Still generated: the warning is advisory
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "export async function fetch(url: string)");
+}
+
+#[test]
+fn unkeyable_map_key_warns_once_with_location() {
+    let project = Project::with_source(
+        r#"
+        use std::collections::HashMap;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize, Hash, PartialEq, Eq)]
+        pub struct Point { pub x: i32, pub y: i32 }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Board {
+            pub cells: HashMap<Point, String>,
+            pub marks: HashMap<Point, bool>,
+        }
+
+        #[tauri::command]
+        fn board(extra: HashMap<Point, i32>) -> Board { todo!() }
+        "#,
+    );
+    let config = project.root().join("tauri-codegen.toml");
+    let mut contents = fs::read_to_string(&config).unwrap();
+    contents.push_str("[generation]\nmap_key_fallback = \"record\"\n");
+    fs::write(&config, contents).unwrap();
+
+    let output = run_generate_with_args(&project, &["--annotations", "github"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let warning = "Map key type 'Point' in src-tauri/src/lib.rs does not serialize";
+    assert_eq!(stdout.matches(warning).count(), 1, "got:\n{stdout}");
+    assert_contains(&stdout, "::warning file=src-tauri/src/lib.rs::");
+}
+
+#[test]
+fn unresolved_map_key_keeps_record_with_warning_by_default() {
+    let project = Project::with_source(
+        r#"
+        use std::collections::HashMap;
+
+        #[tauri::command]
+        fn owners() -> HashMap<external::Owner, String> { todo!() }
+        "#,
+    );
+
+    let output = run_generate_with_args(&project, &[]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert_contains(
+        &stderr,
+        "Map key type 'Owner' in src-tauri/src/lib.rs is unresolved; \
+         using 'Record<string, V>'",
+    );
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "Promise<Record<string, string>>");
+}
+
+#[test]
+fn every_record_fallback_map_key_warns() {
+    let project = Project::with_source(
+        r#"
+        use std::collections::HashMap;
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Board {
+            pub cells: HashMap<(i32, i32), String>,
+            pub flags: HashMap<bool, String>,
+            pub owners: HashMap<external::Owner, String>,
+            pub names: HashMap<String, String>,
+        }
+
+        #[tauri::command]
+        fn board() -> Board { todo!() }
+        "#,
+    );
+    let config = project.root().join("tauri-codegen.toml");
+    let mut contents = fs::read_to_string(&config).unwrap();
+    contents.push_str("[generation]\nmap_key_fallback = \"record\"\n");
+    fs::write(&config, contents).unwrap();
+
+    let output = run_generate_with_args(&project, &[]);
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    for warning in [
+        "Map key type '[number, number]' in src-tauri/src/lib.rs does not serialize \
+         as a string or number",
+        "Map key type 'bool' in src-tauri/src/lib.rs has no TypeScript type",
+        "Map key type 'Owner' in src-tauri/src/lib.rs is unresolved",
+    ] {
+        assert_eq!(stderr.matches(warning).count(), 1, "got:\n{stderr}");
+    }
+    assert_eq!(stderr.matches("Map key type").count(), 3, "got:\n{stderr}");
 }
@@ -0,0 +1,44 @@
+//! `generate --explain <type>` reports how a type was resolved and writes
+//! nothing.
+
+use crate::helpers::{assert_contains, run_generate_with_args, Project};
+
+#[test]
+fn explain_names_resolved_file_and_resolution_path() {
+    let project = Project::with_source(
+        r#"
+        mod models;
+        use crate::models::User;
+
+        #[tauri::command]
+        fn get_user() -> Result<User, String> { todo!() }
+        "#,
+    );
+    project.add_source(
+        "models.rs",
+        r#"
+        use serde::{Deserialize, Serialize};
+        #[derive(Serialize, Deserialize)]
+        pub struct User { pub id: i32 }
+        "#,
+    );
+
+    let output = run_generate_with_args(&project, &["--explain", "User"]);
+    assert!(
+        output.status.success(),
+        "stderr:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert_contains(&stdout, "Type 'User':");
+    assert_contains(&stdout, "Candidate definitions:");
+    assert_contains(&stdout, "(command 'get_user'): found in ");
+    assert_contains(&stdout, "models.rs via import `use crate::models::User`");
+    assert_contains(&stdout, "Exported: yes, as a struct from ");
+    assert!(!project.types_out.exists(), "explain must not write files");
+    assert!(
+        !project.commands_out.exists(),
+        "explain must not write files"
+    );
+}
@@ -39,23 +39,29 @@ impl Project {
         let types_out = generated_dir.join("types.ts");
         let commands_out = generated_dir.join("commands.ts");
 
+        let project = Self {
+            dir,
+            types_out,
+            commands_out,
+        };
+        project.write_config("");
+        project
+    }
+
+    /// Rewrite the config, with `extra_input` lines added to `[input]`.
+    pub fn write_config(&self, extra_input: &str) {
         fs::write(
-            root.join("tauri-codegen.toml"),
-            concat!(
-                "[input]\n",
-                "source_dir = \"src-tauri/src\"\n",
-                "[output]\n",
-                "types_file = \"src/generated/types.ts\"\n",
-                "commands_file = \"src/generated/commands.ts\"\n",
+            self.dir.path().join("tauri-codegen.toml"),
+            format!(
+                "[input]\n\
+                 source_dir = \"src-tauri/src\"\n\
+                 {extra_input}\
+                 [output]\n\
+                 types_file = \"src/generated/types.ts\"\n\
+                 commands_file = \"src/generated/commands.ts\"\n"
             ),
         )
         .unwrap();
-
-        Self {
-            dir,
-            types_out,
-            commands_out,
-        }
     }
 
     /// Write an extra source file under `src-tauri/src/<relative>`.
@@ -75,9 +81,24 @@ impl Project {
 /// Run `tauri-ts-generator generate` inside `project` and return the captured
 /// output. The caller decides whether to assert success or failure.
 pub fn run_generate(project: &Project) -> Output {
+    run_generate_with_args(project, &[])
+}
+
+/// Like [`run_generate`], with extra `generate` flags appended.
+pub fn run_generate_with_args(project: &Project, args: &[&str]) -> Output {
     Command::new(binary_path())
         .current_dir(project.root())
         .arg("generate")
+        .args(args)
+        .output()
+        .expect("spawn tauri-ts-generator")
+}
+
+/// Run `tauri-ts-generator check` inside `project`.
+pub fn run_check(project: &Project) -> Output {
+    Command::new(binary_path())
+        .current_dir(project.root())
+        .arg("check")
         .output()
         .expect("spawn tauri-ts-generator")
 }
@@ -14,8 +14,11 @@ mod helpers;
 
 mod basic;
 mod channels;
+mod check;
 mod errors;
+mod explain;
 mod init;
+mod output_selection;
 mod path_qualified;
 mod recursive;
 mod rename_all;
@@ -23,3 +26,4 @@ mod serde_features;
 mod smart_pointers;
 mod snapshots;
 mod struct_shapes;
+mod watch;
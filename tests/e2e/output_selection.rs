@@ -0,0 +1,118 @@
+//! `generate --types-only` / `--commands-only` write just one of the two
+//! output files and must not touch the other. `--stdout` prints the
+//! selected files instead of writing them.
+
+use std::fs;
+
+use crate::helpers::{assert_contains, run_generate_with_args, Project};
+
+const SOURCE: &str = r#"
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize)]
+    pub struct User { pub id: i32 }
+
+    #[tauri::command]
+    fn get_user() -> User { todo!() }
+"#;
+
+const UNTOUCHED: &str = "// hand-written, must survive\n";
+
+fn assert_success(output: &std::process::Output) {
+    assert!(
+        output.status.success(),
+        "expected success; stderr:\n{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn types_only_leaves_commands_file_untouched() {
+    let project = Project::with_source(SOURCE);
+    fs::write(&project.commands_out, UNTOUCHED).unwrap();
+
+    assert_success(&run_generate_with_args(&project, &["--types-only"]));
+
+    let types = fs::read_to_string(&project.types_out).unwrap();
+    assert_contains(&types, "export interface User {");
+    assert_eq!(
+        fs::read_to_string(&project.commands_out).unwrap(),
+        UNTOUCHED
+    );
+}
+
+#[test]
+fn commands_only_leaves_types_file_untouched() {
+    let project = Project::with_source(SOURCE);
+    fs::write(&project.types_out, UNTOUCHED).unwrap();
+
+    assert_success(&run_generate_with_args(&project, &["--commands-only"]));
+
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(
+        &commands,
+        "export async function getUser(): Promise<User> {",
+    );
+    assert_eq!(fs::read_to_string(&project.types_out).unwrap(), UNTOUCHED);
+}
+
+#[test]
+fn types_only_and_commands_only_conflict() {
+    let project = Project::with_source(SOURCE);
+
+    let output = run_generate_with_args(&project, &["--types-only", "--commands-only"]);
+    assert!(!output.status.success());
+    assert!(!project.types_out.exists());
+    assert!(!project.commands_out.exists());
+}
+
+#[test]
+fn stdout_prints_selected_files_without_writing() {
+    let project = Project::with_source(SOURCE);
+
+    let output = run_generate_with_args(&project, &["--stdout"]);
+    assert_success(&output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("// ==> src/generated/types.ts <==\n"),
+        "{stdout}"
+    );
+    assert_contains(&stdout, "export interface User {");
+    assert_contains(&stdout, "\n// ==> src/generated/commands.ts <==\n");
+    assert_contains(&stdout, "export async function getUser(): Promise<User> {");
+    // Status lines go to stderr
+    assert!(!stdout.contains("Parsed 1 commands"), "{stdout}");
+    assert!(!project.types_out.exists());
+    assert!(!project.commands_out.exists());
+
+    let output = run_generate_with_args(&project, &["--stdout", "--commands-only"]);
+    assert_success(&output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(
+        stdout.starts_with("// ==> src/generated/commands.ts <==\n"),
+        "{stdout}"
+    );
+    assert!(!stdout.contains("export interface User"), "{stdout}");
+}
+
+#[test]
+fn stdout_with_github_annotations_keeps_annotations_off_stdout() {
+    // `fetch` shadows a browser global, which warns
+    let project = Project::with_source(
+        r#"
+        #[tauri::command]
+        fn fetch() -> String { todo!() }
+        "#,
+    );
+
+    let output = run_generate_with_args(&project, &["--stdout", "--annotations", "github"]);
+    assert_success(&output);
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(!stdout.contains("::warning"), "{stdout}");
+    assert!(
+        stdout.starts_with("// ==> src/generated/types.ts <=="),
+        "{stdout}"
+    );
+    assert_contains(&stderr, "::warning file=");
+}
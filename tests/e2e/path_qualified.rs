@@ -170,3 +170,38 @@ fn qualified_path_with_generic_arguments() {
         "both base and arg types must be imported:\n{commands}"
     );
 }
+
+#[test]
+fn qualified_and_aliased_tauri_injected_args_are_dropped() {
+    // Tauri injects these from the runtime; whatever spelling the command
+    // uses — fully qualified, module-qualified or a `use ... as` rename —
+    // they must never become frontend parameters.
+    let project = Project::with_source(
+        r#"
+        use tauri::WebviewWindow as MainWindow;
+
+        pub struct AppState;
+
+        #[tauri::command]
+        fn focus(
+            window: tauri::WebviewWindow,
+            webview: tauri::webview::Webview,
+            main: MainWindow,
+            state: tauri::State<'_, AppState>,
+            app: ::tauri::AppHandle,
+            id: i32,
+        ) -> Result<(), String> { todo!() }
+        "#,
+    );
+    run_generate_ok(&project);
+    let commands = std::fs::read_to_string(&project.commands_out).unwrap();
+
+    assert!(
+        commands.contains("export async function focus(id: number): Promise<void>"),
+        "injected args must be dropped:\n{commands}"
+    );
+    assert!(
+        commands.contains("invoke<void>(\"focus\", { id })"),
+        "injected args must not reach the payload:\n{commands}"
+    );
+}
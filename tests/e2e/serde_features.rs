@@ -58,6 +58,38 @@ fn skip_excludes_field() {
     );
 }
 
+#[test]
+fn type_only_reachable_through_skipped_field_is_not_exported() {
+    let project = Project::with_source(
+        r#"
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Serialize, Deserialize)]
+        pub struct SessionCache { pub entries: Vec<String> }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Secret { pub value: String }
+
+        #[derive(Serialize)]
+        pub struct User {
+            pub id: i32,
+            #[serde(skip)]
+            pub cache: SessionCache,
+            #[serde(skip_serializing)]
+            pub secret: Secret,
+        }
+
+        #[tauri::command]
+        fn x() -> Result<User, String> { todo!() }
+        "#,
+    );
+    run_generate_ok(&project);
+    let types = std::fs::read_to_string(&project.types_out).unwrap();
+    assert!(types.contains("export interface User"), "{types}");
+    assert!(!types.contains("SessionCache"), "{types}");
+    assert!(!types.contains("Secret"), "{types}");
+}
+
 #[test]
 fn skip_serializing_is_kept_because_input_path_still_needs_it() {
     let project = Project::with_source(
@@ -77,7 +109,8 @@ fn skip_serializing_is_kept_because_input_path_still_needs_it() {
     );
     run_generate_ok(&project);
     let types = std::fs::read_to_string(&project.types_out).unwrap();
-    assert!(types.contains("password: string"), "{types}");
+    // Absent from responses, still accepted as input: optional
+    assert!(types.contains("password?: string"), "{types}");
 }
 
 #[test]
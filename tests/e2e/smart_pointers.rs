@@ -74,3 +74,35 @@ fn vec_of_box_custom_type_is_preserved() {
     );
     assert!(types.contains("export interface Item"));
 }
+
+#[test]
+fn boxed_and_arced_custom_types_are_exported() {
+    let project = Project::with_source(
+        r#"
+        use serde::{Deserialize, Serialize};
+        use std::sync::Arc;
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Config { pub debug: bool }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct User { pub id: i32 }
+
+        #[derive(Serialize, Deserialize)]
+        pub struct Session {
+            pub user: Box<User>,
+            pub config: Arc<Config>,
+        }
+
+        #[tauri::command]
+        fn x() -> Result<Session, String> { todo!() }
+        "#,
+    );
+    run_generate_ok(&project);
+    let types = std::fs::read_to_string(&project.types_out).unwrap();
+    assert!(types.contains("user: User;"), "{types}");
+    assert!(types.contains("config: Config;"), "{types}");
+    assert!(types.contains("export interface User"), "{types}");
+    assert!(types.contains("export interface Config"), "{types}");
+    assert!(!types.contains("Box") && !types.contains("Arc"), "{types}");
+}
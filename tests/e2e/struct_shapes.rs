@@ -136,16 +136,38 @@ fn serde_default_makes_field_optional() {
         "#,
     );
     assert!(types.contains("name: string;"), "{types}");
+    // serde still serializes None as null, so the null stays
     assert!(
-        types.contains("theme?: string;"),
+        types.contains("theme?: string | null;"),
         "serde(default) must make theme optional, got:\n{types}"
     );
     assert!(
-        types.contains("retries?: number;"),
+        types.contains("retries?: number | null;"),
         "serde(default = fn) must make retries optional, got:\n{types}"
     );
 }
 
+#[test]
+fn serde_default_fn_on_plain_field_makes_it_optional() {
+    let types = types_for(
+        r#"
+        use serde::{Deserialize, Serialize};
+        fn default_retries() -> i32 { 3 }
+        #[derive(Serialize, Deserialize)]
+        pub struct Config {
+            #[serde(default = "default_retries")]
+            pub retries: i32,
+        }
+        #[tauri::command]
+        fn x() -> Result<Config, String> { todo!() }
+        "#,
+    );
+    assert!(
+        types.contains("retries?: number;"),
+        "serde(default = fn) must make retries optional without null, got:\n{types}"
+    );
+}
+
 #[test]
 fn skip_serializing_if_none_makes_option_optional() {
     let types = types_for(
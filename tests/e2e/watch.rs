@@ -0,0 +1,92 @@
+//! `generate --watch` generates immediately, regenerates on source
+//! changes and keeps running after a pass that fails.
+
+use std::fs;
+use std::io::{BufRead, BufReader, Read};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::helpers::{assert_contains, binary_path, Project};
+
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Kills the watcher even if an assertion fails.
+struct Watching(Child);
+
+impl Drop for Watching {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+/// Forward every stdout/stderr line of the child to one channel.
+fn forward_lines(stream: impl Read + Send + 'static, tx: mpsc::Sender<String>) {
+    thread::spawn(move || {
+        for line in BufReader::new(stream).lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+}
+
+/// Block until a line containing `needle` arrives.
+fn wait_for(lines: &Receiver<String>, needle: &str) {
+    let deadline = Instant::now() + TIMEOUT;
+    while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+        match lines.recv_timeout(left) {
+            Ok(line) if line.contains(needle) => return,
+            Ok(_) => {}
+            Err(_) => break,
+        }
+    }
+    panic!("timed out waiting for a line containing {needle:?}");
+}
+
+#[test]
+fn watch_regenerates_on_change_and_survives_failed_pass() {
+    let project = Project::with_source(
+        r#"
+        #[tauri::command]
+        fn ping() -> String { todo!() }
+        "#,
+    );
+
+    let mut child = Command::new(binary_path())
+        .current_dir(project.root())
+        .args(["generate", "--watch"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("spawn tauri-ts-generator");
+    let (tx, lines) = mpsc::channel();
+    forward_lines(child.stdout.take().unwrap(), tx.clone());
+    forward_lines(child.stderr.take().unwrap(), tx);
+    let _guard = Watching(child);
+
+    wait_for(&lines, "Watching");
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "export async function ping()");
+
+    // A duplicate command name is a hard error for a one-shot run.
+    project.add_source(
+        "other.rs",
+        "#[tauri::command]\nfn ping() -> String { todo!() }",
+    );
+    wait_for(&lines, "Generation failed");
+
+    project.add_source(
+        "lib.rs",
+        r#"
+        #[tauri::command]
+        fn pong() -> String { todo!() }
+        "#,
+    );
+    fs::remove_file(project.root().join("src-tauri/src/other.rs")).unwrap();
+    wait_for(&lines, "Regenerated");
+    let commands = fs::read_to_string(&project.commands_out).unwrap();
+    assert_contains(&commands, "export async function pong()");
+}
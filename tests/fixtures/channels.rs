@@ -0,0 +1,17 @@
+// Fixture: a command streaming progress through a tauri::ipc::Channel
+
+use serde::Serialize;
+use tauri::ipc::Channel;
+
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase", tag = "event", content = "data")]
+pub enum ProgressEvent {
+    Started { total_bytes: u64 },
+    Progress { downloaded: u64 },
+    Finished,
+}
+
+#[tauri::command]
+pub async fn download(url: String, on_progress: Channel<ProgressEvent>) -> Result<(), String> {
+    unimplemented!()
+}
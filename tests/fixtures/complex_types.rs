@@ -65,3 +65,22 @@ pub enum ApiResponse<T> {
 #[derive(Serialize, Deserialize)]
 pub struct TupleStruct(pub i32, pub String);
 
+#[derive(Serialize, Deserialize)]
+pub struct Point(pub f64, pub f64);
+
+#[derive(Serialize, Deserialize)]
+pub struct UserId(pub String);
+
+#[derive(Serialize, Deserialize)]
+pub struct Marker {
+    pub position: Point,
+    pub owner: UserId,
+}
+
+
+#[derive(Serialize)]
+pub struct CachedUser {
+    pub id: i32,
+    #[serde(skip)]
+    pub lookup_cache: HashMap<String, i32>,
+}
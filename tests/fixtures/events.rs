@@ -0,0 +1,17 @@
+// Fixture: event payloads marked with #[tauri_event]
+
+use serde::Serialize;
+use tauri_ts_generator::tauri_event;
+
+/// Sent after the user edits their profile
+#[derive(Clone, Serialize)]
+#[tauri_event(name = "user-updated")]
+pub struct UserUpdated {
+    pub id: u32,
+    pub name: String,
+}
+
+/// Sent once the main window has loaded
+#[derive(Clone, Serialize)]
+#[tauri_event]
+pub struct AppReady;
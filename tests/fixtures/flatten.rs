@@ -0,0 +1,37 @@
+// Fixture: #[serde(flatten)] of a struct, a map and an unresolvable type
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct Address {
+    pub city: String,
+    pub country: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+    #[serde(flatten)]
+    pub address: Address,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Labels {
+    pub id: i32,
+    #[serde(flatten)]
+    pub extra: HashMap<String, String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Metadata {
+    #[serde(flatten)]
+    pub fields: HashMap<String, i32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Wrapped {
+    pub id: i32,
+    #[serde(flatten)]
+    pub external: external_crate::Opaque,
+}
@@ -0,0 +1,21 @@
+// Fixture: Generic struct instantiated in a command signature
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ResourceList<T> {
+    pub items: Vec<T>,
+    pub total: u32,
+    pub continue_token: Option<String>,
+}
+
+#[tauri::command]
+pub fn list_users(page: u32) -> Result<ResourceList<User>, String> {
+    todo!()
+}
@@ -0,0 +1,28 @@
+// Fixture: unit-only enums serialized as strings and, through serde_repr,
+// as their integer discriminants
+
+use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+#[derive(Serialize_repr, Deserialize_repr)]
+#[repr(u8)]
+pub enum Level {
+    Low = 1,
+    Medium = 5,
+    High = 10,
+}
+
+#[derive(Serialize_repr)]
+#[repr(i16)]
+pub enum Offset {
+    Behind = -1,
+    Even,
+    Ahead,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Phase {
+    NotStarted,
+    Done,
+}
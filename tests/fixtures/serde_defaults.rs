@@ -0,0 +1,21 @@
+// Fixture: fields serde fills in when they are missing from the input
+
+use serde::{Deserialize, Serialize};
+
+fn default_page_size() -> u32 {
+    50
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SearchRequest {
+    pub query: String,
+    #[serde(default)]
+    pub include_archived: bool,
+    #[serde(default = "default_page_size")]
+    pub page_size: u32,
+    #[serde(default)]
+    pub cursor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub locale: Option<String>,
+    pub owner: Option<String>,
+}
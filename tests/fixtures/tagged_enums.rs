@@ -0,0 +1,44 @@
+// Fixture: Enums under each serde tagging mode, with unit, tuple and
+// struct variants
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Profile {
+    pub bio: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum ExternalRole {
+    Guest,
+    Member(String, u32),
+    Admin { permissions: Vec<String> },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum InternalRole {
+    Guest,
+    Member(Profile),
+    Admin { permissions: Vec<String> },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "t", content = "c")]
+pub enum AdjacentRole {
+    Guest,
+    Member(String, u32),
+    Admin { permissions: Vec<String> },
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum Event {
+    Joined(Profile),
+    Moved {
+        from_x: i32,
+        to_x: i32,
+        #[serde(rename = "who")]
+        user: Profile,
+    },
+}
@@ -0,0 +1,26 @@
+// Fixture: untagged enums, serialized as the bare payload of each variant
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+pub struct Range {
+    pub start: u32,
+    pub end: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Id {
+    Num(i32),
+    Text(String),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Selection {
+    Nothing,
+    Single(u32),
+    Pair(u32, u32),
+    Span(Range),
+    Named { label: String, ids: Vec<Id> },
+}
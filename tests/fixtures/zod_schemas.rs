@@ -0,0 +1,41 @@
+// Fixture: nested custom types and enums for the Zod schema output
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub id: u32,
+    pub name: String,
+    pub email: Option<String>,
+    pub role: Role,
+    pub address: Address,
+    pub tags: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Address {
+    pub city: String,
+    pub coordinates: Option<(f64, f64)>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Event {
+    Joined { user: User },
+    Left { user_id: u32 },
+    Reset,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Team {
+    pub members: HashMap<String, User>,
+    pub lead: Option<User>,
+    pub history: Vec<Event>,
+}
@@ -1,15 +1,23 @@
 //! Integration tests for TypeScript code generation
 
 use std::path::PathBuf;
-use tauri_ts_generator::config::NamingConfig;
+use tauri_ts_generator::config::{GenerationConfig, NamingConfig, UnitEnumStyle};
 use tauri_ts_generator::generator::{
-    commands_gen::generate_commands_file, types_gen::generate_types_file, GeneratorContext,
+    backend::{Bindings, CodeGenerator},
+    commands_gen::generate_commands_file,
+    events_gen::generate_events_file,
+    kotlin::KotlinGenerator,
+    types_gen::generate_types_file,
+    zod::generate_zod_file,
+    GeneratorContext,
 };
 use tauri_ts_generator::models::{
-    CommandArg, EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustType, StructField,
-    StructShape, TauriCommand, VariantData,
+    CommandArg, DerivedTypes, EnumRepresentation, EnumVariant, RustEnum, RustStruct, RustType,
+    StructField, StructShape, TauriCommand, VariantData,
+};
+use tauri_ts_generator::parser::{
+    parse_commands, parse_events, parse_expanded_commands, parse_types, ParseOptions, ParsedTypes,
 };
-use tauri_ts_generator::parser::{parse_commands, parse_types, ParseOptions, ParsedTypes};
 
 /// Get path to test fixtures
 fn fixture_path(name: &str) -> PathBuf {
@@ -112,6 +120,87 @@ fn test_generate_commands_from_simple_fixture() {
     assert!(output.contains("Promise<void>"));
 }
 
+#[test]
+fn test_generate_channel_fixture() {
+    let content = read_fixture("channels.rs");
+    let path = fixture_path("channels.rs");
+
+    let commands = parse_commands(&content, &path).expect("Failed to parse commands");
+    let ParsedTypes { enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+    assert_eq!(
+        commands[0].args[1].ty,
+        RustType::Channel(Box::new(RustType::custom("ProgressEvent")))
+    );
+
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for e in &enums {
+        ctx.register_enum(&e.name);
+    }
+    let output = generate_commands_file(
+        &commands,
+        &PathBuf::from("./types.ts"),
+        &PathBuf::from("./commands.ts"),
+        &ctx,
+    );
+
+    assert!(
+        output.contains("import { invoke, Channel } from \"@tauri-apps/api/core\";\n"),
+        "got:\n{output}"
+    );
+    assert!(output.contains("import type { ProgressEvent } from \"./types\";\n"));
+    assert!(
+        output.contains(
+            "export async function download(url: string, onProgress: Channel<ProgressEvent>): \
+             Promise<void> {\n  return invoke<void>(\"download\", { url, onProgress });\n}\n"
+        ),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_async_and_sync_commands_generate_identically() {
+    // `invoke` is asynchronous either way, so whether the Rust function is
+    // `async` must not show in the arguments or the generated function
+    let source = |asyncness: &str| {
+        format!(
+            r#"
+            #[derive(Serialize, Deserialize)]
+            pub struct Filter {{ pub query: String }}
+
+            #[tauri::command(rename_all = "snake_case")]
+            pub {asyncness}fn search_items(page_size: u32, filter: Option<Filter>, tags: Vec<String>) -> Result<Vec<String>, String> {{
+                unimplemented!()
+            }}
+            "#
+        )
+    };
+    let generate = |content: &str| {
+        let path = PathBuf::from("lib.rs");
+        let commands = parse_commands(content, &path).expect("Failed to parse commands");
+        let mut ctx = GeneratorContext::new(NamingConfig::default());
+        ctx.register_type("Filter");
+        generate_commands_file(
+            &commands,
+            &PathBuf::from("./types.ts"),
+            &PathBuf::from("./commands.ts"),
+            &ctx,
+        )
+    };
+
+    let sync_output = generate(&source(""));
+    let async_output = generate(&source("async "));
+    assert_eq!(sync_output, async_output);
+    assert!(
+        sync_output.contains(
+            "export async function searchItems(pageSize: number, filter: Filter | null, \
+             tags: string[]): Promise<string[]> {\n  \
+             return invoke<string[]>(\"search_items\", { page_size: pageSize, filter, tags });\n}\n"
+        ),
+        "got:\n{sync_output}"
+    );
+}
+
 #[test]
 fn test_generate_complex_types() {
     let content = read_fixture("complex_types.rs");
@@ -147,6 +236,382 @@ fn test_generate_complex_types() {
     // External representation
     assert!(output.contains("Text: string"));
     assert!(output.contains("Image: {"));
+
+    // Tuple structs are positional tuples, newtypes are transparent aliases,
+    // and both are still referenced by name.
+    assert!(output.contains("export type Point = [number, number];"));
+    assert!(output.contains("export type UserId = string;"));
+    assert!(output.contains("position: Point;"));
+    assert!(output.contains("owner: UserId;"));
+    assert!(!output.contains("field0"));
+
+    // #[serde(skip)] fields never reach the interface
+    assert!(output.contains("export interface CachedUser {\n  id: number;\n}"));
+    assert!(!output.contains("lookup_cache"));
+}
+
+#[test]
+fn test_generate_generic_struct_fixture() {
+    let content = read_fixture("generic_commands.rs");
+    let path = fixture_path("generic_commands.rs");
+
+    let commands = parse_commands(&content, &path).expect("Failed to parse commands");
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+
+    // The interface declares its parameter instead of leaving `T` dangling
+    let types = generate_types_file(&structs, &enums, &[], &ctx);
+    assert!(types.contains(
+        "export interface ResourceList<T> {\n  items: T[];\n  total: number;\n  continue_token: string | null;\n}"
+    ));
+
+    // The command references the concrete instantiation
+    let commands = generate_commands_file(
+        &commands,
+        &PathBuf::from("./types.ts"),
+        &PathBuf::from("./commands.ts"),
+        &ctx,
+    );
+    assert!(commands
+        .contains("export async function listUsers(page: number): Promise<ResourceList<User>> {"));
+    assert!(commands.contains("invoke<ResourceList<User>>(\"list_users\", { page })"));
+    assert!(commands.contains("import type { ResourceList, User } from \"./types\";"));
+}
+
+#[test]
+fn test_generate_tagged_enums_fixture() {
+    let content = read_fixture("tagged_enums.rs");
+    let path = fixture_path("tagged_enums.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+    for e in &enums {
+        ctx.register_type(&e.name);
+    }
+
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
+
+    // External: unit variants are bare strings, payloads are keyed by name
+    assert!(output.contains(
+        "export type ExternalRole =\n  | \"Guest\"\n  | { Member: [string, number] }\n  | { Admin: { permissions: string[] } };"
+    ));
+
+    // Internal: the tag sits beside the variant's fields
+    assert!(output.contains(
+        "export type InternalRole =\n  | { type: \"Guest\" }\n  | ({ type: \"Member\" } & Profile)\n  | { type: \"Admin\"; permissions: string[] };"
+    ));
+
+    // Adjacent: the payload moves under the content key
+    assert!(output.contains(
+        "export type AdjacentRole =\n  | { t: \"Guest\" }\n  | { t: \"Member\"; c: [string, number] }\n  | { t: \"Admin\"; c: { permissions: string[] } };"
+    ));
+    // A struct payload, named or inline, stays nested under the content key
+    assert!(output.contains(
+        "export type Event =\n  | { type: \"Joined\"; data: Profile }\n  | { type: \"Moved\"; data: { from_x: number; to_x: number; who: Profile } };"
+    ));
+}
+
+#[test]
+fn test_unit_returns_are_promise_void_on_every_parser_path() {
+    let source = r#"
+        #[tauri::command]
+        fn implicit_unit() {}
+
+        #[tauri::command]
+        async fn explicit_unit() -> () {}
+
+        #[tauri::command]
+        async fn unit_result() -> Result<(), String> { Ok(()) }
+
+        struct Service;
+
+        impl Service {
+            #[tauri::command]
+            async fn method_unit(&self) {}
+        }
+    "#;
+    let expanded = r#"
+        pub async fn expanded_unit() -> () {}
+        pub use __cmd__expanded_unit;
+
+        pub fn expanded_unit_result() -> Result<(), String> { Ok(()) }
+        pub use __cmd__expanded_unit_result;
+    "#;
+    let path = PathBuf::from("commands.rs");
+    let mut commands = parse_commands(source, &path).expect("Failed to parse commands");
+    commands.extend(parse_expanded_commands(expanded, &path).expect("Failed to parse commands"));
+    assert_eq!(commands.len(), 6);
+
+    let output = generate_commands_file(
+        &commands,
+        &PathBuf::from("types.ts"),
+        &PathBuf::from("commands.ts"),
+        &GeneratorContext::new(NamingConfig::default()),
+    );
+
+    for function in [
+        "implicitUnit",
+        "explicitUnit",
+        "unitResult",
+        "methodUnit",
+        "expandedUnit",
+        "expandedUnitResult",
+    ] {
+        assert!(
+            output.contains(&format!(
+                "export async function {}(): Promise<void> {{",
+                function
+            )),
+            "{function} should return Promise<void>, got:\n{output}"
+        );
+    }
+    assert!(output.contains("invoke<void>(\"unit_result\")"));
+}
+
+#[test]
+fn test_generate_kotlin_from_simple_fixture() {
+    let content = read_fixture("simple_commands.rs");
+    let path = fixture_path("simple_commands.rs");
+
+    let commands = parse_commands(&content, &path).expect("Failed to parse commands");
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+    for e in &enums {
+        ctx.register_enum(&e.name);
+    }
+    let bindings = Bindings {
+        structs: &structs,
+        enums: &enums,
+        aliases: &[],
+        commands: &commands,
+        events: &[],
+    };
+
+    let output = KotlinGenerator::new(ctx).single_file(&bindings);
+
+    assert!(output.contains("import kotlinx.serialization.Serializable\n"));
+    assert!(output.contains(
+        "@Serializable\ndata class User(\n  val id: Int,\n  val name: String,\n  val email: String?,\n)\n"
+    ));
+    assert!(output
+        .contains("@Serializable\nenum class Status {\n  Active,\n  Inactive,\n  Pending,\n}\n"));
+    assert!(output.contains("  suspend fun greet(name: String): String\n"));
+    assert!(output.contains("  suspend fun getUser(id: Int): User\n"));
+    assert!(output.contains("  suspend fun getAllUsers(): List<User>\n"));
+    assert!(output.contains("  suspend fun deleteUser(id: Int)\n"));
+    assert!(!output.contains("export "));
+}
+
+fn generate_numeric_enums_fixture(generation: GenerationConfig) -> String {
+    let content = read_fixture("numeric_enums.rs");
+    let path = fixture_path("numeric_enums.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+
+    let mut ctx = GeneratorContext::with_generation(NamingConfig::default(), generation);
+    for e in &enums {
+        ctx.register_type(&e.name);
+    }
+    generate_types_file(&structs, &enums, &[], &ctx)
+}
+
+#[test]
+fn test_generate_numeric_enums_fixture() {
+    let output = generate_numeric_enums_fixture(GenerationConfig::default());
+
+    // serde_repr enums serialize as their discriminants, implicit ones included
+    assert!(output.contains("export type Level =\n  | 1\n  | 5\n  | 10;"));
+    assert!(output.contains("export type Offset =\n  | -1\n  | 0\n  | 1;"));
+    // Plain serde unit enums stay string unions
+    assert!(output.contains("export type Phase =\n  | \"not-started\"\n  | \"done\";"));
+}
+
+#[test]
+fn test_generate_numeric_enums_fixture_as_ts_enums() {
+    let output = generate_numeric_enums_fixture(GenerationConfig {
+        unit_enum_style: UnitEnumStyle::Enum,
+        ..Default::default()
+    });
+
+    assert!(output.contains("export enum Level {\n  Low = 1,\n  Medium = 5,\n  High = 10,\n}"));
+    assert!(output.contains("export enum Offset {\n  Behind = -1,\n  Even = 0,\n  Ahead = 1,\n}"));
+    assert!(output.contains(
+        "export enum Phase {\n  \"not-started\" = \"not-started\",\n  done = \"done\",\n}"
+    ));
+}
+
+#[test]
+fn test_generate_serde_defaults_fixture() {
+    let content = read_fixture("serde_defaults.rs");
+    let path = fixture_path("serde_defaults.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+    let output = generate_types_file(
+        &structs,
+        &enums,
+        &[],
+        &GeneratorContext::new(NamingConfig::default()),
+    );
+
+    assert!(output.contains("  query: string;\n"));
+    // Non-Option fields with a default may be left out
+    assert!(output.contains("  include_archived?: boolean;\n"));
+    assert!(output.contains("  page_size?: number;\n"));
+    // An Option with a default may be left out but is still written as null
+    assert!(output.contains("  cursor?: string | null;\n"));
+    // ...unless serde skips it when it is None
+    assert!(output.contains("  locale?: string;\n"));
+    assert!(output.contains("  owner: string | null;\n"));
+}
+
+#[test]
+fn test_generate_untagged_enums_fixture() {
+    let content = read_fixture("untagged_enums.rs");
+    let path = fixture_path("untagged_enums.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+    for e in &enums {
+        ctx.register_enum(&e.name);
+    }
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
+
+    assert!(
+        output.contains("export type Id =\n  | number\n  | string;\n"),
+        "got:\n{output}"
+    );
+    // A unit variant is written as `null`; newtype variants are their
+    // value, tuple variants an array, struct variants an object
+    assert!(
+        output.contains(
+            "export type Selection =\n  | null\n  | number\n  | [number, number]\n  | Range\n  | \
+             { label: string; ids: Id[] };\n"
+        ),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_generate_events_fixture() {
+    let content = read_fixture("events.rs");
+    let path = fixture_path("events.rs");
+
+    let events = parse_events(&content, &path).expect("Failed to parse events");
+    let ParsedTypes { structs, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+    let mut ctx = GeneratorContext::with_generation(
+        NamingConfig::default(),
+        GenerationConfig {
+            doc_comments: true,
+            ..Default::default()
+        },
+    );
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+    let output = generate_events_file(
+        &events,
+        &PathBuf::from("src/bindings/types.ts"),
+        &PathBuf::from("src/bindings/events.ts"),
+        &ctx,
+    );
+
+    assert!(output.contains("import { listen } from \"@tauri-apps/api/event\";\n"));
+    assert!(output.contains("import type { UserUpdated } from \"./types\";\n"));
+    assert!(
+        output.contains(
+            "/**\n * Sent after the user edits their profile\n */\n\
+             export function onUserUpdated(handler: (payload: UserUpdated) => void): Promise<UnlistenFn> {\n  \
+             return listen<UserUpdated>(\"user-updated\", (event) => handler(event.payload));\n}\n"
+        ),
+        "got:\n{output}"
+    );
+    // A unit struct payload is always `null`: the handler takes nothing
+    assert!(
+        output.contains(
+            "export function onAppReady(handler: () => void): Promise<UnlistenFn> {\n  \
+             return listen(\"app-ready\", () => handler());\n}\n"
+        ),
+        "got:\n{output}"
+    );
+}
+
+#[test]
+fn test_generate_zod_schemas_fixture() {
+    let content = read_fixture("zod_schemas.rs");
+    let path = fixture_path("zod_schemas.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+    for e in &enums {
+        ctx.register_enum(&e.name);
+    }
+    let output = generate_zod_file(&structs, &enums, &[], &[], &ctx);
+
+    assert!(output.contains("import { z } from \"zod\";\n"));
+    assert!(output.contains(
+        "export const AddressSchema = z.object({\n  \
+         city: z.string(),\n  \
+         coordinates: z.tuple([z.number(), z.number()]).nullable(),\n});\n\
+         export type Address = z.infer<typeof AddressSchema>;\n"
+    ));
+    assert!(output.contains(
+        "export const RoleSchema = z.union([\n  z.literal(\"Admin\"),\n  z.literal(\"Member\"),\n]);\n"
+    ));
+    assert!(output.contains(
+        "export const UserSchema = z.object({\n  \
+         id: z.number(),\n  \
+         name: z.string(),\n  \
+         email: z.string().nullable(),\n  \
+         role: RoleSchema,\n  \
+         address: AddressSchema,\n  \
+         tags: z.array(z.string()),\n});\n"
+    ));
+    assert!(output.contains(
+        "export const EventSchema = z.union([\n  \
+         z.object({ type: z.literal(\"joined\"), user: UserSchema }),\n  \
+         z.object({ type: z.literal(\"left\"), user_id: z.number() }),\n  \
+         z.object({ type: z.literal(\"reset\") }),\n]);\n"
+    ));
+    assert!(output.contains(
+        "export const TeamSchema = z.object({\n  \
+         members: z.record(z.string(), UserSchema),\n  \
+         lead: UserSchema.nullable(),\n  \
+         history: z.array(EventSchema),\n});\n"
+    ));
+
+    // Each schema is declared before the schemas that reference it
+    let position = |name: &str| output.find(&format!("export const {} =", name)).unwrap();
+    assert!(position("AddressSchema") < position("UserSchema"));
+    assert!(position("RoleSchema") < position("UserSchema"));
+    assert!(position("UserSchema") < position("EventSchema"));
+    assert!(position("EventSchema") < position("TeamSchema"));
+    assert!(!output.contains("z.lazy"));
 }
 
 #[test]
@@ -159,10 +624,14 @@ fn test_generate_with_naming_prefix() {
             ty: RustType::Primitive("i32".to_string()),
             has_explicit_rename: false,
             use_optional: false,
+            keeps_null: false,
             is_flatten: false,
+            docs: None,
         }],
         shape: StructShape::Named,
         source_file: PathBuf::from("test.rs"),
+        derived: DerivedTypes::default(),
+        docs: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -170,6 +639,7 @@ fn test_generate_with_naming_prefix() {
         type_suffix: "".to_string(),
         function_prefix: "".to_string(),
         function_suffix: "".to_string(),
+        type_overrides: Default::default(),
     });
     ctx.register_type("User");
 
@@ -186,6 +656,8 @@ fn test_generate_with_naming_suffix() {
         fields: vec![],
         shape: StructShape::Named,
         source_file: PathBuf::from("test.rs"),
+        derived: DerivedTypes::default(),
+        docs: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -193,6 +665,7 @@ fn test_generate_with_naming_suffix() {
         type_suffix: "DTO".to_string(),
         function_prefix: "".to_string(),
         function_suffix: "".to_string(),
+        type_overrides: Default::default(),
     });
     ctx.register_type("User");
 
@@ -209,6 +682,7 @@ fn test_generate_command_with_function_prefix_suffix() {
         return_type: Some(RustType::custom("User")),
         source_file: PathBuf::from("test.rs"),
         rename_all: None,
+        docs: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig {
@@ -216,6 +690,7 @@ fn test_generate_command_with_function_prefix_suffix() {
         type_suffix: "".to_string(),
         function_prefix: "api".to_string(),
         function_suffix: "Cmd".to_string(),
+        type_overrides: Default::default(),
     });
     ctx.register_type("User");
 
@@ -236,6 +711,7 @@ fn test_type_imports_in_commands_file() {
             return_type: Some(RustType::custom("User")),
             source_file: PathBuf::from("test.rs"),
             rename_all: None,
+            docs: None,
         },
         TauriCommand {
             name: "create_item".to_string(),
@@ -246,6 +722,7 @@ fn test_type_imports_in_commands_file() {
             return_type: None,
             source_file: PathBuf::from("test.rs"),
             rename_all: None,
+            docs: None,
         },
     ];
 
@@ -305,25 +782,33 @@ fn test_field_names_match_serde_behavior() {
                 ty: RustType::Primitive("i32".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "firstName".to_string(), // serde(rename_all="camelCase") applied
                 ty: RustType::Primitive("String".to_string()),
                 has_explicit_rename: true,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
             StructField {
                 name: "CUSTOM_NAME".to_string(), // serde(rename="CUSTOM_NAME") applied
                 ty: RustType::Primitive("DateTime".to_string()),
                 has_explicit_rename: true,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             },
         ],
         shape: StructShape::Named,
         source_file: PathBuf::from("test.rs"),
+        derived: DerivedTypes::default(),
+        docs: None,
     }];
 
     let ctx = GeneratorContext::new(NamingConfig::default());
@@ -352,31 +837,39 @@ fn test_complex_enum_discriminated_union() {
                         ty: RustType::Primitive("i32".to_string()),
                         has_explicit_rename: false,
                         use_optional: false,
+                        keeps_null: false,
                         is_flatten: false,
+                        docs: None,
                     },
                     StructField {
                         name: "y".to_string(),
                         ty: RustType::Primitive("i32".to_string()),
                         has_explicit_rename: false,
                         use_optional: false,
+                        keeps_null: false,
                         is_flatten: false,
+                        docs: None,
                     },
                 ]),
                 has_explicit_rename: false,
+                discriminant: None,
             },
             EnumVariant {
                 name: "KeyPress".to_string(),
                 data: VariantData::Tuple(vec![RustType::Primitive("String".to_string())]),
                 has_explicit_rename: false,
+                discriminant: None,
             },
             EnumVariant {
                 name: "Close".to_string(),
                 data: VariantData::Unit,
                 has_explicit_rename: false,
+                discriminant: None,
             },
         ],
         source_file: PathBuf::from("test.rs"),
         representation: EnumRepresentation::default(),
+        docs: None,
     }];
 
     let ctx = GeneratorContext::new(NamingConfig::default());
@@ -409,18 +902,24 @@ fn test_serde_flatten_generates_intersection_type() {
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
                 StructField {
                     name: "country".to_string(),
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
             ],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
         RustStruct {
             name: "User".to_string(),
@@ -431,18 +930,24 @@ fn test_serde_flatten_generates_intersection_type() {
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
                 StructField {
                     name: "address".to_string(),
                     ty: RustType::custom("Address"),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: true,
+                    docs: None,
                 },
             ],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
     ];
 
@@ -463,6 +968,34 @@ fn test_serde_flatten_generates_intersection_type() {
     assert!(output.contains("& Address"));
 }
 
+#[test]
+fn test_generate_flatten_fixture() {
+    let content = read_fixture("flatten.rs");
+    let path = fixture_path("flatten.rs");
+
+    let ParsedTypes { structs, enums, .. } =
+        parse_types(&content, &path, ParseOptions::SOURCE).expect("Failed to parse types");
+
+    let mut ctx = GeneratorContext::new(NamingConfig::default());
+    for s in &structs {
+        ctx.register_type(&s.name);
+    }
+
+    let output = generate_types_file(&structs, &enums, &[], &ctx);
+
+    // A flattened struct merges through an intersection
+    assert!(output.contains("export type User = {\n  name: string;\n} & Address;"));
+
+    // A flattened map becomes an index signature
+    assert!(
+        output.contains("export type Labels = {\n  id: number;\n} & { [key: string]: string };")
+    );
+    assert!(output.contains("export type Metadata = { [key: string]: number };"));
+
+    // An unresolvable target stays a nested property
+    assert!(output.contains("export interface Wrapped {\n  id: number;\n  external: "));
+}
+
 #[test]
 fn test_flatten_type_as_command_return() {
     // Test that flatten types work correctly as command return types
@@ -475,10 +1008,14 @@ fn test_flatten_type_as_command_return() {
                 ty: RustType::Primitive("String".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             }],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
         RustStruct {
             name: "User".to_string(),
@@ -489,18 +1026,24 @@ fn test_flatten_type_as_command_return() {
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
                 StructField {
                     name: "address".to_string(),
                     ty: RustType::custom("Address"),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: true,
+                    docs: None,
                 },
             ],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
     ];
 
@@ -510,6 +1053,7 @@ fn test_flatten_type_as_command_return() {
         return_type: Some(RustType::custom("User")),
         source_file: PathBuf::from("test.rs"),
         rename_all: None,
+        docs: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig::default());
@@ -543,10 +1087,14 @@ fn test_flatten_type_as_command_argument() {
                 ty: RustType::Primitive("i64".to_string()),
                 has_explicit_rename: false,
                 use_optional: false,
+                keeps_null: false,
                 is_flatten: false,
+                docs: None,
             }],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
         RustStruct {
             name: "CreateRequest".to_string(),
@@ -557,18 +1105,24 @@ fn test_flatten_type_as_command_argument() {
                     ty: RustType::Primitive("String".to_string()),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: false,
+                    docs: None,
                 },
                 StructField {
                     name: "meta".to_string(),
                     ty: RustType::custom("Metadata"),
                     has_explicit_rename: false,
                     use_optional: false,
+                    keeps_null: false,
                     is_flatten: true,
+                    docs: None,
                 },
             ],
             shape: StructShape::Named,
             source_file: PathBuf::from("test.rs"),
+            derived: DerivedTypes::default(),
+            docs: None,
         },
     ];
 
@@ -581,6 +1135,7 @@ fn test_flatten_type_as_command_argument() {
         return_type: None,
         source_file: PathBuf::from("test.rs"),
         rename_all: None,
+        docs: None,
     }];
 
     let mut ctx = GeneratorContext::new(NamingConfig::default());
@@ -2,8 +2,10 @@
 
 use std::fs;
 use std::path::PathBuf;
-use tauri_ts_generator::config::{Config, InputConfig, NamingConfig, OutputConfig};
-use tauri_ts_generator::pipeline::Pipeline;
+use tauri_ts_generator::config::{
+    Config, GenerationConfig, InputConfig, NamingConfig, OutputConfig, OutputTarget, StyleConfig,
+};
+use tauri_ts_generator::pipeline::{OutputSelection, Pipeline};
 use tempfile::tempdir;
 
 /// Create a test config with temp directories
@@ -12,14 +14,31 @@ fn create_test_config(source_dir: PathBuf, output_dir: PathBuf) -> Config {
         input: InputConfig {
             source_dir,
             exclude: vec!["tests".to_string(), "target".to_string()],
+            include: vec![],
             use_cargo_expand: false,
             cargo_manifest: None,
+            include_test_commands: false,
+            command_attributes: vec![],
+            follow_symlinks: false,
+            path_dependencies: false,
+            external_crate_prefixes: vec![],
+            active_features: vec![],
         },
         output: OutputConfig {
             types_file: output_dir.join("types.ts"),
             commands_file: output_dir.join("commands.ts"),
+            single_file: None,
+            target: OutputTarget::TypeScript,
+            command_map_file: None,
+            zod_file: None,
+            events_file: None,
+            types_namespace: None,
+            header: None,
+            header_timestamp: false,
+            style: StyleConfig::default(),
         },
         naming: NamingConfig::default(),
+        generation: GenerationConfig::default(),
     }
 }
 
@@ -234,19 +253,37 @@ pub fn get_user(id: i32) -> User {
         input: InputConfig {
             source_dir: src_dir,
             exclude: vec![],
+            include: vec![],
             use_cargo_expand: false,
             cargo_manifest: None,
+            include_test_commands: false,
+            command_attributes: vec![],
+            follow_symlinks: false,
+            path_dependencies: false,
+            external_crate_prefixes: vec![],
+            active_features: vec![],
         },
         output: OutputConfig {
             types_file: output_dir.join("types.ts"),
             commands_file: output_dir.join("commands.ts"),
+            single_file: None,
+            target: OutputTarget::TypeScript,
+            command_map_file: None,
+            zod_file: None,
+            events_file: None,
+            types_namespace: None,
+            header: None,
+            header_timestamp: false,
+            style: StyleConfig::default(),
         },
         naming: NamingConfig {
             type_prefix: "I".to_string(),
             type_suffix: "".to_string(),
             function_prefix: "".to_string(),
             function_suffix: "Cmd".to_string(),
+            type_overrides: Default::default(),
         },
+        generation: GenerationConfig::default(),
     };
 
     let pipeline = Pipeline::new(false);
@@ -260,6 +297,419 @@ pub fn get_user(id: i32) -> User {
     assert!(commands_content.contains("export async function getUserCmd"));
 }
 
+#[test]
+fn test_pipeline_type_overrides() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[derive(Serialize)]
+pub struct Team {
+    pub owner: User,
+    pub members: Vec<User>,
+}
+
+#[tauri::command]
+pub fn get_user(user: User) -> Option<User> {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn get_team() -> Team {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.naming.type_prefix = "I".to_string();
+    config
+        .naming
+        .type_overrides
+        .insert("User".to_string(), "AppUser".to_string());
+    Pipeline::new(false).run(&config).unwrap();
+
+    // The override replaces the prefix; other types keep it
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("export interface AppUser {"),
+        "got:\n{types}"
+    );
+    assert!(types.contains("export interface ITeam {"), "got:\n{types}");
+    assert!(types.contains("  owner: AppUser;\n  members: AppUser[];\n"));
+    assert!(!types.contains("IUser"));
+
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("import type { AppUser, ITeam } from \"./types\";\n"),
+        "got:\n{commands}"
+    );
+    assert!(commands.contains(
+        "export async function getUser(user: AppUser): Promise<AppUser | null> {\n  \
+         return invoke<AppUser | null>(\"get_user\", { user });\n}\n"
+    ));
+    assert!(!commands.contains("IUser"));
+}
+
+#[test]
+fn test_pipeline_types_namespace() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(Serialize)]
+pub struct User {
+    pub name: String,
+    pub role: Role,
+}
+
+#[derive(Serialize)]
+pub struct Team {
+    pub owner: User,
+    pub members: Vec<User>,
+}
+
+#[tauri::command]
+pub fn create_team(owner: User) -> Team {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.types_namespace = Some("Bindings".to_string());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains(
+            "export namespace Bindings {\n  \
+             export interface Team {\n    owner: Bindings.User;\n    members: Bindings.User[];\n  }\n\n  \
+             export interface User {\n    name: string;\n    role: Bindings.Role;\n  }\n\n  \
+             export type Role =\n    | \"Admin\"\n    | \"Member\";\n}\n"
+        ),
+        "got:\n{types}"
+    );
+
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("import type { Bindings } from \"./types\";\n"),
+        "got:\n{commands}"
+    );
+    assert!(
+        commands.contains(
+            "export async function createTeam(owner: Bindings.User): Promise<Bindings.Team> {\n  \
+             return invoke<Bindings.Team>(\"create_team\", { owner });\n}\n"
+        ),
+        "got:\n{commands}"
+    );
+}
+
+#[test]
+fn test_pipeline_numeric_branding() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+    pub name: String,
+}
+
+#[tauri::command]
+pub fn get_user(id: u64) -> User {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.generation.numeric_branding = true;
+
+    let pipeline = Pipeline::new(false);
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline failed: {:?}", result.err());
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("  id: I32;"));
+    assert!(types_content.contains("  name: string;"));
+    assert!(types_content.contains("export type I32 = number;"));
+    // Referenced only from a command argument, still defined.
+    assert!(types_content.contains("export type U64 = number;"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("getUser(id: U64): Promise<User>"));
+    assert!(commands_content.contains("import type { U64, User } from"));
+}
+
+#[test]
+fn test_pipeline_export_error_types() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+}
+
+#[derive(Serialize)]
+pub enum AppError {
+    NotFound,
+    Forbidden,
+}
+
+#[tauri::command]
+pub fn get_user(id: i32) -> Result<User, AppError> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    // Default: the Ok payload drives everything, the error type stays out.
+    let default_dir = temp.path().join("default");
+    let config = create_test_config(src_dir.clone(), default_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(default_dir.join("types.ts")).unwrap();
+    assert!(!types_content.contains("AppError"));
+    let commands_content = fs::read_to_string(default_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains(" * @throws {Error}\n"));
+    assert!(commands_content.contains("getUser(id: number): Promise<User>"));
+
+    // Opt-in: the error type is exported and named by `@throws`.
+    let exported_dir = temp.path().join("exported");
+    let mut config = create_test_config(src_dir, exported_dir.clone());
+    config.generation.export_error_types = true;
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(exported_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type AppError ="));
+    let commands_content = fs::read_to_string(exported_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains(" * @throws {AppError}\n"));
+    assert!(commands_content.contains("getUser(id: number): Promise<User>"));
+}
+
+const TYPE_ALIAS_SOURCE: &str = r#"
+use serde::Serialize;
+use std::collections::HashMap;
+
+pub type UserId = i32;
+pub type Meta = HashMap<String, Vec<Tag>>;
+pub type Page<T> = Vec<T>;
+
+#[derive(Serialize)]
+pub struct Tag {
+    pub label: String,
+}
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: UserId,
+    pub meta: Meta,
+}
+
+#[tauri::command]
+pub fn get_user(id: UserId) -> Result<User, String> {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn list_tags() -> Page<Tag> {
+    unimplemented!()
+}
+"#;
+
+#[test]
+fn test_pipeline_exports_type_aliases() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(src_dir.join("lib.rs"), TYPE_ALIAS_SOURCE).unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type UserId = number;"));
+    assert!(types_content.contains("export type Meta = Record<string, Tag[]>;"));
+    assert!(types_content.contains("export type Page<T> = T[];"));
+    assert!(types_content.contains("  id: UserId;\n  meta: Meta;\n"));
+    assert!(types_content.contains("export interface Tag {"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("getUser(id: UserId): Promise<User>"));
+    assert!(commands_content.contains("listTags(): Promise<Page<Tag>>"));
+}
+
+#[test]
+fn test_pipeline_inline_type_aliases() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(src_dir.join("lib.rs"), TYPE_ALIAS_SOURCE).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.generation.inline_type_aliases = true;
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(!types_content.contains("export type UserId"));
+    assert!(!types_content.contains("export type Meta"));
+    assert!(!types_content.contains("export type Page"));
+    assert!(types_content.contains("  id: number;\n  meta: Record<string, Tag[]>;\n"));
+    // Types only reachable through an alias target are still exported
+    assert!(types_content.contains("export interface Tag {"));
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("getUser(id: number): Promise<User>"));
+    assert!(commands_content.contains("listTags(): Promise<Tag[]>"));
+    assert!(commands_content.contains("import type { Tag, User } from"));
+}
+
+#[test]
+fn test_pipeline_header_pragmas() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+}
+
+#[tauri::command]
+pub fn get_user() -> User {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.generation.header_pragmas = vec![
+        "/* eslint-disable */".to_string(),
+        "// @ts-nocheck".to_string(),
+    ];
+    Pipeline::new(false).run(&config).unwrap();
+
+    let expected_top =
+        "/* eslint-disable */\n// @ts-nocheck\n// This file was auto-generated by tauri-ts-generator\n";
+    for file in ["types.ts", "commands.ts"] {
+        let content = fs::read_to_string(output_dir.join(file)).unwrap();
+        assert!(
+            content.starts_with(expected_top),
+            "{file} should open with the pragmas:\n{content}"
+        );
+    }
+}
+
+#[test]
+fn test_pipeline_output_inside_source_dir_is_not_scanned() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = src_dir.join("generated");
+
+    fs::create_dir_all(&output_dir).unwrap();
+
+    let code = r#"
+#[tauri::command]
+pub fn ping() -> String {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    // A Rust output file: reading it back would report `ping` twice
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.commands_file = output_dir.join("commands.rs");
+    let pipeline = Pipeline::new(false);
+    fs::write(&config.output.commands_file, code).unwrap();
+
+    let result = pipeline.run(&config);
+    assert!(result.is_ok(), "Pipeline failed: {:?}", result.err());
+
+    // Running twice must be stable as well.
+    assert!(pipeline.run(&config).is_ok());
+
+    let commands_content = fs::read_to_string(&config.output.commands_file).unwrap();
+    assert_eq!(
+        commands_content
+            .matches("export async function ping")
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_pipeline_scans_sources_next_to_output_files() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let api_dir = src_dir.join("api");
+
+    fs::create_dir_all(&api_dir).unwrap();
+    fs::write(src_dir.join("lib.rs"), "mod api;\n").unwrap();
+    fs::write(
+        api_dir.join("user.rs"),
+        r#"
+#[tauri::command]
+pub fn get_user_name() -> String {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, api_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let commands = fs::read_to_string(api_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("export async function getUserName(): Promise<string>"),
+        "got:\n{commands}"
+    );
+}
+
 #[test]
 fn test_pipeline_empty_source() {
     let temp = tempdir().unwrap();
@@ -1265,3 +1715,1173 @@ fn test_cargo_expand_does_not_cause_ambiguity() {
         res => panic!("Expected Found, got {:?}", res),
     }
 }
+
+#[test]
+fn test_pipeline_tab_indent_single_quotes() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+    fs::create_dir_all(&src_dir).unwrap();
+
+    let code = r#"
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: i32,
+    pub role: Role,
+}
+
+#[derive(Serialize)]
+pub enum Role {
+    Admin,
+    Guest,
+}
+
+#[tauri::command]
+pub fn get_user(id: i32) -> Result<User, String> {
+    unimplemented!()
+}
+"#;
+    fs::write(src_dir.join("lib.rs"), code).unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.style = toml::from_str("indent = \"\\t\"\nquote = \"single\"").unwrap();
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types_content.contains("export interface User {\n\tid: number;\n\trole: Role;\n}\n"),
+        "got:\n{types_content}"
+    );
+    assert!(
+        types_content.contains("export type Role =\n\t| 'Admin'\n\t| 'Guest';\n"),
+        "got:\n{types_content}"
+    );
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("import { invoke } from '@tauri-apps/api/core';\n"),
+        "got:\n{commands_content}"
+    );
+    assert!(
+        commands_content.contains("import type { User } from './types';\n"),
+        "got:\n{commands_content}"
+    );
+    assert!(
+        commands_content.contains("\treturn invoke<User>('get_user', { id });\n"),
+        "got:\n{commands_content}"
+    );
+}
+
+#[test]
+fn test_pipeline_groups_commands_by_module() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("generated");
+    fs::create_dir_all(src_dir.join("api")).unwrap();
+
+    fs::write(
+        src_dir.join("lib.rs"),
+        "#[tauri::command]\npub fn ping() -> String { unimplemented!() }\n",
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("user.rs"),
+        r#"
+#[tauri::command]
+pub fn get_user(id: i32) -> String { unimplemented!() }
+
+#[tauri::command]
+pub fn list_users() -> Vec<String> { unimplemented!() }
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("api").join("admin.rs"),
+        "#[tauri::command]\npub fn ban(id: i32) {}\n",
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.generation.group_commands_by_module = true;
+    Pipeline::new(false).run(&config).unwrap();
+
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands_content.contains("export const user = {\n  getUser,\n  listUsers,\n};\n"),
+        "got:\n{commands_content}"
+    );
+    assert!(
+        commands_content.contains("export const apiAdmin = {\n  ban,\n};\n"),
+        "got:\n{commands_content}"
+    );
+    // Crate-root commands keep only their top-level export.
+    assert!(commands_content.contains("export async function ping()"));
+    assert!(!commands_content.contains("  ping,"));
+}
+
+#[test]
+fn test_pipeline_transparent_structs_alias_inner_type() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct Label {
+    pub value: String,
+}
+
+#[derive(Serialize)]
+pub struct Inner {
+    pub id: u32,
+}
+
+#[derive(Serialize)]
+#[serde(transparent)]
+pub struct Wrapper(Inner);
+
+#[tauri::command]
+pub fn get_label() -> Label {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn get_wrapper() -> Wrapper {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type Label = string;"));
+    assert!(types_content.contains("export type Wrapper = Inner;"));
+    // The collector still descends through the wrapper to export Inner.
+    assert!(types_content.contains("export interface Inner {"));
+}
+
+#[test]
+fn test_pipeline_boxed_slice_maps_to_array() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn list_users() -> Box<[User]> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface User {"));
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("listUsers(): Promise<User[]>"));
+}
+
+#[test]
+fn test_pipeline_exports_hashmap_key_enum() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use std::collections::HashMap;
+
+#[derive(Serialize, Hash, PartialEq, Eq)]
+pub enum Status {
+    Active,
+    Banned,
+}
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn users_by_status() -> HashMap<Status, User> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    // The key type is only reachable through the map key, yet still exported
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export type Status ="));
+    assert!(types_content.contains("| \"Active\""));
+    assert!(types_content.contains("export interface User {"));
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("usersByStatus(): Promise<Record<Status, User>>"));
+}
+
+#[test]
+fn test_pipeline_command_type_via_renamed_import() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("api.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct Response {
+    pub ok: bool,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("commands.rs"),
+        r#"
+use crate::api::Response as Resp;
+
+#[tauri::command]
+pub fn ping() -> Resp {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    // `Response` is found in `api` through the import's original name; the
+    // signature keeps the alias, which is exported as a name for it
+    let types_content = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types_content.contains("export interface Response {"));
+    assert!(types_content.contains("export type Resp = Response;"));
+    let commands_content = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands_content.contains("import type { Resp } from \"./types\";"));
+    assert!(commands_content.contains("ping(): Promise<Resp>"));
+}
+
+#[test]
+fn test_pipeline_single_file_output() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_user(id: u32) -> User {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.single_file = Some(output_dir.join("bindings.ts"));
+    Pipeline::new(false).run(&config).unwrap();
+
+    let bindings = fs::read_to_string(output_dir.join("bindings.ts")).unwrap();
+    assert!(bindings.starts_with("// This file was auto-generated by tauri-ts-generator\n"));
+    assert!(bindings.contains("import { invoke } from \"@tauri-apps/api/core\";\n"));
+    assert!(!bindings.contains("./types"));
+    let interface = bindings.find("export interface User {").unwrap();
+    let function = bindings
+        .find("export async function getUser(id: number): Promise<User>")
+        .unwrap();
+    assert!(interface < function);
+
+    assert!(!output_dir.join("types.ts").exists());
+    assert!(!output_dir.join("commands.ts").exists());
+}
+
+#[test]
+fn test_pipeline_command_map_file() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_user(user_id: u32) -> Result<User, String> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.command_map_file = Some(output_dir.join("decl").join("commands.d.ts"));
+    Pipeline::new(false).run(&config).unwrap();
+
+    let map = fs::read_to_string(output_dir.join("decl").join("commands.d.ts")).unwrap();
+    assert!(map.contains("import type { User } from \"../types\";\n"));
+    assert!(
+        map.contains("export interface CommandMap {\n  get_user: [{ userId: number }, User];\n}\n")
+    );
+    assert!(output_dir.join("commands.ts").exists());
+}
+
+#[test]
+fn test_pipeline_resolves_types_in_inline_modules() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+pub mod commands;
+pub mod legacy;
+
+pub mod models {
+    pub mod account {
+        #[derive(Serialize)]
+        pub struct Profile {
+            pub display_name: String,
+        }
+    }
+}
+"#,
+    )
+    .unwrap();
+    // A second `Profile`, so resolving by name alone would be ambiguous
+    fs::write(
+        src_dir.join("legacy.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct Profile {
+    pub login: String,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("commands.rs"),
+        r#"
+use crate::models::account::Profile;
+
+#[tauri::command]
+pub fn get_profile() -> Profile {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("export interface Profile {\n  display_name: string;\n}\n"),
+        "got:\n{types}"
+    );
+    assert!(!types.contains("login"));
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(commands.contains("export async function getProfile(): Promise<Profile> {"));
+}
+
+#[test]
+fn test_pipeline_events_file() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct Profile {
+    pub name: String,
+}
+
+#[derive(Clone, Serialize)]
+#[tauri_event(name = "user-updated")]
+pub struct UserUpdated {
+    pub id: u32,
+    pub profile: Profile,
+}
+
+#[tauri::command]
+pub fn ping() -> String {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    // Without `events_file`, payload types are not exported
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(!types.contains("UserUpdated"));
+
+    config.output.events_file = Some(output_dir.join("events.ts"));
+    Pipeline::new(false).run(&config).unwrap();
+
+    // The payload and the types it refers to are exported even though no
+    // command uses them
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types.contains("export interface UserUpdated {"));
+    assert!(types.contains("export interface Profile {"));
+    let events = fs::read_to_string(output_dir.join("events.ts")).unwrap();
+    assert!(events.contains("import type { UserUpdated } from \"./types\";\n"));
+    assert!(events.contains("export function onUserUpdated("));
+}
+
+#[test]
+fn test_pipeline_output_is_sorted_and_stable_across_runs() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("zoo.rs"),
+        r#"
+use crate::animals::{Zebra, Aardvark, Mood};
+
+#[tauri::command]
+pub fn watch_zebra() -> Zebra {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn feed_aardvark(animal: Aardvark) -> Mood {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        src_dir.join("animals.rs"),
+        r#"
+#[derive(Serialize, Deserialize)]
+pub struct Zebra { pub stripes: u32, pub keeper: Keeper }
+
+#[derive(Serialize, Deserialize)]
+pub struct Aardvark { pub name: String }
+
+#[derive(Serialize, Deserialize)]
+pub struct Keeper { pub name: String }
+
+#[derive(Serialize)]
+pub enum Mood { Happy, Hungry }
+"#,
+    )
+    .unwrap();
+
+    let run = |output_dir: PathBuf| {
+        let config = create_test_config(src_dir.clone(), output_dir.clone());
+        Pipeline::new(false).run(&config).unwrap();
+        (
+            fs::read(output_dir.join("types.ts")).unwrap(),
+            fs::read(output_dir.join("commands.ts")).unwrap(),
+        )
+    };
+    let first = run(temp.path().join("first"));
+    let second = run(temp.path().join("second"));
+    assert_eq!(first, second);
+
+    // Declarations and functions come out alphabetically by name
+    let types = String::from_utf8(first.0).unwrap();
+    let positions: Vec<usize> = [
+        "interface Aardvark",
+        "interface Keeper",
+        "interface Zebra",
+        "type Mood",
+    ]
+    .iter()
+    .map(|decl| types.find(decl).unwrap())
+    .collect();
+    assert!(positions.windows(2).all(|w| w[0] < w[1]), "{types}");
+
+    let commands = String::from_utf8(first.1).unwrap();
+    assert!(
+        commands.find("function feedAardvark").unwrap()
+            < commands.find("function watchZebra").unwrap()
+    );
+}
+
+#[test]
+fn test_pipeline_optional_hashmap_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use std::collections::HashMap;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[derive(Serialize)]
+pub struct Directory {
+    pub members: Option<HashMap<String, User>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub admins: Option<HashMap<String, User>>,
+    pub deputies: Option<HashMap<String, Option<User>>>,
+}
+
+#[tauri::command]
+pub fn get_directory() -> Directory {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("  members: Record<string, User> | null;\n"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("  admins?: Record<string, User>;\n"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("  deputies: Record<string, User | null> | null;\n"),
+        "got:\n{types}"
+    );
+    // Only reachable through the map values
+    assert!(types.contains("export interface User {"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_serde_json_map_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use serde_json::value::RawValue;
+use serde_json::{Map, Value};
+
+#[derive(Serialize)]
+pub struct Document {
+    pub attributes: serde_json::Map<String, Value>,
+    pub extra: Option<Map<String, Value>>,
+    pub raw: Box<RawValue>,
+}
+
+#[tauri::command]
+pub fn get_document() -> Document {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("  attributes: Record<string, unknown>;\n"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("  extra: Record<string, unknown> | null;\n"),
+        "got:\n{types}"
+    );
+    assert!(types.contains("  raw: unknown;\n"), "got:\n{types}");
+    assert!(!types.contains("Map"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_drops_phantom_data_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use std::marker::PhantomData;
+
+#[derive(Serialize)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct Handle<T> {
+    pub id: u64,
+    phantom: PhantomData<T>,
+}
+
+#[tauri::command]
+pub fn get_handle() -> Handle<User> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("export interface Handle<T> {\n  id: number;\n}\n"),
+        "got:\n{types}"
+    );
+    assert!(!types.contains("PhantomData"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_index_map_values_are_exported() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use indexmap::IndexMap;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn users_by_name() -> IndexMap<String, User> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("usersByName(): Promise<Record<string, User>>"),
+        "got:\n{commands}"
+    );
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types.contains("export interface User {"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_commands_carry_source_file_and_rename_all() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(src_dir.join("api")).unwrap();
+    fs::write(src_dir.join("lib.rs"), "mod api;\n").unwrap();
+    fs::write(src_dir.join("api").join("mod.rs"), "pub mod user;\n").unwrap();
+    fs::write(
+        src_dir.join("api").join("user.rs"),
+        r#"
+#[tauri::command(rename_all = "snake_case")]
+pub fn rename_user(user_id: u32, new_name: String) {}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    // Function names come from the command's source file, invoke keys from
+    // its rename_all
+    config.generation.prefix_with_module = true;
+    Pipeline::new(false).run(&config).unwrap();
+
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains(
+            "export async function apiUserRenameUser(userId: number, newName: string): Promise<void> {\n  \
+             return invoke<void>(\"rename_user\", { user_id: userId, new_name: newName });\n"
+        ),
+        "got:\n{commands}"
+    );
+}
+
+#[test]
+fn test_pipeline_custom_header_opens_each_file_once() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_user() -> User {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.header = Some("Generated by tauri-codegen v{version}. Do not edit.".to_string());
+    config.generation.header_pragmas = vec!["/* eslint-disable */".to_string()];
+    Pipeline::new(false).run(&config).unwrap();
+
+    let banner = format!(
+        "/* eslint-disable */\n// Generated by tauri-codegen v{}. Do not edit.\n\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    for file in ["types.ts", "commands.ts"] {
+        let content = fs::read_to_string(output_dir.join(file)).unwrap();
+        assert!(content.starts_with(&banner), "{file}:\n{content}");
+        assert_eq!(content.matches("Generated by tauri-codegen").count(), 1);
+        assert!(!content.contains("auto-generated"), "{file}:\n{content}");
+    }
+}
+
+#[test]
+fn test_pipeline_resolves_types_from_path_dependencies() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    let app_src = root.join("app").join("src");
+    let shared_src = root.join("shared").join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&app_src).unwrap();
+    fs::create_dir_all(&shared_src).unwrap();
+    fs::write(
+        root.join("Cargo.toml"),
+        "[workspace]\nmembers = [\"app\", \"shared\"]\n",
+    )
+    .unwrap();
+    fs::write(
+        root.join("app").join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[dependencies]\nshared = { path = \"../shared\" }\n",
+    )
+    .unwrap();
+    fs::write(
+        app_src.join("lib.rs"),
+        r#"
+mod ui;
+use shared::models::User;
+
+#[tauri::command]
+pub fn get_user() -> User {
+    unimplemented!()
+}
+
+#[tauri::command]
+pub fn settings() -> shared::Settings {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+    // Same name as the dependency's type: the import must pick the right one
+    fs::write(
+        app_src.join("ui.rs"),
+        "#[derive(Serialize)]\npub struct User {\n    pub label: String,\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        shared_src.join("lib.rs"),
+        r#"
+pub mod models;
+
+#[derive(Serialize)]
+pub struct Settings {
+    pub theme: String,
+}
+"#,
+    )
+    .unwrap();
+    fs::write(
+        shared_src.join("models.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+    pub settings: crate::Settings,
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(app_src, output_dir.clone());
+    config.input.path_dependencies = true;
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("export interface User {\n  id: number;\n  settings: Settings;\n}"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("export interface Settings {\n  theme: string;\n}"),
+        "got:\n{types}"
+    );
+    assert!(!types.contains("label"), "got:\n{types}");
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("getUser(): Promise<User>"),
+        "got:\n{commands}"
+    );
+}
+
+#[test]
+fn test_pipeline_cfg_attr_skip_under_active_feature() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct Account {
+    pub id: u32,
+    #[cfg_attr(feature = "redact", serde(skip))]
+    pub password_hash: String,
+}
+
+#[tauri::command]
+pub fn get_account() -> Account {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("  password_hash: string;\n"),
+        "got:\n{types}"
+    );
+
+    config.input.active_features = vec!["redact".to_string()];
+    Pipeline::new(false).run(&config).unwrap();
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(!types.contains("password_hash"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_generate_strings_matches_run_without_writing() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_user() -> User {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let output = Pipeline::new(false).generate_strings(&config).unwrap();
+    assert!(!output_dir.exists());
+
+    Pipeline::new(false).run(&config).unwrap();
+    assert_eq!(output.files.len(), 2);
+    for (path, content) in &output.files {
+        assert_eq!(&fs::read_to_string(path).unwrap(), content);
+    }
+    assert!(output
+        .file(&output_dir.join("types.ts"))
+        .unwrap()
+        .contains("export interface User {"));
+}
+
+#[test]
+fn test_pipeline_generate_strings_follows_output_selection() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[tauri::command]
+pub fn get_user() -> User {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    let output = Pipeline::new(false)
+        .with_output_selection(OutputSelection::TypesOnly)
+        .generate_strings(&config)
+        .unwrap();
+    assert_eq!(output.files.len(), 1);
+
+    let printed = output.to_string();
+    let types = output.file(&output_dir.join("types.ts")).unwrap();
+    assert_eq!(
+        printed,
+        format!(
+            "// ==> {} <==\n{}",
+            output_dir.join("types.ts").display(),
+            types
+        )
+    );
+    assert!(output.file(&output_dir.join("commands.ts")).is_none());
+}
+
+#[test]
+fn test_pipeline_double_option_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize)]
+pub struct User {
+    pub id: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct Patch {
+    pub nickname: Option<Option<String>>,
+    // Absent, null or a user
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub manager: Option<Option<User>>,
+}
+
+#[tauri::command]
+pub fn apply_patch(patch: Patch) {}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("  nickname: string | null;\n"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("  manager?: User | null;\n"),
+        "got:\n{types}"
+    );
+}
+
+#[test]
+fn test_pipeline_nonzero_integer_fields() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use std::num::{NonZero, NonZeroU32};
+
+#[derive(Serialize)]
+pub struct Page {
+    pub count: NonZeroU32,
+    pub size: Option<NonZero<u64>>,
+}
+
+#[tauri::command]
+pub fn get_page() -> Page {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let config = create_test_config(src_dir, output_dir.clone());
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(types.contains("  count: number;\n"), "got:\n{types}");
+    assert!(types.contains("  size: number | null;\n"), "got:\n{types}");
+    assert!(!types.contains("NonZero"), "got:\n{types}");
+}
+
+#[test]
+fn test_pipeline_unkeyable_map_keys_become_entries() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+use std::collections::HashMap;
+
+#[derive(Serialize, Hash, PartialEq, Eq)]
+pub struct Cell {
+    pub row: u32,
+    pub col: u32,
+}
+
+#[derive(Serialize)]
+pub struct Sheet {
+    #[serde_as(as = "Vec<(_, _)>")]
+    pub values: HashMap<Cell, i32>,
+    pub labels: HashMap<String, i32>,
+}
+
+#[tauri::command]
+pub fn get_sheet() -> Sheet {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.zod_file = Some(output_dir.join("schemas.ts"));
+    Pipeline::new(false).run(&config).unwrap();
+
+    let types = fs::read_to_string(output_dir.join("types.ts")).unwrap();
+    assert!(
+        types.contains("  values: [Cell, number][];\n"),
+        "got:\n{types}"
+    );
+    assert!(
+        types.contains("  labels: Record<string, number>;\n"),
+        "got:\n{types}"
+    );
+    assert!(types.contains("export interface Cell {"), "got:\n{types}");
+
+    let schemas = fs::read_to_string(output_dir.join("schemas.ts")).unwrap();
+    assert!(
+        schemas.contains("  values: z.array(z.tuple([CellSchema, z.number()])),\n"),
+        "got:\n{schemas}"
+    );
+}
+
+#[test]
+fn test_pipeline_zod_file_validates_command_args() {
+    let temp = tempdir().unwrap();
+    let src_dir = temp.path().join("src");
+    let output_dir = temp.path().join("output");
+
+    fs::create_dir_all(&src_dir).unwrap();
+    fs::write(
+        src_dir.join("lib.rs"),
+        r#"
+#[derive(Serialize, Deserialize)]
+pub struct User {
+    pub name: String,
+}
+
+#[tauri::command]
+pub fn save_user(user: User, notify: Option<bool>) -> Result<(), String> {
+    unimplemented!()
+}
+"#,
+    )
+    .unwrap();
+
+    let mut config = create_test_config(src_dir, output_dir.clone());
+    config.output.zod_file = Some(output_dir.join("schemas.ts"));
+    Pipeline::new(false).run(&config).unwrap();
+
+    let schemas = fs::read_to_string(output_dir.join("schemas.ts")).unwrap();
+    assert!(
+        schemas.contains(
+            "export const SaveUserArgsSchema = z.object({\n  user: UserSchema,\n  notify: z.boolean().nullable(),\n});\n"
+        ),
+        "got:\n{schemas}"
+    );
+
+    let commands = fs::read_to_string(output_dir.join("commands.ts")).unwrap();
+    assert!(
+        commands.contains("import { SaveUserArgsSchema } from \"./schemas\";\n"),
+        "got:\n{commands}"
+    );
+    assert!(
+        commands.contains(
+            "  try {\n    SaveUserArgsSchema.parse({ user, notify });\n  } catch (error) {\n"
+        ),
+        "got:\n{commands}"
+    );
+}